@@ -105,6 +105,8 @@ export function __chunk_graph_inner_get_chunk_modules(jsChunkUkey: number, compi
 
 export function __chunk_graph_inner_get_chunk_modules_iterable_by_source_type(jsChunkUkey: number, sourceType: string, compilation: JsCompilation): Array<JsModule>
 
+export function __chunk_graph_inner_get_module_chunks(moduleIdentifier: string, compilation: JsCompilation): Array<JsChunk>
+
 export function __chunk_group_inner_get_chunk_group(ukey: number, compilation: JsCompilation): JsChunkGroup
 
 export function __chunk_inner_can_be_initial(jsChunkUkey: number, compilation: JsCompilation): boolean
@@ -119,6 +121,14 @@ export function __chunk_inner_has_runtime(jsChunkUkey: number, compilation: JsCo
 
 export function __chunk_inner_is_only_initial(jsChunkUkey: number, compilation: JsCompilation): boolean
 
+export function __module_graph_inner_get_exports_info(moduleIdentifier: string, compilation: JsCompilation): Array<JsExportInfo>
+
+export function __module_graph_inner_get_incoming_connections(moduleIdentifier: string, compilation: JsCompilation): Array<JsModuleGraphConnection>
+
+export function __module_graph_inner_get_module(moduleIdentifier: string, compilation: JsCompilation): JsModule | null
+
+export function __module_graph_inner_get_outgoing_connections(moduleIdentifier: string, compilation: JsCompilation): Array<JsModuleGraphConnection>
+
 export interface AfterResolveData {
   request: string
   context: string
@@ -176,18 +186,26 @@ export const enum BuiltinPluginName {
   WarnCaseSensitiveModulesPlugin = 'WarnCaseSensitiveModulesPlugin',
   DataUriPlugin = 'DataUriPlugin',
   FileUriPlugin = 'FileUriPlugin',
+  HttpUriPlugin = 'HttpUriPlugin',
   RuntimePlugin = 'RuntimePlugin',
   JsonModulesPlugin = 'JsonModulesPlugin',
   InferAsyncModulesPlugin = 'InferAsyncModulesPlugin',
   JavascriptModulesPlugin = 'JavascriptModulesPlugin',
   AsyncWebAssemblyModulesPlugin = 'AsyncWebAssemblyModulesPlugin',
+  WebAssemblyModulesPlugin = 'WebAssemblyModulesPlugin',
   AssetModulesPlugin = 'AssetModulesPlugin',
   SourceMapDevToolPlugin = 'SourceMapDevToolPlugin',
   EvalSourceMapDevToolPlugin = 'EvalSourceMapDevToolPlugin',
+  EvalDevToolModulePlugin = 'EvalDevToolModulePlugin',
   SideEffectsFlagPlugin = 'SideEffectsFlagPlugin',
   FlagDependencyExportsPlugin = 'FlagDependencyExportsPlugin',
   FlagDependencyUsagePlugin = 'FlagDependencyUsagePlugin',
   MangleExportsPlugin = 'MangleExportsPlugin',
+  IgnorePlugin = 'IgnorePlugin',
+  NormalModuleReplacementPlugin = 'NormalModuleReplacementPlugin',
+  ContextReplacementPlugin = 'ContextReplacementPlugin',
+  SizeLimitsPlugin = 'SizeLimitsPlugin',
+  LazyCompilationPlugin = 'LazyCompilationPlugin',
   HttpExternalsRspackPlugin = 'HttpExternalsRspackPlugin',
   CopyRspackPlugin = 'CopyRspackPlugin',
   HtmlRspackPlugin = 'HtmlRspackPlugin',
@@ -324,6 +342,13 @@ export interface JsExecuteModuleResult {
   id: number
 }
 
+export interface JsExportInfo {
+  name: string
+  /** `None` when it's unknown whether the export is provided */
+  provided?: boolean
+  used: "unused" | "onlyPropertiesUsed" | "noInfo" | "unknown" | "used"
+}
+
 export interface JsHooks {
   processAssetsStageAdditional: (...args: any[]) => any
   processAssetsStagePreProcess: (...args: any[]) => any
@@ -421,6 +446,25 @@ export interface JsModule {
   nameForCondition?: string
 }
 
+export interface JsModuleGraphConnection {
+  /** Identifier of the module that contains the dependency, `None` for entries */
+  originModuleIdentifier?: string
+  moduleIdentifier: string
+  dependencyType: string
+  userRequest?: string
+  active: boolean
+  conditional: boolean
+}
+
+export interface JsOriginRecord {
+  module: string
+  moduleIdentifier: string
+  moduleName: string
+  moduleId: string
+  loc: string
+  request: string
+}
+
 export interface JsResolveForSchemeInput {
   resourceData: JsResourceData
   scheme: string
@@ -488,6 +532,7 @@ export interface JsStatsChunk {
   parents?: Array<string>
   children?: Array<string>
   siblings?: Array<string>
+  origins: Array<JsOriginRecord>
 }
 
 export interface JsStatsChunkGroup {
@@ -540,11 +585,19 @@ export interface JsStatsModule {
   issuerId?: string
   issuerPath: Array<JsStatsModuleIssuer>
   nameForCondition?: string
+  layer?: string
   reasons?: Array<JsStatsModuleReason>
   assets?: Array<string>
   source?: string | Buffer
   profile?: JsStatsModuleProfile
   orphan: boolean
+  depth?: number
+  providedExports?: Array<string>
+  usedExports?: boolean | Array<string>
+  optimizationBailout: Array<string>
+  cacheable: boolean
+  built: boolean
+  cached: boolean
 }
 
 export interface JsStatsModuleIssuer {
@@ -669,13 +722,18 @@ export interface RawCacheGroupOptions {
   /** What kind of chunks should be selected. */
   chunks?: RegExp | 'async' | 'initial' | 'all'
   type?: RegExp | string
+  layer?: RegExp | string
   automaticNameDelimiter?: string
   minChunks?: number
   minSize?: number
+  minSizeReduction?: number
+  enforceSizeThreshold?: number
+  minRemainingSize?: number
   maxSize?: number
   maxAsyncSize?: number
   maxInitialSize?: number
   name?: string | false | Function
+  usedExports?: boolean
   reuseExistingChunk?: boolean
   enforce?: boolean
 }
@@ -696,6 +754,11 @@ export interface RawCacheOptions {
   version: string
 }
 
+export interface RawCheckResourceFnCtx {
+  resource: string
+  context: string
+}
+
 export interface RawChunkOptionNameCtx {
   module: JsModule
 }
@@ -735,6 +798,14 @@ export interface RawContainerReferencePluginOptions {
   enhanced: boolean
 }
 
+export interface RawContextReplacementPluginOptions {
+  resourceRegExp: RegExp
+  newContentResource?: string
+  newContentRecursive?: boolean
+  newContentRegExp?: RegExp
+  newContentCreateContextMap?: Record<string, string>
+}
+
 export interface RawCopyGlobOptions {
   caseSensitiveMatch?: boolean
   dot?: boolean
@@ -771,17 +842,21 @@ export interface RawCssModulesConfig {
 
 export interface RawCssPluginConfig {
   modules: RawCssModulesConfig
+  /** Browsers selected by the `browserslist` targets of `target` */
+  targets: Array<string>
 }
 
 export interface RawEntryOptions {
   name?: string
   runtime?: string
+  dependOn?: Array<string>
   chunkLoading?: string
   asyncChunks?: boolean
   publicPath?: string
   baseUri?: string
   filename?: string
   library?: RawLibraryOptions
+  layer?: string
 }
 
 export interface RawEntryPluginOptions {
@@ -790,9 +865,16 @@ export interface RawEntryPluginOptions {
   options: RawEntryOptions
 }
 
+export interface RawEvalDevToolModulePluginOptions {
+  namespace?: string
+  moduleFilenameTemplate?: string | Function
+  sourceUrlComment?: string
+}
+
 export interface RawExperiments {
   newSplitChunks: boolean
   topLevelAwait: boolean
+  layers: boolean
   rspackFuture: RawRspackFuture
 }
 
@@ -878,11 +960,33 @@ export interface RawHtmlRspackPluginOptions {
   meta?: Record<string, Record<string, string>>
 }
 
+export interface RawHttpClientResponse {
+  status: number
+  /** Header names should be lower cased */
+  headers: Record<string, string>
+  body: Buffer
+}
+
 export interface RawHttpExternalsRspackPluginOptions {
   css: boolean
   webAsync: boolean
 }
 
+export interface RawHttpUriPluginOptions {
+  allowedUris: (string | RegExp)[]
+  lockfileLocation: string
+  cacheLocation?: string
+  frozen: boolean
+  upgrade: boolean
+  httpClient: (url: string) => Promise<RawHttpClientResponse>
+}
+
+export interface RawIgnorePluginOptions {
+  resourceRegExp?: RegExp
+  contextRegExp?: RegExp
+  checkResource?: (data: RawCheckResourceFnCtx) => boolean
+}
+
 export interface RawInfo {
   immutable?: boolean
   minimized?: boolean
@@ -899,6 +1003,15 @@ export interface RawJavascriptParserOptions {
   dynamicImportPreload: string
   dynamicImportPrefetch: string
   url: string
+  worker: Array<string>
+}
+
+export interface RawLazyCompilationPluginOptions {
+  entries: boolean
+  imports: boolean
+  test?: RegExp
+  /** Requested by the client with the encoded resource path to activate a module */
+  clientUrl: string
 }
 
 export interface RawLibraryAuxiliaryComment {
@@ -981,9 +1094,11 @@ export interface RawModuleRule {
   generator?: RawGeneratorOptions
   resolve?: RawResolveOptions
   issuer?: RawRuleSetCondition
+  issuerLayer?: RawRuleSetCondition
   dependency?: RawRuleSetCondition
   scheme?: RawRuleSetCondition
   mimetype?: RawRuleSetCondition
+  layer?: string
   oneOf?: Array<RawModuleRule>
   rules?: Array<RawModuleRule>
   /** Specifies the category of the loader. No value means normal loader. */
@@ -1016,6 +1131,21 @@ export interface RawNodeOption {
   global: string
 }
 
+export interface RawNormalModuleReplacementCreateData {
+  resource: string
+}
+
+export interface RawNormalModuleReplacementPluginOptions {
+  resourceRegExp: RegExp
+  newResource: string | ((data: RawNormalModuleReplacementResolveData) => RawNormalModuleReplacementResolveData)
+}
+
+export interface RawNormalModuleReplacementResolveData {
+  request: string
+  context: string
+  createData?: RawNormalModuleReplacementCreateData
+}
+
 export interface RawOptimizationOptions {
   removeAvailableModules: boolean
   sideEffects: string
@@ -1043,6 +1173,8 @@ export interface RawOptions {
   node?: RawNodeOption
   profile: boolean
   bail: boolean
+  recordsInputPath?: string
+  recordsOutputPath?: string
   builtins: RawBuiltins
 }
 
@@ -1195,6 +1327,13 @@ export interface RawRuleSetLogicalConditions {
   not?: RawRuleSetCondition
 }
 
+export interface RawSizeLimitsPluginOptions {
+  hints: "warning" | "error"
+  maxAssetSize: number
+  maxEntrypointSize: number
+  assetFilter?: (assetFilename: string) => boolean
+}
+
 export interface RawSnapshotOptions {
   resolve: RawSnapshotStrategy
   module: RawSnapshotStrategy
@@ -1218,6 +1357,7 @@ export interface RawSourceMapDevToolPluginOptions {
   publicPath?: string
   sourceRoot?: string
   test?: (text: string) => boolean
+  ignoreList?: (text: string) => boolean
 }
 
 export interface RawSplitChunksOptions {
@@ -1232,11 +1372,13 @@ export interface RawSplitChunksOptions {
   minChunks?: number
   hidePathInfo?: boolean
   minSize?: number
+  minSizeReduction?: number
   enforceSizeThreshold?: number
   minRemainingSize?: number
   maxSize?: number
   maxAsyncSize?: number
   maxInitialSize?: number
+  usedExports?: boolean
 }
 
 export interface RawStatsOptions {
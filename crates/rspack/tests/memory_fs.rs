@@ -0,0 +1,43 @@
+use std::{path::PathBuf, sync::Arc};
+
+use rspack_core::Compiler;
+use rspack_fs::{InputFileSystem, MemoryFileSystem};
+use rspack_testing::TestConfig;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_build_project_in_memory_fs() {
+  let context = PathBuf::from("/memory-fs-project");
+  let input_filesystem = Arc::new(MemoryFileSystem::from_files([
+    (
+      context.join("index.js"),
+      "import { answer } from './lib/answer';\nconsole.log(answer);\n",
+    ),
+    (
+      context.join("lib/answer.js"),
+      "export const answer = 'from memory';\n",
+    ),
+  ]));
+  assert!(!context.exists(), "the project should only live in memory");
+
+  let config: TestConfig = serde_json::from_str("{}").expect("should be valid config");
+  let (mut options, plugins) = config.apply(context.clone());
+  options.input_filesystem = input_filesystem;
+
+  let mut compiler = Compiler::new(options, plugins, MemoryFileSystem::new());
+  compiler.build().await.expect("should build");
+
+  let stats = compiler.compilation.get_stats();
+  assert!(
+    stats.get_errors().is_empty(),
+    "{}",
+    stats
+      .emit_diagnostics_string(true)
+      .expect("should emit diagnostics")
+  );
+  let output = compiler
+    .output_filesystem
+    .read(&context.join("dist/main.js"))
+    .expect("should emit main.js to the output file system");
+  assert!(String::from_utf8_lossy(&output).contains("from memory"));
+  assert!(!context.exists(), "nothing should be written to disk");
+}
@@ -0,0 +1,107 @@
+use std::{
+  path::PathBuf,
+  sync::{Arc, Mutex},
+};
+
+use rspack_core::{ApplyContext, Compiler, CompilerOptions, ModuleType, Plugin, PluginContext};
+use rspack_error::Result;
+use rspack_fs::MemoryFileSystem;
+use rspack_plugin_javascript::{
+  visitors::JavascriptParser, JavascriptParserPlugin, JavascriptParserPluginRegistry,
+};
+use rspack_testing::TestConfig;
+use swc_core::ecma::ast::{CallExpr, Callee, Expr};
+
+type Calls = Arc<Mutex<Vec<String>>>;
+
+struct CustomCallParserPlugin {
+  calls: Calls,
+}
+
+impl JavascriptParserPlugin for CustomCallParserPlugin {
+  fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr) -> Option<bool> {
+    let is_custom_call = matches!(
+      &expr.callee,
+      Callee::Expr(callee) if matches!(&**callee, Expr::Ident(ident) if &*ident.sym == "__custom_call__")
+    );
+    if !is_custom_call {
+      return None;
+    }
+    self.calls.lock().expect("should lock calls").push(
+      parser
+        .resource_data()
+        .resource_path
+        .to_string_lossy()
+        .to_string(),
+    );
+    Some(true)
+  }
+}
+
+#[derive(Debug)]
+struct CustomCallPlugin {
+  calls: Calls,
+}
+
+impl Plugin for CustomCallPlugin {
+  fn name(&self) -> &'static str {
+    "CustomCallPlugin"
+  }
+
+  fn apply(
+    &self,
+    mut ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    let calls = self.calls.clone();
+    ctx
+      .context
+      .hooks::<JavascriptParserPluginRegistry>()
+      .register(
+        ModuleType::Js,
+        Arc::new(move |_| {
+          Box::new(CustomCallParserPlugin {
+            calls: calls.clone(),
+          })
+        }),
+      );
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn should_call_parser_plugins_of_other_plugins() {
+  let context = PathBuf::from("/parser-plugin-project");
+  let input_filesystem = Arc::new(MemoryFileSystem::from_files([
+    (
+      context.join("index.js"),
+      "import './lib';\n__custom_call__();\n",
+    ),
+    (context.join("lib.js"), "__custom_call__(1, 2);\n"),
+  ]));
+
+  let config: TestConfig = serde_json::from_str("{}").expect("should be valid config");
+  let (mut options, mut plugins) = config.apply(context.clone());
+  options.input_filesystem = input_filesystem;
+  let calls = Calls::default();
+  // applied before `JsPlugin`, the registry is shared no matter the order
+  plugins.insert(
+    0,
+    Box::new(CustomCallPlugin {
+      calls: calls.clone(),
+    }),
+  );
+
+  let mut compiler = Compiler::new(options, plugins, MemoryFileSystem::new());
+  compiler.build().await.expect("should build");
+
+  let mut calls = calls.lock().expect("should lock calls").clone();
+  calls.sort();
+  assert_eq!(
+    calls,
+    vec![
+      context.join("index.js").to_string_lossy().to_string(),
+      context.join("lib.js").to_string_lossy().to_string(),
+    ]
+  );
+}
@@ -1,8 +1,12 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
 use napi_derive::napi;
 use rspack_core::{
   CompilerOptions, Context, Experiments, IncrementalRebuild, IncrementalRebuildMakeState,
   ModuleOptions, Optimization, OutputOptions, Target, TreeShaking,
 };
+use rspack_fs::NativeFileSystem;
 use serde::Deserialize;
 
 mod raw_builtins;
@@ -63,6 +67,8 @@ pub struct RawOptions {
   pub node: Option<RawNodeOption>,
   pub profile: bool,
   pub bail: bool,
+  pub records_input_path: Option<String>,
+  pub records_output_path: Option<String>,
   pub builtins: RawBuiltins,
 }
 
@@ -86,6 +92,7 @@ impl RawOptions {
       },
       new_split_chunks: self.experiments.new_split_chunks,
       top_level_await: self.experiments.top_level_await,
+      layers: self.experiments.layers,
       rspack_future: self.experiments.rspack_future.into(),
     };
     let optimization: Optimization = IS_ENABLE_NEW_SPLIT_CHUNKS
@@ -118,7 +125,11 @@ impl RawOptions {
       dev_server: Default::default(),
       profile: self.profile,
       bail: self.bail,
+      records_input_path: self.records_input_path.map(PathBuf::from),
+      records_output_path: self.records_output_path.map(PathBuf::from),
       builtins,
+      input_filesystem: Arc::new(NativeFileSystem),
+      intermediate_filesystem: Arc::new(NativeFileSystem),
     })
   }
 }
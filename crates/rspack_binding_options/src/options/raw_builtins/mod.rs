@@ -1,10 +1,16 @@
 mod raw_banner;
 mod raw_bundle_info;
+mod raw_context_replacement;
 mod raw_copy;
 mod raw_html;
+mod raw_http_uri;
+mod raw_ignore;
+mod raw_lazy_compilation;
 mod raw_limit_chunk_count;
 mod raw_mf;
+mod raw_normal_module_replacement;
 mod raw_progress;
+mod raw_size_limits;
 mod raw_swc_js_minimizer;
 mod raw_to_be_deprecated;
 
@@ -22,9 +28,10 @@ use rspack_ids::{
 use rspack_napi_shared::NapiResultExt;
 use rspack_plugin_asset::AssetPlugin;
 use rspack_plugin_banner::BannerPlugin;
+use rspack_plugin_context_replacement::ContextReplacementPlugin;
 use rspack_plugin_copy::{CopyRspackPlugin, CopyRspackPluginOptions};
 use rspack_plugin_devtool::{
-  EvalSourceMapDevToolPlugin, SourceMapDevToolModuleOptionsPlugin,
+  EvalDevToolModulePlugin, EvalSourceMapDevToolPlugin, SourceMapDevToolModuleOptionsPlugin,
   SourceMapDevToolModuleOptionsPluginOptions, SourceMapDevToolPlugin,
   SourceMapDevToolPluginOptions,
 };
@@ -35,6 +42,7 @@ use rspack_plugin_externals::{
 };
 use rspack_plugin_hmr::HotModuleReplacementPlugin;
 use rspack_plugin_html::HtmlRspackPlugin;
+use rspack_plugin_ignore::IgnorePlugin;
 use rspack_plugin_javascript::{
   FlagDependencyExportsPlugin, FlagDependencyUsagePlugin, InferAsyncModulesPlugin, JsPlugin,
   MangleExportsPlugin, SideEffectsFlagPlugin,
@@ -47,25 +55,37 @@ use rspack_plugin_mf::{
   ConsumeSharedPlugin, ContainerPlugin, ContainerReferencePlugin, ProvideSharedPlugin,
   ShareRuntimePlugin,
 };
+use rspack_plugin_normal_module_replacement::NormalModuleReplacementPlugin;
 use rspack_plugin_progress::ProgressPlugin;
 use rspack_plugin_real_content_hash::RealContentHashPlugin;
 use rspack_plugin_remove_empty_chunks::RemoveEmptyChunksPlugin;
 use rspack_plugin_runtime::{
   enable_chunk_loading_plugin, ArrayPushCallbackChunkFormatPlugin, BundlerInfoPlugin,
-  ChunkPrefetchPreloadPlugin, CommonJsChunkFormatPlugin, ModuleChunkFormatPlugin, RuntimePlugin,
+  ChunkPrefetchPreloadPlugin, CommonJsChunkFormatPlugin, LazyCompilationPlugin,
+  ModuleChunkFormatPlugin, RuntimePlugin,
 };
-use rspack_plugin_schemes::{DataUriPlugin, FileUriPlugin};
+use rspack_plugin_schemes::{DataUriPlugin, FileUriPlugin, HttpUriPlugin};
+use rspack_plugin_size_limits::SizeLimitsPlugin;
 use rspack_plugin_swc_css_minimizer::SwcCssMinimizerRspackPlugin;
 use rspack_plugin_swc_js_minimizer::SwcJsMinimizerRspackPlugin;
 use rspack_plugin_warn_sensitive_module::WarnCaseSensitiveModulesPlugin;
-use rspack_plugin_wasm::{enable_wasm_loading_plugin, AsyncWasmPlugin};
+use rspack_plugin_wasm::{enable_wasm_loading_plugin, AsyncWasmPlugin, SyncWasmPlugin};
 use rspack_plugin_web_worker_template::web_worker_template_plugin;
 use rspack_plugin_worker::WorkerPlugin;
 
 pub use self::{
-  raw_banner::RawBannerPluginOptions, raw_copy::RawCopyRspackPluginOptions,
-  raw_html::RawHtmlRspackPluginOptions, raw_limit_chunk_count::RawLimitChunkCountPluginOptions,
-  raw_mf::RawContainerPluginOptions, raw_progress::RawProgressPluginOptions,
+  raw_banner::RawBannerPluginOptions,
+  raw_context_replacement::RawContextReplacementPluginOptions,
+  raw_copy::RawCopyRspackPluginOptions,
+  raw_html::RawHtmlRspackPluginOptions,
+  raw_http_uri::{RawHttpClientResponse, RawHttpUriPluginOptions},
+  raw_ignore::RawIgnorePluginOptions,
+  raw_lazy_compilation::RawLazyCompilationPluginOptions,
+  raw_limit_chunk_count::RawLimitChunkCountPluginOptions,
+  raw_mf::RawContainerPluginOptions,
+  raw_normal_module_replacement::RawNormalModuleReplacementPluginOptions,
+  raw_progress::RawProgressPluginOptions,
+  raw_size_limits::RawSizeLimitsPluginOptions,
   raw_swc_js_minimizer::RawSwcJsMinimizerRspackPluginOptions,
 };
 use self::{
@@ -73,8 +93,9 @@ use self::{
   raw_mf::{RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions, RawProvideOptions},
 };
 use crate::{
-  RawEntryPluginOptions, RawExternalItemWrapper, RawExternalsPluginOptions,
-  RawHttpExternalsRspackPluginOptions, RawSourceMapDevToolPluginOptions, RawSplitChunksOptions,
+  RawEntryPluginOptions, RawEvalDevToolModulePluginOptions, RawExternalItemWrapper,
+  RawExternalsPluginOptions, RawHttpExternalsRspackPluginOptions, RawSourceMapDevToolPluginOptions,
+  RawSplitChunksOptions,
 };
 
 #[napi(string_enum)]
@@ -117,18 +138,26 @@ pub enum BuiltinPluginName {
   WarnCaseSensitiveModulesPlugin,
   DataUriPlugin,
   FileUriPlugin,
+  HttpUriPlugin,
   RuntimePlugin,
   JsonModulesPlugin,
   InferAsyncModulesPlugin,
   JavascriptModulesPlugin,
   AsyncWebAssemblyModulesPlugin,
+  WebAssemblyModulesPlugin,
   AssetModulesPlugin,
   SourceMapDevToolPlugin,
   EvalSourceMapDevToolPlugin,
+  EvalDevToolModulePlugin,
   SideEffectsFlagPlugin,
   FlagDependencyExportsPlugin,
   FlagDependencyUsagePlugin,
   MangleExportsPlugin,
+  IgnorePlugin,
+  NormalModuleReplacementPlugin,
+  ContextReplacementPlugin,
+  SizeLimitsPlugin,
+  LazyCompilationPlugin,
 
   // rspack specific plugins
   HttpExternalsRspackPlugin,
@@ -203,9 +232,7 @@ impl BuiltinPlugin {
       }
       BuiltinPluginName::EnableWasmLoadingPlugin => {
         let wasm_loading_type = downcast_into::<String>(self.options)?;
-        plugins.push(enable_wasm_loading_plugin(
-          wasm_loading_type.as_str().into(),
-        ));
+        enable_wasm_loading_plugin(wasm_loading_type.as_str().into(), plugins);
       }
       BuiltinPluginName::ChunkPrefetchPreloadPlugin => {
         plugins.push(ChunkPrefetchPreloadPlugin.boxed());
@@ -296,6 +323,10 @@ impl BuiltinPlugin {
       }
       BuiltinPluginName::DataUriPlugin => plugins.push(DataUriPlugin.boxed()),
       BuiltinPluginName::FileUriPlugin => plugins.push(FileUriPlugin.boxed()),
+      BuiltinPluginName::HttpUriPlugin => {
+        let options = downcast_into::<RawHttpUriPluginOptions>(self.options)?.try_into()?;
+        plugins.push(HttpUriPlugin::new(options).boxed())
+      }
       BuiltinPluginName::RuntimePlugin => plugins.push(RuntimePlugin.boxed()),
       BuiltinPluginName::JsonModulesPlugin => plugins.push(JsonPlugin.boxed()),
       BuiltinPluginName::InferAsyncModulesPlugin => plugins.push(InferAsyncModulesPlugin.boxed()),
@@ -303,6 +334,7 @@ impl BuiltinPlugin {
       BuiltinPluginName::AsyncWebAssemblyModulesPlugin => {
         plugins.push(AsyncWasmPlugin::new().boxed())
       }
+      BuiltinPluginName::WebAssemblyModulesPlugin => plugins.push(SyncWasmPlugin::new().boxed()),
       BuiltinPluginName::AssetModulesPlugin => plugins.push(AssetPlugin.boxed()),
       BuiltinPluginName::SourceMapDevToolPlugin => {
         let options: SourceMapDevToolPluginOptions =
@@ -326,6 +358,14 @@ impl BuiltinPlugin {
         );
         plugins.push(EvalSourceMapDevToolPlugin::new(options).boxed());
       }
+      BuiltinPluginName::EvalDevToolModulePlugin => {
+        plugins.push(
+          EvalDevToolModulePlugin::new(
+            downcast_into::<RawEvalDevToolModulePluginOptions>(self.options)?.into(),
+          )
+          .boxed(),
+        );
+      }
       BuiltinPluginName::SideEffectsFlagPlugin => {
         plugins.push(SideEffectsFlagPlugin::default().boxed())
       }
@@ -338,6 +378,27 @@ impl BuiltinPlugin {
       BuiltinPluginName::MangleExportsPlugin => {
         plugins.push(MangleExportsPlugin::new(downcast_into::<bool>(self.options)?).boxed())
       }
+      BuiltinPluginName::IgnorePlugin => {
+        let condition = downcast_into::<RawIgnorePluginOptions>(self.options)?.try_into()?;
+        plugins.push(IgnorePlugin::new(condition).boxed())
+      }
+      BuiltinPluginName::NormalModuleReplacementPlugin => {
+        let plugin: NormalModuleReplacementPlugin =
+          downcast_into::<RawNormalModuleReplacementPluginOptions>(self.options)?.try_into()?;
+        plugins.push(plugin.boxed())
+      }
+      BuiltinPluginName::ContextReplacementPlugin => {
+        let options = downcast_into::<RawContextReplacementPluginOptions>(self.options)?.into();
+        plugins.push(ContextReplacementPlugin::new(options).boxed())
+      }
+      BuiltinPluginName::SizeLimitsPlugin => {
+        let options = downcast_into::<RawSizeLimitsPluginOptions>(self.options)?.try_into()?;
+        plugins.push(SizeLimitsPlugin::new(options).boxed())
+      }
+      BuiltinPluginName::LazyCompilationPlugin => {
+        let options = downcast_into::<RawLazyCompilationPluginOptions>(self.options)?.into();
+        plugins.push(LazyCompilationPlugin::new(options).boxed())
+      }
 
       // rspack specific plugins
       BuiltinPluginName::HttpExternalsRspackPlugin => {
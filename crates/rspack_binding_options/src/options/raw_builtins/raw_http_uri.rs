@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use derivative::Derivative;
+use napi::bindgen_prelude::Buffer;
+use napi::{Either, Env, JsFunction};
+use napi_derive::napi;
+use rspack_error::Result;
+use rspack_napi_shared::{
+  get_napi_env,
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  JsRegExp, JsRegExpExt, NapiResultExt,
+};
+use rspack_plugin_schemes::{AllowedUri, HttpClient, HttpResponse, HttpUriOptions};
+
+#[napi(object)]
+pub struct RawHttpClientResponse {
+  pub status: u32,
+  /// Header names should be lower cased
+  pub headers: HashMap<String, String>,
+  pub body: Buffer,
+}
+
+type RawAllowedUri = Either<String, JsRegExp>;
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object)]
+pub struct RawHttpUriPluginOptions {
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(string | RegExp)[]")]
+  pub allowed_uris: Vec<RawAllowedUri>,
+  pub lockfile_location: String,
+  pub cache_location: Option<String>,
+  pub frozen: bool,
+  pub upgrade: bool,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(url: string) => Promise<RawHttpClientResponse>")]
+  pub http_client: JsFunction,
+}
+
+/// Performs the requests with the `httpClient` function of the JavaScript side.
+struct JsHttpClient(ThreadsafeFunction<String, RawHttpClientResponse>);
+
+impl Debug for JsHttpClient {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_tuple("JsHttpClient").finish()
+  }
+}
+
+#[async_trait]
+impl HttpClient for JsHttpClient {
+  async fn get(&self, url: &str) -> Result<HttpResponse> {
+    let response = self
+      .0
+      .call(url.to_string(), ThreadsafeFunctionCallMode::NonBlocking)
+      .into_rspack_result()?
+      .await
+      .unwrap_or_else(|err| panic!("Failed to call httpClient function: {err}"))?;
+    Ok(HttpResponse {
+      status: response.status as u16,
+      headers: response.headers,
+      body: response.body.into(),
+    })
+  }
+}
+
+impl TryFrom<RawHttpUriPluginOptions> for HttpUriOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawHttpUriPluginOptions) -> Result<Self> {
+    let http_client: napi::Result<ThreadsafeFunction<String, RawHttpClientResponse>> = try {
+      let env = get_napi_env();
+      rspack_binding_macros::js_fn_into_threadsafe_fn!(value.http_client, &Env::from(env))
+    };
+    Ok(Self {
+      allowed_uris: value
+        .allowed_uris
+        .into_iter()
+        .map(|allowed| match allowed {
+          Either::A(prefix) => AllowedUri::Prefix(prefix),
+          Either::B(regex) => AllowedUri::Regex(regex.to_rspack_regex()),
+        })
+        .collect(),
+      lockfile_location: value.lockfile_location.into(),
+      cache_location: value.cache_location.map(Into::into),
+      frozen: value.frozen,
+      upgrade: value.upgrade,
+      client: Arc::new(JsHttpClient(
+        http_client.expect("convert to threadsafe function failed"),
+      )),
+    })
+  }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use derivative::Derivative;
+use napi::{Env, JsFunction};
+use napi_derive::napi;
+use rspack_error::Result;
+use rspack_napi_shared::{
+  get_napi_env,
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  JsRegExp, JsRegExpExt, NapiResultExt,
+};
+use rspack_plugin_ignore::IgnoreCondition;
+
+#[napi(object)]
+pub struct RawCheckResourceFnCtx {
+  pub resource: String,
+  pub context: String,
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object)]
+pub struct RawIgnorePluginOptions {
+  #[napi(ts_type = "RegExp")]
+  pub resource_reg_exp: Option<JsRegExp>,
+  #[napi(ts_type = "RegExp")]
+  pub context_reg_exp: Option<JsRegExp>,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(data: RawCheckResourceFnCtx) => boolean")]
+  pub check_resource: Option<JsFunction>,
+}
+
+impl TryFrom<RawIgnorePluginOptions> for IgnoreCondition {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawIgnorePluginOptions) -> Result<Self> {
+    if let Some(check_resource) = value.check_resource {
+      let func: napi::Result<ThreadsafeFunction<RawCheckResourceFnCtx, bool>> = try {
+        let env = get_napi_env();
+        rspack_binding_macros::js_fn_into_threadsafe_fn!(check_resource, &Env::from(env))
+      };
+      let func = Arc::new(func.expect("convert to threadsafe function failed"));
+      return Ok(IgnoreCondition::CheckResource(Box::new(
+        move |resource, context| {
+          let func = func.clone();
+          Box::pin(async move {
+            func
+              .call(
+                RawCheckResourceFnCtx { resource, context },
+                ThreadsafeFunctionCallMode::NonBlocking,
+              )
+              .into_rspack_result()?
+              .await
+              .unwrap_or_else(|err| panic!("Failed to call checkResource function: {err}"))
+          })
+        },
+      )));
+    }
+
+    let resource_reg_exp = value.resource_reg_exp.ok_or_else(|| {
+      rspack_error::error!("IgnorePlugin requires either resourceRegExp or checkResource")
+    })?;
+    Ok(IgnoreCondition::RegExp {
+      resource_reg_exp: resource_reg_exp.to_rspack_regex(),
+      context_reg_exp: value
+        .context_reg_exp
+        .map(|context_reg_exp| context_reg_exp.to_rspack_regex()),
+    })
+  }
+}
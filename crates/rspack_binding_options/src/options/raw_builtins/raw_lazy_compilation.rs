@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use napi_derive::napi;
+use rspack_napi_shared::{JsRegExp, JsRegExpExt};
+use rspack_plugin_runtime::{LazyCompilationOptions, RebuildBackend};
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawLazyCompilationPluginOptions {
+  pub entries: bool,
+  pub imports: bool,
+  #[napi(ts_type = "RegExp")]
+  pub test: Option<JsRegExp>,
+  /// Requested by the client with the encoded resource path to activate a module
+  pub client_url: String,
+}
+
+impl From<RawLazyCompilationPluginOptions> for LazyCompilationOptions {
+  fn from(value: RawLazyCompilationPluginOptions) -> Self {
+    Self {
+      backend: Arc::new(RebuildBackend::new(value.client_url)),
+      entries: value.entries,
+      imports: value.imports,
+      test: value.test.map(|test| test.to_rspack_regex()),
+    }
+  }
+}
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use derivative::Derivative;
+use napi::{Either, Env, JsFunction};
+use napi_derive::napi;
+use rspack_error::Result;
+use rspack_napi_shared::{
+  get_napi_env,
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  JsRegExp, JsRegExpExt, NapiResultExt,
+};
+use rspack_plugin_normal_module_replacement::{
+  CreateData, NewResource, NormalModuleReplacementPlugin, ResolveData,
+};
+
+#[napi(object)]
+pub struct RawNormalModuleReplacementResolveData {
+  pub request: String,
+  pub context: String,
+  pub create_data: Option<RawNormalModuleReplacementCreateData>,
+}
+
+#[napi(object)]
+pub struct RawNormalModuleReplacementCreateData {
+  pub resource: String,
+}
+
+impl From<ResolveData> for RawNormalModuleReplacementResolveData {
+  fn from(value: ResolveData) -> Self {
+    Self {
+      request: value.request,
+      context: value.context,
+      create_data: value
+        .create_data
+        .map(|create_data| RawNormalModuleReplacementCreateData {
+          resource: create_data.resource,
+        }),
+    }
+  }
+}
+
+impl From<RawNormalModuleReplacementResolveData> for ResolveData {
+  fn from(value: RawNormalModuleReplacementResolveData) -> Self {
+    Self {
+      request: value.request,
+      context: value.context,
+      create_data: value.create_data.map(|create_data| CreateData {
+        resource: create_data.resource,
+      }),
+    }
+  }
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object)]
+pub struct RawNormalModuleReplacementPluginOptions {
+  #[napi(ts_type = "RegExp")]
+  pub resource_reg_exp: JsRegExp,
+  #[derivative(Debug = "ignore")]
+  #[napi(
+    ts_type = "string | ((data: RawNormalModuleReplacementResolveData) => RawNormalModuleReplacementResolveData)"
+  )]
+  pub new_resource: Either<String, JsFunction>,
+}
+
+impl TryFrom<RawNormalModuleReplacementPluginOptions> for NormalModuleReplacementPlugin {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawNormalModuleReplacementPluginOptions) -> Result<Self> {
+    let new_resource = match value.new_resource {
+      Either::A(request) => NewResource::String(request),
+      Either::B(f) => {
+        let func: napi::Result<
+          ThreadsafeFunction<
+            RawNormalModuleReplacementResolveData,
+            RawNormalModuleReplacementResolveData,
+          >,
+        > = try {
+          let env = get_napi_env();
+          rspack_binding_macros::js_fn_into_threadsafe_fn!(f, &Env::from(env))
+        };
+        let func = Arc::new(func.expect("convert to threadsafe function failed"));
+        NewResource::Fn(Box::new(move |data| {
+          let func = func.clone();
+          Box::pin(async move {
+            func
+              .call(data.into(), ThreadsafeFunctionCallMode::NonBlocking)
+              .into_rspack_result()?
+              .await
+              .unwrap_or_else(|err| panic!("Failed to call newResource function: {err}"))
+              .map(Into::into)
+          })
+        }))
+      }
+    };
+    Ok(NormalModuleReplacementPlugin::new(
+      value.resource_reg_exp.to_rspack_regex(),
+      new_resource,
+    ))
+  }
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use derivative::Derivative;
+use napi::{Env, JsFunction};
+use napi_derive::napi;
+use rspack_error::Result;
+use rspack_napi_shared::{
+  get_napi_env,
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  NapiResultExt,
+};
+use rspack_plugin_size_limits::{PerformanceHints, SizeLimitsPluginOptions};
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object)]
+pub struct RawSizeLimitsPluginOptions {
+  #[napi(ts_type = "\"warning\" | \"error\"")]
+  pub hints: String,
+  pub max_asset_size: f64,
+  pub max_entrypoint_size: f64,
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "(assetFilename: string) => boolean")]
+  pub asset_filter: Option<JsFunction>,
+}
+
+impl TryFrom<RawSizeLimitsPluginOptions> for SizeLimitsPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawSizeLimitsPluginOptions) -> Result<Self> {
+    let hints = match value.hints.as_str() {
+      "warning" => PerformanceHints::Warning,
+      "error" => PerformanceHints::Error,
+      hints => {
+        return Err(rspack_error::error!(
+          "Unknown performance hints {hints}, expected \"warning\" or \"error\""
+        ))
+      }
+    };
+    let asset_filter = value.asset_filter.map(|asset_filter| {
+      let func: napi::Result<ThreadsafeFunction<String, bool>> = try {
+        let env = get_napi_env();
+        rspack_binding_macros::js_fn_into_threadsafe_fn!(asset_filter, &Env::from(env))
+      };
+      let func = Arc::new(func.expect("convert to threadsafe function failed"));
+      Box::new(move |name: String| {
+        let func = func.clone();
+        Box::pin(async move {
+          func
+            .call(name, ThreadsafeFunctionCallMode::NonBlocking)
+            .into_rspack_result()?
+            .await
+            .unwrap_or_else(|err| panic!("Failed to call assetFilter function: {err}"))
+        })
+      }) as rspack_plugin_size_limits::AssetFilterFn
+    });
+    Ok(Self {
+      hints,
+      max_asset_size: value.max_asset_size,
+      max_entrypoint_size: value.max_entrypoint_size,
+      asset_filter,
+    })
+  }
+}
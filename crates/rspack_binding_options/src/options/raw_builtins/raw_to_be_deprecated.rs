@@ -150,6 +150,8 @@ impl From<RawRelayConfig> for RelayOptions {
 #[napi(object)]
 pub struct RawCssPluginConfig {
   pub modules: RawCssModulesConfig,
+  /// Browsers selected by the `browserslist` targets of `target`
+  pub targets: Vec<String>,
 }
 
 #[derive(Deserialize, Debug, Serialize, Clone)]
@@ -187,6 +189,7 @@ impl RawBuiltins {
     if let Some(css) = self.css {
       let options = CssConfig {
         modules: css.modules.try_into()?,
+        targets: css.targets,
       };
       plugins.push(CssPlugin::new(options).boxed());
     }
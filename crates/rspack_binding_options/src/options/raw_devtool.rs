@@ -6,8 +6,8 @@ use napi_derive::napi;
 use rspack_napi_shared::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use rspack_napi_shared::{get_napi_env, NapiResultExt};
 use rspack_plugin_devtool::{
-  Append, ModuleFilenameTemplate, ModuleFilenameTemplateFnCtx, SourceMapDevToolPluginOptions,
-  TestFn,
+  Append, EvalDevToolModulePluginOptions, ModuleFilenameTemplate, ModuleFilenameTemplateFnCtx,
+  SourceMapDevToolPluginOptions, TestFn,
 };
 use serde::Deserialize;
 
@@ -141,12 +141,16 @@ pub struct RawSourceMapDevToolPluginOptions {
   #[serde(skip_deserializing)]
   #[napi(ts_type = "(text: string) => boolean")]
   pub test: Option<JsFunction>,
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "(text: string) => boolean")]
+  pub ignore_list: Option<JsFunction>,
 }
 
 impl From<RawSourceMapDevToolPluginOptions> for SourceMapDevToolPluginOptions {
   fn from(opts: RawSourceMapDevToolPluginOptions) -> Self {
     let append = opts.append.map(normalize_raw_append);
     let test = opts.test.map(normalize_raw_test);
+    let ignore_list = opts.ignore_list.map(normalize_raw_test);
     let filename = opts.filename.and_then(|raw| match raw {
       Either::A(_) => None,
       Either::B(s) => Some(s),
@@ -175,6 +179,29 @@ impl From<RawSourceMapDevToolPluginOptions> for SourceMapDevToolPluginOptions {
       module: opts.module.unwrap_or(false),
       source_root: opts.source_root,
       test,
+      ignore_list,
+    }
+  }
+}
+
+#[derive(Deserialize)]
+#[napi(object)]
+pub struct RawEvalDevToolModulePluginOptions {
+  pub namespace: Option<String>,
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "string | Function")]
+  pub module_filename_template: Option<RawModuleFilenameTemplate>,
+  pub source_url_comment: Option<String>,
+}
+
+impl From<RawEvalDevToolModulePluginOptions> for EvalDevToolModulePluginOptions {
+  fn from(opts: RawEvalDevToolModulePluginOptions) -> Self {
+    Self {
+      namespace: opts.namespace,
+      module_filename_template: opts
+        .module_filename_template
+        .map(normalize_raw_module_filename_template),
+      source_url_comment: opts.source_url_comment,
     }
   }
 }
@@ -19,12 +19,14 @@ pub struct RawEntryPluginOptions {
 pub struct RawEntryOptions {
   pub name: Option<String>,
   pub runtime: Option<String>,
+  pub depend_on: Option<Vec<String>>,
   pub chunk_loading: Option<String>,
   pub async_chunks: Option<bool>,
   pub public_path: Option<String>,
   pub base_uri: Option<String>,
   pub filename: Option<String>,
   pub library: Option<RawLibraryOptions>,
+  pub layer: Option<String>,
 }
 
 impl From<RawEntryOptions> for EntryOptions {
@@ -32,12 +34,14 @@ impl From<RawEntryOptions> for EntryOptions {
     Self {
       name: value.name,
       runtime: value.runtime,
+      depend_on: value.depend_on,
       chunk_loading: value.chunk_loading.as_deref().map(Into::into),
       async_chunks: value.async_chunks,
       public_path: value.public_path.map(Into::into),
       base_uri: value.base_uri,
       filename: value.filename.map(Into::into),
       library: value.library.map(Into::into),
+      layer: value.layer,
     }
   }
 }
@@ -15,6 +15,7 @@ pub struct RawRspackFuture {
 pub struct RawExperiments {
   pub new_split_chunks: bool,
   pub top_level_await: bool,
+  pub layers: bool,
   pub rspack_future: RawRspackFuture,
 }
 
@@ -270,9 +270,11 @@ pub struct RawModuleRule {
   pub generator: Option<RawGeneratorOptions>,
   pub resolve: Option<RawResolveOptions>,
   pub issuer: Option<RawRuleSetCondition>,
+  pub issuer_layer: Option<RawRuleSetCondition>,
   pub dependency: Option<RawRuleSetCondition>,
   pub scheme: Option<RawRuleSetCondition>,
   pub mimetype: Option<RawRuleSetCondition>,
+  pub layer: Option<String>,
   pub one_of: Option<Vec<RawModuleRule>>,
   pub rules: Option<Vec<RawModuleRule>>,
   /// Specifies the category of the loader. No value means normal loader.
@@ -319,6 +321,7 @@ pub struct RawJavascriptParserOptions {
   pub dynamic_import_preload: String,
   pub dynamic_import_prefetch: String,
   pub url: String,
+  pub worker: Vec<String>,
 }
 
 impl From<RawJavascriptParserOptions> for JavascriptParserOptions {
@@ -328,6 +331,7 @@ impl From<RawJavascriptParserOptions> for JavascriptParserOptions {
       dynamic_import_preload: JavascriptParserOrder::from(value.dynamic_import_preload.as_str()),
       dynamic_import_prefetch: JavascriptParserOrder::from(value.dynamic_import_prefetch.as_str()),
       url: JavascriptParserUrl::from(value.url.as_str()),
+      worker: value.worker,
     }
   }
 }
@@ -681,9 +685,11 @@ impl TryFrom<RawModuleRule> for ModuleRule {
       resolve: value.resolve.map(|raw| raw.try_into()).transpose()?,
       side_effects: value.side_effects,
       issuer: value.issuer.map(|raw| raw.try_into()).transpose()?,
+      issuer_layer: value.issuer_layer.map(|raw| raw.try_into()).transpose()?,
       dependency: value.dependency.map(|raw| raw.try_into()).transpose()?,
       scheme: value.scheme.map(|raw| raw.try_into()).transpose()?,
       mimetype: value.mimetype.map(|raw| raw.try_into()).transpose()?,
+      layer: value.layer,
       one_of,
       rules,
       enforce,
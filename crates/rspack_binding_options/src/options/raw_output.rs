@@ -220,8 +220,14 @@ impl TryFrom<RawOutputOptions> for OutputOptions {
       module: value.module,
       trusted_types: value.trusted_types.map(Into::into),
       source_map_filename: value.source_map_filename.into(),
-      hash_function: value.hash_function.as_str().into(),
-      hash_digest: value.hash_digest.as_str().into(),
+      hash_function: value
+        .hash_function
+        .parse()
+        .map_err(|e: String| rspack_error::error!(e))?,
+      hash_digest: value
+        .hash_digest
+        .parse()
+        .map_err(|e: String| rspack_error::error!(e))?,
       hash_digest_length: value.hash_digest_length as usize,
       hash_salt: value.hash_salt.into(),
       async_chunks: value.async_chunks,
@@ -45,13 +45,14 @@ pub struct RawSplitChunksOptions {
   pub min_chunks: Option<u32>,
   pub hide_path_info: Option<bool>,
   pub min_size: Option<f64>,
-  //   pub min_size_reduction: usize,
+  pub min_size_reduction: Option<f64>,
   pub enforce_size_threshold: Option<f64>,
   pub min_remaining_size: Option<f64>,
   // layer: String,
   pub max_size: Option<f64>,
   pub max_async_size: Option<f64>,
   pub max_initial_size: Option<f64>,
+  pub used_exports: Option<bool>,
 }
 
 #[derive(Derivative, Deserialize)]
@@ -79,15 +80,18 @@ pub struct RawCacheGroupOptions {
   #[napi(ts_type = "RegExp | string")]
   #[derivative(Debug = "ignore")]
   pub r#type: Option<Either<JsRegExp, JsString>>,
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "RegExp | string")]
+  #[derivative(Debug = "ignore")]
+  pub layer: Option<Either<JsRegExp, JsString>>,
   pub automatic_name_delimiter: Option<String>,
   //   pub max_async_requests: usize,
   //   pub max_initial_requests: usize,
   pub min_chunks: Option<u32>,
   pub min_size: Option<f64>,
-  //   pub min_size_reduction: usize,
-  //   pub enforce_size_threshold: usize,
-  //   pub min_remaining_size: usize,
-  // layer: String,
+  pub min_size_reduction: Option<f64>,
+  pub enforce_size_threshold: Option<f64>,
+  pub min_remaining_size: Option<f64>,
   pub max_size: Option<f64>,
   pub max_async_size: Option<f64>,
   pub max_initial_size: Option<f64>,
@@ -95,7 +99,7 @@ pub struct RawCacheGroupOptions {
   #[napi(ts_type = "string | false | Function")]
   #[derivative(Debug = "ignore")]
   pub name: Option<RawChunkOptionName>,
-  // used_exports: bool,
+  pub used_exports: Option<bool>,
   pub reuse_existing_chunk: Option<bool>,
   pub enforce: Option<bool>,
 }
@@ -125,6 +129,11 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks_new::PluginOptio
     let empty_sizes = SplitChunkSizes::empty();
 
     let overall_min_size = create_sizes(raw_opts.min_size);
+    let overall_min_size_reduction = create_sizes(raw_opts.min_size_reduction);
+    let overall_min_remaining_size =
+      create_sizes(raw_opts.min_remaining_size).merge(&overall_min_size);
+    let overall_enforce_size_threshold = create_sizes(raw_opts.enforce_size_threshold);
+    let overall_used_exports = raw_opts.used_exports.unwrap_or_default();
     let overall_max_size = create_sizes(raw_opts.max_size);
     let overall_max_async_size = create_sizes(raw_opts.max_async_size).merge(&overall_max_size);
     let overall_max_initial_size = create_sizes(raw_opts.max_initial_size).merge(&overall_max_size);
@@ -146,6 +155,27 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks_new::PluginOptio
             &overall_min_size
           });
 
+          let min_size_reduction = create_sizes(v.min_size_reduction).merge(if enforce {
+            &empty_sizes
+          } else {
+            &overall_min_size_reduction
+          });
+
+          // like the overall one, `min_remaining_size` of a cache group defaults to its own `min_size`
+          let min_remaining_size = create_sizes(v.min_remaining_size)
+            .merge(&create_sizes(v.min_size))
+            .merge(if enforce {
+              &empty_sizes
+            } else {
+              &overall_min_remaining_size
+            });
+
+          let enforce_size_threshold = create_sizes(v.enforce_size_threshold).merge(if enforce {
+            &empty_sizes
+          } else {
+            &overall_enforce_size_threshold
+          });
+
           let max_size = create_sizes(v.max_size);
 
           let max_async_size = create_sizes(v.max_async_size)
@@ -176,6 +206,11 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks_new::PluginOptio
             .map(create_module_type_filter)
             .unwrap_or_else(rspack_plugin_split_chunks_new::create_default_module_type_filter);
 
+          let layer = v
+            .layer
+            .map(create_module_layer_filter)
+            .unwrap_or_else(rspack_plugin_split_chunks_new::create_default_module_layer_filter);
+
           let mut name = v.name.map_or(default_chunk_option_name(), |name| {
             normalize_raw_chunk_name(name)
           });
@@ -196,7 +231,12 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks_new::PluginOptio
                 .unwrap_or_else(rspack_plugin_split_chunks_new::create_async_chunk_filter)
             }),
             min_chunks,
+            enforce,
             min_size,
+            min_size_reduction,
+            min_remaining_size,
+            enforce_size_threshold,
+            used_exports: v.used_exports.unwrap_or(overall_used_exports),
             automatic_name_delimiter: v
               .automatic_name_delimiter
               .unwrap_or(overall_automatic_name_delimiter.clone()),
@@ -209,6 +249,7 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks_new::PluginOptio
             max_async_size,
             max_initial_size,
             r#type,
+            layer,
           }
         }),
     );
@@ -282,3 +323,23 @@ fn create_module_type_filter(
     }
   }
 }
+
+/// Aligns with `checkModuleLayer` of webpack, an empty string matches modules without a layer,
+/// other strings match layers starting with them.
+fn create_module_layer_filter(
+  raw: Either<JsRegExp, JsString>,
+) -> rspack_plugin_split_chunks_new::ModuleLayerFilter {
+  match raw {
+    Either::A(js_reg) => {
+      let regex = js_reg.to_rspack_regex();
+      Arc::new(move |m| m.get_layer().map_or(false, |layer| regex.test(layer)))
+    }
+    Either::B(js_str) => {
+      let layer_str = js_str.into_string();
+      Arc::new(move |m| match m.get_layer() {
+        Some(layer) => !layer_str.is_empty() && layer.starts_with(layer_str.as_str()),
+        None => layer_str.is_empty(),
+      })
+    }
+  }
+}
@@ -1,6 +1,6 @@
 use napi::Result;
 use napi_derive::napi;
-use rspack_core::{ChunkUkey, SourceType};
+use rspack_core::{ChunkUkey, ModuleIdentifier, SourceType};
 
 use crate::{JsChunk, JsCompilation, JsModule, ToJsModule};
 
@@ -72,3 +72,23 @@ pub fn get_chunk_modules_iterable_by_source_type(
       .collect(),
   )
 }
+
+#[napi(js_name = "__chunk_graph_inner_get_module_chunks")]
+pub fn get_module_chunks(module_identifier: String, compilation: &JsCompilation) -> Vec<JsChunk> {
+  let compilation = &compilation.inner;
+  let Some(chunk_graph_module) = compilation
+    .chunk_graph
+    .chunk_graph_module_by_module_identifier
+    .get(&ModuleIdentifier::from(module_identifier))
+  else {
+    return vec![];
+  };
+
+  let mut chunks = chunk_graph_module
+    .chunks
+    .iter()
+    .map(|c| compilation.chunk_by_ukey.expect_get(c))
+    .collect::<Vec<_>>();
+  chunks.sort_by_key(|c| c.ukey);
+  chunks.into_iter().map(JsChunk::from).collect()
+}
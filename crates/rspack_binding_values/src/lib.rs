@@ -7,6 +7,7 @@ mod codegen_result;
 mod compilation;
 mod hooks;
 mod module;
+mod module_graph;
 mod normal_module_factory;
 mod path_data;
 mod source;
@@ -21,6 +22,7 @@ pub use codegen_result::*;
 pub use compilation::*;
 pub use hooks::*;
 pub use module::*;
+pub use module_graph::*;
 pub use normal_module_factory::*;
 pub use path_data::*;
 pub use source::*;
@@ -0,0 +1,132 @@
+use napi_derive::napi;
+use rspack_core::{
+  ExportInfoProvided, ModuleGraph, ModuleGraphConnection, ModuleIdentifier, UsageState,
+};
+
+use crate::{JsCompilation, JsModule, ToJsModule};
+
+#[napi(object)]
+pub struct JsModuleGraphConnection {
+  /// Identifier of the module that contains the dependency, `None` for entries
+  pub origin_module_identifier: Option<String>,
+  pub module_identifier: String,
+  pub dependency_type: String,
+  pub user_request: Option<String>,
+  pub active: bool,
+  pub conditional: bool,
+}
+
+impl JsModuleGraphConnection {
+  fn new(connection: &ModuleGraphConnection, module_graph: &ModuleGraph) -> Self {
+    let dependency = module_graph.dependency_by_id(&connection.dependency_id);
+    Self {
+      origin_module_identifier: connection
+        .original_module_identifier
+        .map(|identifier| identifier.to_string()),
+      module_identifier: connection.module_identifier.to_string(),
+      dependency_type: dependency
+        .map(|dependency| dependency.dependency_type().to_string())
+        .unwrap_or_default(),
+      user_request: dependency
+        .and_then(|dependency| dependency.as_module_dependency())
+        .map(|dependency| dependency.user_request().to_string()),
+      active: connection.active,
+      conditional: connection.conditional,
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsExportInfo {
+  pub name: String,
+  /// `None` when it's unknown whether the export is provided
+  pub provided: Option<bool>,
+  #[napi(ts_type = r#""unused" | "onlyPropertiesUsed" | "noInfo" | "unknown" | "used""#)]
+  pub used: String,
+}
+
+fn usage_state_to_string(state: UsageState) -> String {
+  match state {
+    UsageState::Unused => "unused",
+    UsageState::OnlyPropertiesUsed => "onlyPropertiesUsed",
+    UsageState::NoInfo => "noInfo",
+    UsageState::Unknown => "unknown",
+    UsageState::Used => "used",
+  }
+  .to_string()
+}
+
+#[napi(js_name = "__module_graph_inner_get_module")]
+pub fn get_module(module_identifier: String, compilation: &JsCompilation) -> Option<JsModule> {
+  compilation
+    .inner
+    .module_graph
+    .module_by_identifier(&ModuleIdentifier::from(module_identifier))
+    .and_then(|module| module.to_js_module().ok())
+}
+
+#[napi(js_name = "__module_graph_inner_get_outgoing_connections")]
+pub fn get_outgoing_connections(
+  module_identifier: String,
+  compilation: &JsCompilation,
+) -> Vec<JsModuleGraphConnection> {
+  let module_graph = &compilation.inner.module_graph;
+  let mut connections = module_graph
+    .get_outgoing_connections_by_identifier(&ModuleIdentifier::from(module_identifier))
+    .into_iter()
+    .map(|connection| JsModuleGraphConnection::new(connection, module_graph))
+    .collect::<Vec<_>>();
+  connections.sort_by(|a, b| a.module_identifier.cmp(&b.module_identifier));
+  connections
+}
+
+#[napi(js_name = "__module_graph_inner_get_incoming_connections")]
+pub fn get_incoming_connections(
+  module_identifier: String,
+  compilation: &JsCompilation,
+) -> Vec<JsModuleGraphConnection> {
+  let module_graph = &compilation.inner.module_graph;
+  let Some(module) = module_graph.module_by_identifier(&ModuleIdentifier::from(module_identifier))
+  else {
+    return vec![];
+  };
+  let mut connections = module_graph
+    .get_incoming_connections(module)
+    .into_iter()
+    .map(|connection| JsModuleGraphConnection::new(connection, module_graph))
+    .collect::<Vec<_>>();
+  connections.sort_by(|a, b| a.origin_module_identifier.cmp(&b.origin_module_identifier));
+  connections
+}
+
+#[napi(js_name = "__module_graph_inner_get_exports_info")]
+pub fn get_exports_info(
+  module_identifier: String,
+  compilation: &JsCompilation,
+) -> Vec<JsExportInfo> {
+  let module_graph = &compilation.inner.module_graph;
+  let module_identifier = ModuleIdentifier::from(module_identifier);
+  if module_graph
+    .module_graph_module_by_identifier(&module_identifier)
+    .is_none()
+  {
+    return vec![];
+  }
+  module_graph
+    .get_exports_info(&module_identifier)
+    .exports
+    .iter()
+    .map(|(name, export_info_id)| {
+      let export_info = export_info_id.get_export_info(module_graph);
+      JsExportInfo {
+        name: name.to_string(),
+        provided: match export_info.provided {
+          Some(ExportInfoProvided::True) => Some(true),
+          Some(ExportInfoProvided::False) => Some(false),
+          Some(ExportInfoProvided::Null) | None => None,
+        },
+        used: usage_state_to_string(export_info.get_used(None)),
+      }
+    })
+    .collect()
+}
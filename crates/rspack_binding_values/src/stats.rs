@@ -231,11 +231,19 @@ pub struct JsStatsModule {
   pub issuer_id: Option<String>,
   pub issuer_path: Vec<JsStatsModuleIssuer>,
   pub name_for_condition: Option<String>,
+  pub layer: Option<String>,
   pub reasons: Option<Vec<JsStatsModuleReason>>,
   pub assets: Option<Vec<String>>,
   pub source: Option<Either<String, Buffer>>,
   pub profile: Option<JsStatsModuleProfile>,
   pub orphan: bool,
+  pub depth: Option<u32>,
+  pub provided_exports: Option<Vec<String>>,
+  pub used_exports: Option<Either<bool, Vec<String>>>,
+  pub optimization_bailout: Vec<String>,
+  pub cacheable: bool,
+  pub built: bool,
+  pub cached: bool,
 }
 
 impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
@@ -268,6 +276,7 @@ impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
       issuer_name: stats.issuer_name,
       issuer_id: stats.issuer_id,
       name_for_condition: stats.name_for_condition,
+      layer: stats.layer,
       issuer_path: stats.issuer_path.into_iter().map(Into::into).collect(),
       reasons: stats
         .reasons
@@ -276,6 +285,16 @@ impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
       source,
       profile: stats.profile.map(|p| p.into()),
       orphan: stats.orphan,
+      depth: stats.depth.map(|d| d as u32),
+      provided_exports: stats.provided_exports,
+      used_exports: stats.used_exports.map(|used_exports| match used_exports {
+        rspack_core::StatsUsedExports::Bool(b) => Either::A(b),
+        rspack_core::StatsUsedExports::Vec(v) => Either::B(v),
+      }),
+      optimization_bailout: stats.optimization_bailout,
+      cacheable: stats.cacheable,
+      built: stats.built,
+      cached: stats.cached,
     })
   }
 }
@@ -364,6 +383,7 @@ pub struct JsStatsChunk {
   pub parents: Option<Vec<String>>,
   pub children: Option<Vec<String>>,
   pub siblings: Option<Vec<String>>,
+  pub origins: Vec<JsOriginRecord>,
 }
 
 impl TryFrom<rspack_core::StatsChunk<'_>> for JsStatsChunk {
@@ -385,10 +405,34 @@ impl TryFrom<rspack_core::StatsChunk<'_>> for JsStatsChunk {
       parents: stats.parents,
       children: stats.children,
       siblings: stats.siblings,
+      origins: stats.origins.into_iter().map(Into::into).collect(),
     })
   }
 }
 
+#[napi(object)]
+pub struct JsOriginRecord {
+  pub module: String,
+  pub module_identifier: String,
+  pub module_name: String,
+  pub module_id: String,
+  pub loc: String,
+  pub request: String,
+}
+
+impl From<rspack_core::StatsOriginRecord> for JsOriginRecord {
+  fn from(stats: rspack_core::StatsOriginRecord) -> Self {
+    Self {
+      module: stats.module,
+      module_identifier: stats.module_identifier,
+      module_name: stats.module_name,
+      module_id: stats.module_id,
+      loc: stats.loc,
+      request: stats.request,
+    }
+  }
+}
+
 #[napi(object)]
 pub struct JsStatsChunkGroupAsset {
   pub name: String,
@@ -0,0 +1,279 @@
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
+
+use argh::FromArgs;
+use rspack_core::{Compilation, Compiler, WatchOptions};
+use rspack_error::{
+  emitter::{DiagnosticDisplay, StdioDiagnosticDisplay},
+  Diagnostic,
+};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_testing::TestConfig;
+
+/// The build finished, but the compilation has errors
+const EXIT_COMPILATION_ERROR: u8 = 1;
+/// The config couldn't be loaded or the compiler failed before finishing the build
+const EXIT_FATAL_ERROR: u8 = 2;
+
+#[derive(FromArgs)]
+/// build a project with rspack, configured by a json file in the format of `test.config.json`
+struct Args {
+  /// path of the config file (default: "rspack.config.json")
+  #[argh(option, short = 'c', default = "PathBuf::from(\"rspack.config.json\")")]
+  config: PathBuf,
+
+  /// root of the project (default: the directory of the config file)
+  #[argh(option)]
+  context: Option<PathBuf>,
+
+  /// rebuild whenever a dependency of the build changes
+  #[argh(switch, short = 'w')]
+  watch: bool,
+
+  /// enable tracing with the given filter, e.g. "info" or "rspack_core=trace"
+  #[argh(option)]
+  trace: Option<String>,
+
+  /// tracing layer: "logger" | "chrome" (default: "logger")
+  #[argh(option, default = "String::from(\"logger\")")]
+  trace_layer: String,
+
+  /// where traces are written: "stdout" | "stderr" | a file path (default: "stdout")
+  #[argh(option, default = "String::from(\"stdout\")")]
+  trace_output: String,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+  let args: Args = argh::from_env();
+
+  // the chrome layer flushes when the guard is dropped, so it has to live until the end
+  let _guard = match (&args.trace, args.trace_layer.as_str()) {
+    (None, _) => None,
+    (Some(filter), "logger") => {
+      rspack_tracing::enable_tracing_by_env(filter, &args.trace_output);
+      None
+    }
+    (Some(filter), "chrome") => {
+      rspack_tracing::enable_tracing_by_env_with_chrome_layer(filter, &args.trace_output)
+    }
+    (Some(_), layer) => {
+      eprintln!("Unknown trace layer: {layer}, expected \"logger\" or \"chrome\"");
+      return ExitCode::from(EXIT_FATAL_ERROR);
+    }
+  };
+
+  let (config, context) = match load_config(&args.config, args.context) {
+    Ok(loaded) => loaded,
+    Err(message) => {
+      eprintln!("{message}");
+      return ExitCode::from(EXIT_FATAL_ERROR);
+    }
+  };
+  let (options, plugins) = config.apply(context);
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+
+  if args.watch {
+    let mut start = Instant::now();
+    let result = compiler
+      .watch(WatchOptions::default(), |result| {
+        match result {
+          Ok(compilation) => {
+            report(compilation, start);
+          }
+          Err(e) => emit_fatal_error(e),
+        }
+        start = Instant::now();
+        ControlFlow::Continue(())
+      })
+      .await;
+    return match result {
+      Ok(()) => ExitCode::SUCCESS,
+      Err(e) => {
+        emit_fatal_error(e);
+        ExitCode::from(EXIT_FATAL_ERROR)
+      }
+    };
+  }
+
+  let start = Instant::now();
+  match compiler.build().await {
+    Ok(()) if report(&compiler.compilation, start) => ExitCode::SUCCESS,
+    Ok(()) => ExitCode::from(EXIT_COMPILATION_ERROR),
+    Err(e) => {
+      emit_fatal_error(e);
+      ExitCode::from(EXIT_FATAL_ERROR)
+    }
+  }
+}
+
+/// Read the config, the context defaults to the directory of the config file.
+fn load_config(path: &Path, context: Option<PathBuf>) -> Result<(TestConfig, PathBuf), String> {
+  let path = path
+    .canonicalize()
+    .map_err(|e| format!("Failed to find config {}: {e}", path.display()))?;
+  let content = std::fs::read_to_string(&path)
+    .map_err(|e| format!("Failed to read config {}: {e}", path.display()))?;
+  let config: TestConfig = serde_json::from_str(&content)
+    .map_err(|e| format!("Failed to parse config {}: {e}", path.display()))?;
+
+  let context = match context {
+    Some(context) => context
+      .canonicalize()
+      .map_err(|e| format!("Failed to find context {}: {e}", context.display()))?,
+    None => path
+      .parent()
+      .expect("config file should have a parent directory")
+      .to_path_buf(),
+  };
+  Ok((config, context))
+}
+
+/// Print diagnostics and emitted assets of the compilation, returns `false` if it has errors.
+fn report(compilation: &Compilation, start: Instant) -> bool {
+  let stats = compilation.get_stats();
+  if let Err(e) = stats.emit_diagnostics() {
+    eprintln!("Failed to print diagnostics: {e}");
+  }
+
+  let (mut assets, _) = stats.get_assets();
+  assets.sort_by(|a, b| a.name.cmp(&b.name));
+  for asset in assets {
+    println!("{:>12}  {}", format_size(asset.size), asset.name);
+  }
+
+  let errors = compilation.get_errors().count();
+  let warnings = compilation.get_warnings().count();
+  println!(
+    "compiled {} in {} ms ({errors} errors, {warnings} warnings)",
+    stats.get_hash().unwrap_or_default(),
+    start.elapsed().as_millis()
+  );
+  errors == 0
+}
+
+fn emit_fatal_error(error: rspack_error::Error) {
+  let mut displayer = StdioDiagnosticDisplay::default();
+  if displayer.emit_diagnostic(&Diagnostic::from(error)).is_err() {
+    eprintln!("Failed to print the error");
+  }
+}
+
+fn format_size(size: f64) -> String {
+  if size < 1024.0 {
+    format!("{size} B")
+  } else if size < 1024.0 * 1024.0 {
+    format!("{:.2} KiB", size / 1024.0)
+  } else {
+    format!("{:.2} MiB", size / 1024.0 / 1024.0)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+      .join("tests/fixtures")
+      .join(name)
+  }
+
+  fn parse_args(args: &[&str]) -> Result<Args, argh::EarlyExit> {
+    Args::from_args(&["rspack"], args)
+  }
+
+  #[test]
+  fn should_parse_default_args() {
+    let args = parse_args(&[]).expect("should parse");
+    assert_eq!(args.config, PathBuf::from("rspack.config.json"));
+    assert_eq!(args.context, None);
+    assert!(!args.watch);
+    assert_eq!(args.trace, None);
+    assert_eq!(args.trace_layer, "logger");
+    assert_eq!(args.trace_output, "stdout");
+  }
+
+  #[test]
+  fn should_parse_args() {
+    let args = parse_args(&[
+      "-c",
+      "configs/dev.json",
+      "--context",
+      "src",
+      "-w",
+      "--trace",
+      "rspack_core=trace",
+      "--trace-layer",
+      "chrome",
+      "--trace-output",
+      "trace.json",
+    ])
+    .expect("should parse");
+    assert_eq!(args.config, PathBuf::from("configs/dev.json"));
+    assert_eq!(args.context, Some(PathBuf::from("src")));
+    assert!(args.watch);
+    assert_eq!(args.trace.as_deref(), Some("rspack_core=trace"));
+    assert_eq!(args.trace_layer, "chrome");
+    assert_eq!(args.trace_output, "trace.json");
+  }
+
+  #[test]
+  fn should_reject_unknown_args() {
+    assert!(parse_args(&["--unknown"]).is_err());
+    assert!(parse_args(&["--config"]).is_err());
+  }
+
+  #[test]
+  fn should_load_config_with_context_of_config_directory() {
+    let dir = fixture("basic");
+    let (_, context) =
+      load_config(&dir.join("rspack.config.json"), None).expect("should load config");
+    assert_eq!(context, dir.canonicalize().expect("should canonicalize"));
+  }
+
+  #[test]
+  fn should_load_config_with_given_context() {
+    let (_, context) = load_config(
+      &fixture("basic").join("rspack.config.json"),
+      Some(fixture("invalid")),
+    )
+    .expect("should load config");
+    assert_eq!(
+      context,
+      fixture("invalid")
+        .canonicalize()
+        .expect("should canonicalize")
+    );
+  }
+
+  #[test]
+  fn should_fail_to_load_missing_config() {
+    let message =
+      load_config(&fixture("missing").join("rspack.config.json"), None).expect_err("should fail");
+    assert!(message.starts_with("Failed to find config"), "{message}");
+
+    let message = load_config(
+      &fixture("basic").join("rspack.config.json"),
+      Some(fixture("missing")),
+    )
+    .expect_err("should fail");
+    assert!(message.starts_with("Failed to find context"), "{message}");
+  }
+
+  #[test]
+  fn should_fail_to_load_invalid_config() {
+    let message =
+      load_config(&fixture("invalid").join("rspack.config.json"), None).expect_err("should fail");
+    assert!(message.starts_with("Failed to parse config"), "{message}");
+  }
+
+  #[test]
+  fn should_format_size() {
+    assert_eq!(format_size(512.0), "512 B");
+    assert_eq!(format_size(2048.0), "2.00 KiB");
+    assert_eq!(format_size(3.5 * 1024.0 * 1024.0), "3.50 MiB");
+  }
+}
@@ -113,16 +113,19 @@ impl<'me> CodeSplitter<'me> {
         chunk.filename_template = Some(filename.clone());
       }
       chunk.chunk_reasons.push(format!("Entrypoint({name})",));
-      self
-        .remove_parent_modules_context
-        .add_root_chunk(chunk.ukey);
+      // entries with `depend_on` get their parents after all entrypoints are created
+      if options.depend_on.is_none() {
+        self
+          .remove_parent_modules_context
+          .add_root_chunk(chunk.ukey);
+      }
 
       compilation.chunk_graph.add_chunk(chunk.ukey);
 
       let mut entrypoint = ChunkGroup::new(
         ChunkGroupKind::new_entrypoint(true, Box::new(options.clone())),
         ChunkGroupInfo {
-          runtime: get_entry_runtime(name, options),
+          runtime: get_entry_runtime(name, options, &compilation.entries),
           chunk_loading: !matches!(
             options
               .chunk_loading
@@ -135,11 +138,19 @@ impl<'me> CodeSplitter<'me> {
             .unwrap_or(compilation.options.output.async_chunks),
         },
       );
-      if options.runtime.is_none() {
+      if options.depend_on.is_none() && options.runtime.is_none() {
         entrypoint.set_runtime_chunk(chunk.ukey);
       }
       entrypoint.set_entry_point_chunk(chunk.ukey);
       entrypoint.connect_chunk(chunk);
+      for dependency_id in &dependencies {
+        let request = compilation
+          .module_graph
+          .dependency_by_id(dependency_id)
+          .and_then(|dep| dep.as_module_dependency())
+          .map(|dep| dep.request().to_string());
+        entrypoint.add_origin(None, None, request);
+      }
 
       compilation
         .named_chunk_groups
@@ -222,26 +233,82 @@ impl<'me> CodeSplitter<'me> {
     }
 
     let mut runtime_chunks = HashSet::default();
-    let mut runtime_error = None;
-    for (name, entry_data) in &compilation.entries {
+    let mut errors = vec![];
+    'outer: for (name, entry_data) in &compilation.entries {
       let options = &entry_data.options;
+      let ukey = compilation
+        .entrypoints
+        .get(name)
+        .ok_or_else(|| error!("no entrypoints found"))?;
+
+      if options.depend_on.is_some() && options.runtime.is_some() {
+        errors.push(error!(
+          "Entrypoint '{name}' has 'dependOn' and 'runtime' specified. This is not valid.
+Entrypoints that depend on other entrypoints do not have their own runtime.
+They will use the runtime(s) from referenced entrypoints instead.
+Remove the 'runtime' option from the entrypoint."
+        ));
+      }
 
-      if let Some(runtime) = &options.runtime {
-        let ukey = compilation
-          .entrypoints
-          .get(name)
-          .ok_or_else(|| error!("no entrypoints found"))?;
+      if let Some(depend_on) = &options.depend_on {
+        let entry_point = compilation.chunk_group_by_ukey.expect_get(ukey);
+        let entry_chunk = entry_point.get_entry_point_chunk();
+        let referenced_chunks = compilation
+          .chunk_by_ukey
+          .expect_get(&entry_chunk)
+          .get_all_referenced_chunks(&compilation.chunk_group_by_ukey);
+
+        let mut depend_on_entries = vec![];
+        for dep in depend_on {
+          let dependency_ukey = compilation
+            .entrypoints
+            .get(dep)
+            .ok_or_else(|| error!("Entry {name} depends on {dep}, but this entry was not found"))?;
+          let dependency_chunk = compilation
+            .chunk_group_by_ukey
+            .expect_get(dependency_ukey)
+            .get_entry_point_chunk();
+          if referenced_chunks.contains(&dependency_chunk) {
+            errors.push(error!(
+              "Entrypoints '{name}' and '{dep}' use 'dependOn' to depend on each other in a circular way."
+            ));
+            compilation
+              .chunk_group_by_ukey
+              .expect_get_mut(ukey)
+              .set_runtime_chunk(entry_chunk);
+            self
+              .remove_parent_modules_context
+              .add_root_chunk(entry_chunk);
+            continue 'outer;
+          }
+          depend_on_entries.push((*dependency_ukey, dependency_chunk));
+        }
 
+        for (dependency_ukey, dependency_chunk) in depend_on_entries {
+          compilation
+            .chunk_group_by_ukey
+            .expect_get_mut(&dependency_ukey)
+            .children
+            .insert(*ukey);
+          compilation
+            .chunk_group_by_ukey
+            .expect_get_mut(ukey)
+            .parents
+            .insert(dependency_ukey);
+          self
+            .remove_parent_modules_context
+            .add_chunk_relation(dependency_chunk, entry_chunk);
+        }
+      } else if let Some(runtime) = &options.runtime {
         let entry_point = compilation.chunk_group_by_ukey.expect_get_mut(ukey);
 
         let chunk = match compilation.named_chunks.get(runtime) {
           Some(ukey) => {
             if !runtime_chunks.contains(ukey) {
-              // TODO: add dependOn error message once we implement dependeOn
-              // Did you mean to use 'dependOn: {}' instead to allow using entrypoint '{name}' within the runtime of entrypoint '{runtime}'? For this '{runtime}' must always be loaded when '{name}' is used.
-              runtime_error = Some(error!(
+              errors.push(error!(
 "Entrypoint '{name}' has a 'runtime' option which points to another entrypoint named '{runtime}'.
 It's not valid to use other entrypoints as runtime chunk.
+Did you mean to use 'dependOn: \"{runtime}\"' instead to allow using entrypoint '{name}' within the runtime of entrypoint '{runtime}'? For this '{runtime}' must always be loaded when '{name}' is used.
 Or do you want to use the entrypoints '{name}' and '{runtime}' independently on the same page with a shared runtime? In this case give them both the same value for the 'runtime' option. It must be a name not already used by an entrypoint."
               ));
               let entry_chunk = entry_point.get_entry_point_chunk();
@@ -271,7 +338,42 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
       }
     }
 
-    if let Some(err) = runtime_error {
+    // Entrypoints with `depend_on` use the runtime chunk of the first entrypoint they depend on
+    for (name, entry_data) in &compilation.entries {
+      if entry_data.options.depend_on.is_none() {
+        continue;
+      }
+      let mut visited = HashSet::default();
+      let mut current = name;
+      let runtime_chunk = loop {
+        let entry_point = compilation
+          .chunk_group_by_ukey
+          .expect_get(&compilation.entrypoints[current]);
+        if let Some(runtime_chunk) = entry_point.runtime_chunk {
+          break Some(runtime_chunk);
+        }
+        let Some(dep) = compilation.entries[current]
+          .options
+          .depend_on
+          .as_ref()
+          .and_then(|depend_on| depend_on.first())
+        else {
+          break None;
+        };
+        if !visited.insert(dep) {
+          break None;
+        }
+        current = dep;
+      };
+      if let Some(runtime_chunk) = runtime_chunk {
+        compilation
+          .chunk_group_by_ukey
+          .expect_get_mut(&compilation.entrypoints[name])
+          .set_runtime_chunk(runtime_chunk);
+      }
+    }
+
+    for err in errors {
       compilation.push_diagnostic(err.into());
     }
     Ok(input_entrypoints_and_modules)
@@ -568,6 +670,14 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
       .module_graph
       .block_by_id(&block_id)
       .expect("should have block");
+    let origin_module = *block.parent();
+    let origin_loc = block.loc().copied();
+    let origin_request = block
+      .get_dependencies()
+      .first()
+      .and_then(|dep| self.compilation.module_graph.dependency_by_id(dep))
+      .and_then(|dep| dep.as_module_dependency())
+      .map(|dep| dep.user_request().to_string());
     let item_chunk_group = self
       .compilation
       .chunk_group_by_ukey
@@ -737,6 +847,11 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
         c = Some(cgi);
         cgi
       };
+      self
+        .compilation
+        .chunk_group_by_ukey
+        .expect_get_mut(&cgi)
+        .add_origin(Some(origin_module), origin_loc, origin_request);
       self.block_chunk_groups.insert(block_id, cgi);
     }
 
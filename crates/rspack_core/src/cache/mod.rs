@@ -17,7 +17,7 @@ use occasion::{
   BuildModuleOccasion, CodeGenerateOccasion, CreateChunkAssetsOccasion, ResolveModuleOccasion,
 };
 use snapshot::SnapshotManager;
-use storage::new_storage;
+use storage::{new_persistent_storage, new_storage};
 
 #[derive(Debug)]
 pub struct Cache {
@@ -31,19 +31,25 @@ pub struct Cache {
 
 impl Cache {
   pub fn new(options: Arc<CompilerOptions>) -> Self {
-    let snapshot_manager = Arc::new(SnapshotManager::new(options.snapshot.clone()));
+    let snapshot_manager = Arc::new(SnapshotManager::new(
+      options.snapshot.clone(),
+      options.input_filesystem.clone(),
+    ));
     Self {
       is_idle: true.into(),
       snapshot_manager: snapshot_manager.clone(),
       resolve_module_occasion: ResolveModuleOccasion::new(
-        new_storage(&options.cache),
+        new_persistent_storage(&options.cache, "resolve"),
         snapshot_manager.clone(),
       ),
       build_module_occasion: BuildModuleOccasion::new(
-        new_storage(&options.cache),
+        new_persistent_storage(&options.cache, "build"),
         snapshot_manager,
       ),
-      code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options.cache)),
+      code_generate_occasion: CodeGenerateOccasion::new(new_persistent_storage(
+        &options.cache,
+        "code-generation",
+      )),
       create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(&options.cache)),
     }
   }
@@ -59,6 +65,9 @@ impl Cache {
       .is_ok()
     {
       self.snapshot_manager.clear();
+      self.resolve_module_occasion.begin_idle();
+      self.build_module_occasion.begin_idle();
+      self.code_generate_occasion.begin_idle();
     }
   }
 
@@ -1,15 +1,26 @@
-use std::{collections::HashMap, path::Path, sync::Arc};
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 
 use futures::Future;
 use rkyv::AlignedVec;
-use rspack_error::{Result, TWithDiagnosticArray};
+use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_hash::RspackHashDigest;
 use rspack_identifier::Identifier;
+use serde::{Deserialize, Serialize};
+use swc_core::ecma::atoms::Atom;
 
+use super::PersistedSource;
 use crate::{
   cache::snapshot::{Snapshot, SnapshotManager},
-  cache::storage,
-  BoxModule, BuildExtraDataType, BuildInfo, BuildMeta, BuildResult, DependencyTemplate, Module,
-  ModuleDependency, NormalModuleSource,
+  cache::storage::{self, BlobRef, Blobs, PersistentItem},
+  deserialize_dependency, deserialize_dependency_template, deserialize_module_dependency,
+  serialize_dependency, serialize_dependency_template, serialize_module_dependency,
+  AsyncDependenciesBlock, BoxModule, BuildExtraDataType, BuildInfo, BuildMeta, BuildResult,
+  ChunkGroupOptions, DependencyId, DependencyLocation, DependencyTemplate, GroupOptions, Module,
+  ModuleDependency, ModuleIdentifier, NormalModule, NormalModuleSource, PersistedDependency,
 };
 
 #[derive(Debug, Clone)]
@@ -23,7 +34,7 @@ pub struct NormalModuleStorageData {
 
 type NormalModuleStorageExtraData = HashMap<BuildExtraDataType, AlignedVec>;
 
-type Storage = dyn storage::Storage<(
+type StorageItem = (
   // file system info, None when not cacheable
   Option<Snapshot>,
   // build result
@@ -32,7 +43,279 @@ type Storage = dyn storage::Storage<(
   Option<NormalModuleStorageData>,
   // parser and generator data
   Option<NormalModuleStorageExtraData>,
-)>;
+);
+
+type Storage = dyn storage::Storage<StorageItem>;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedBuildInfo {
+  cacheable: bool,
+  hash: Option<String>,
+  strict: bool,
+  file_dependencies: Vec<PathBuf>,
+  context_dependencies: Vec<PathBuf>,
+  missing_dependencies: Vec<PathBuf>,
+  build_dependencies: Vec<PathBuf>,
+  asset_filenames: Vec<String>,
+  harmony_named_exports: Vec<String>,
+  /// persisted ids of the `export *` dependencies, replaced together with the dependencies
+  all_star_exports: Vec<u32>,
+  need_create_require: bool,
+  json_data: Option<String>,
+}
+
+impl PersistedBuildInfo {
+  fn new(build_info: &BuildInfo) -> Option<Self> {
+    Some(Self {
+      cacheable: build_info.cacheable,
+      hash: build_info.hash.as_ref().map(|h| h.encoded().to_string()),
+      strict: build_info.strict,
+      file_dependencies: build_info.file_dependencies.iter().cloned().collect(),
+      context_dependencies: build_info.context_dependencies.iter().cloned().collect(),
+      missing_dependencies: build_info.missing_dependencies.iter().cloned().collect(),
+      build_dependencies: build_info.build_dependencies.iter().cloned().collect(),
+      asset_filenames: build_info.asset_filenames.iter().cloned().collect(),
+      harmony_named_exports: build_info
+        .harmony_named_exports
+        .iter()
+        .map(|e| e.to_string())
+        .collect(),
+      all_star_exports: build_info.all_star_exports.iter().map(|id| **id).collect(),
+      need_create_require: build_info.need_create_require,
+      json_data: build_info.json_data.as_ref().map(|data| data.dump()),
+    })
+  }
+
+  fn restore(self) -> Option<BuildInfo> {
+    Some(BuildInfo {
+      cacheable: self.cacheable,
+      hash: self.hash.as_deref().map(RspackHashDigest::from_encoded),
+      strict: self.strict,
+      file_dependencies: self.file_dependencies.into_iter().collect(),
+      context_dependencies: self.context_dependencies.into_iter().collect(),
+      missing_dependencies: self.missing_dependencies.into_iter().collect(),
+      build_dependencies: self.build_dependencies.into_iter().collect(),
+      asset_filenames: self.asset_filenames.into_iter().collect(),
+      harmony_named_exports: self
+        .harmony_named_exports
+        .into_iter()
+        .map(Atom::from)
+        .collect(),
+      all_star_exports: self
+        .all_star_exports
+        .into_iter()
+        .map(DependencyId::restored)
+        .collect::<Option<_>>()?,
+      need_create_require: self.need_create_require,
+      json_data: match self.json_data {
+        Some(data) => Some(json::parse(&data).ok()?),
+        None => None,
+      },
+    })
+  }
+}
+
+/// Entrypoint blocks, i.e. `import()` of a worker, are not persisted
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedBlock {
+  group_options: Option<ChunkGroupOptions>,
+  loc: Option<DependencyLocation>,
+  parent: ModuleIdentifier,
+  dependencies: Vec<PersistedDependency>,
+}
+
+impl PersistedBlock {
+  fn new(block: &AsyncDependenciesBlock) -> Option<Self> {
+    let group_options = match block.get_group_options() {
+      Some(GroupOptions::ChunkGroup(options)) => Some(options.clone()),
+      Some(GroupOptions::Entrypoint(_)) => return None,
+      None => None,
+    };
+    Some(Self {
+      group_options,
+      loc: block.loc().copied(),
+      parent: *block.parent(),
+      dependencies: block
+        .dependencies()
+        .iter()
+        .map(|dep| serialize_dependency(&**dep))
+        .collect::<Option<_>>()?,
+    })
+  }
+
+  fn restore(self) -> Option<AsyncDependenciesBlock> {
+    let mut block = AsyncDependenciesBlock::new(self.parent, self.loc);
+    if let Some(options) = self.group_options {
+      block.set_group_options(GroupOptions::ChunkGroup(options));
+    }
+    for dep in self.dependencies {
+      block.add_dependency(deserialize_dependency(dep)?);
+    }
+    Some(block)
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedBuildModule {
+  snapshot: Snapshot,
+  build_info: PersistedBuildInfo,
+  build_meta: BuildMeta,
+  dependencies: Vec<PersistedDependency>,
+  blocks: Vec<PersistedBlock>,
+  code_generation_dependencies: Option<Vec<PersistedDependency>>,
+  presentational_dependencies: Option<Vec<PersistedDependency>>,
+  source: PersistedSource,
+  /// rkyv archives of the parser and generator, stored in the binary section of the pack
+  extra_data: Vec<(BuildExtraDataType, BlobRef)>,
+}
+
+impl PersistentItem for StorageItem {
+  type Persisted = PersistedBuildModule;
+
+  /// Only successfully built modules without diagnostics are persisted.
+  ///
+  /// Dependencies are persisted through the registry of [crate::serialize_dependency], a module
+  /// using a dependency which isn't registered by its plugin only lives in memory and is rebuilt
+  /// after a restart.
+  fn to_persisted(&self, blobs: &mut Blobs) -> Option<Self::Persisted> {
+    let (snapshot, result, module_data, extra_data) = self;
+    let (Some(snapshot), Some(module_data)) = (snapshot, module_data) else {
+      return None;
+    };
+    if !result.diagnostic.is_empty() {
+      return None;
+    }
+    let NormalModuleSource::BuiltSucceed(source) = &module_data.source else {
+      return None;
+    };
+    Some(PersistedBuildModule {
+      snapshot: snapshot.clone(),
+      build_info: PersistedBuildInfo::new(&result.inner.build_info)?,
+      build_meta: result.inner.build_meta.clone(),
+      dependencies: result
+        .inner
+        .dependencies
+        .iter()
+        .map(|dep| serialize_dependency(&**dep))
+        .collect::<Option<_>>()?,
+      blocks: result
+        .inner
+        .blocks
+        .iter()
+        .map(PersistedBlock::new)
+        .collect::<Option<_>>()?,
+      code_generation_dependencies: match &module_data.code_generation_dependencies {
+        Some(deps) => Some(
+          deps
+            .iter()
+            .map(|dep| serialize_module_dependency(&**dep))
+            .collect::<Option<_>>()?,
+        ),
+        None => None,
+      },
+      presentational_dependencies: match &module_data.presentational_dependencies {
+        Some(deps) => Some(
+          deps
+            .iter()
+            .map(|dep| serialize_dependency_template(&**dep))
+            .collect::<Option<_>>()?,
+        ),
+        None => None,
+      },
+      source: PersistedSource::new(source, blobs)?,
+      extra_data: extra_data
+        .iter()
+        .flatten()
+        .map(|(ty, data)| (ty.clone(), blobs.push(data)))
+        .collect(),
+    })
+  }
+
+  fn from_persisted(persisted: Self::Persisted, blobs: &Blobs) -> Option<Self> {
+    // dependencies of a module reference each other by id, e.g. `export *`
+    DependencyId::with_restored_ids(|| {
+      let build_info = persisted.build_info.restore()?;
+      let build_meta = persisted.build_meta;
+      let code_generation_dependencies = match persisted.code_generation_dependencies {
+        Some(deps) => Some(
+          deps
+            .into_iter()
+            .map(deserialize_module_dependency)
+            .collect::<Option<_>>()?,
+        ),
+        None => None,
+      };
+      let presentational_dependencies = match persisted.presentational_dependencies {
+        Some(deps) => Some(
+          deps
+            .into_iter()
+            .map(deserialize_dependency_template)
+            .collect::<Option<_>>()?,
+        ),
+        None => None,
+      };
+      let module_data = NormalModuleStorageData {
+        source: NormalModuleSource::BuiltSucceed(persisted.source.restore(blobs)?),
+        code_generation_dependencies,
+        presentational_dependencies,
+        build_info: Some(build_info.clone()),
+        build_meta: Some(build_meta.clone()),
+      };
+      let result = BuildResult {
+        build_meta,
+        build_info,
+        dependencies: persisted
+          .dependencies
+          .into_iter()
+          .map(deserialize_dependency)
+          .collect::<Option<_>>()?,
+        blocks: persisted
+          .blocks
+          .into_iter()
+          .map(PersistedBlock::restore)
+          .collect::<Option<_>>()?,
+        ..Default::default()
+      };
+      let extra_data = persisted
+        .extra_data
+        .into_iter()
+        .map(|(ty, data)| {
+          let data = blobs.get(data)?;
+          let mut aligned = AlignedVec::with_capacity(data.len());
+          aligned.extend_from_slice(data);
+          Some((ty, aligned))
+        })
+        .collect::<Option<_>>()?;
+      Some((
+        Some(persisted.snapshot),
+        result.with_empty_diagnostic(),
+        Some(module_data),
+        Some(extra_data),
+      ))
+    })
+  }
+}
+
+fn restore_normal_module(
+  module: &mut NormalModule,
+  module_data: Option<NormalModuleStorageData>,
+  extra_data: Option<NormalModuleStorageExtraData>,
+) {
+  if let Some(module_data) = module_data {
+    *module.source_mut() = module_data.source;
+    *module.code_generation_dependencies_mut() = module_data.code_generation_dependencies;
+    *module.presentational_dependencies_mut() = module_data.presentational_dependencies;
+    if let (Some(build_info), Some(build_meta)) = (module_data.build_info, module_data.build_meta) {
+      module.set_module_build_info_and_meta(build_info, build_meta);
+    }
+  }
+  if let Some(extra_data) = extra_data {
+    module.parser_and_generator_mut().resume(&extra_data);
+  }
+}
 
 #[derive(Debug)]
 pub struct BuildModuleOccasion {
@@ -48,6 +331,12 @@ impl BuildModuleOccasion {
     }
   }
 
+  pub fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.begin_idle();
+    }
+  }
+
   pub fn remove_cache(&self, id: &Identifier) {
     if let Some(s) = self.storage.as_ref() {
       s.remove(id);
@@ -87,19 +376,7 @@ impl BuildModuleOccasion {
         };
         if valid {
           if let Some(module) = module.as_normal_module_mut() {
-            if let Some(module_data) = module_data {
-              *module.source_mut() = module_data.source;
-              *module.code_generation_dependencies_mut() = module_data.code_generation_dependencies;
-              *module.presentational_dependencies_mut() = module_data.presentational_dependencies;
-              if let (Some(build_info), Some(build_meta)) =
-                (module_data.build_info, module_data.build_meta)
-              {
-                module.set_module_build_info_and_meta(build_info, build_meta);
-              }
-            }
-            if let Some(extra_data) = extra_data {
-              module.parser_and_generator_mut().resume(&extra_data);
-            }
+            restore_normal_module(module, module_data, extra_data);
           }
           return Ok((Ok(data), true));
         } else {
@@ -188,3 +465,311 @@ impl BuildModuleOccasion {
     Ok((Ok(data), false))
   }
 }
+
+#[cfg(test)]
+mod test {
+  use std::sync::Mutex;
+
+  use rspack_identifier::Identifiable;
+  use rspack_loader_runner::ResourceData;
+  use rspack_sources::{BoxSource, RawSource, Source, SourceExt};
+  use tempfile::TempDir;
+
+  use super::*;
+  use crate::{
+    register_serializable_dependency_template, register_serializable_module_dependency,
+    AsContextDependency, AsDependencyTemplate, BoxDependency, CacheOptions, ConstDependency,
+    DependenciesBlock, Dependency, DependencyType, FileSystemCacheOptions, GenerateContext,
+    ParseContext, ParseResult, ParserAndGenerator, SourceType,
+  };
+
+  /// Records the extra data resumed from cache
+  #[derive(Debug, Default)]
+  struct TestParserAndGenerator(Arc<Mutex<Option<Vec<u8>>>>);
+
+  impl ParserAndGenerator for TestParserAndGenerator {
+    fn source_types(&self) -> &[SourceType] {
+      &[SourceType::Asset]
+    }
+
+    fn parse(&mut self, _parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+      unreachable!("restored modules are never parsed")
+    }
+
+    fn size(&self, _module: &dyn Module, _source_type: &SourceType) -> f64 {
+      0.0
+    }
+
+    fn generate(
+      &self,
+      source: &BoxSource,
+      _module: &dyn Module,
+      _generate_context: &mut GenerateContext,
+    ) -> Result<BoxSource> {
+      Ok(source.clone())
+    }
+
+    fn store(&self, extra_data: &mut NormalModuleStorageExtraData) {
+      let mut data = AlignedVec::new();
+      data.extend_from_slice(&[0, 1, 2, 255]);
+      extra_data.insert(BuildExtraDataType::AssetParserAndGenerator, data);
+    }
+
+    fn resume(&mut self, extra_data: &NormalModuleStorageExtraData) {
+      *self.0.lock().expect("should lock") = extra_data
+        .get(&BuildExtraDataType::AssetParserAndGenerator)
+        .map(|data| data.to_vec());
+    }
+  }
+
+  #[derive(Debug, Clone, Serialize, Deserialize)]
+  struct TestImportDependency {
+    id: DependencyId,
+    request: String,
+  }
+
+  impl TestImportDependency {
+    fn new(request: &str) -> Self {
+      Self {
+        id: DependencyId::new(),
+        request: request.to_string(),
+      }
+    }
+  }
+
+  impl Dependency for TestImportDependency {
+    fn dependency_debug_name(&self) -> &'static str {
+      "TestImportDependency"
+    }
+
+    fn id(&self) -> &DependencyId {
+      &self.id
+    }
+
+    fn dependency_type(&self) -> &DependencyType {
+      &DependencyType::EsmExportImportedSpecifier
+    }
+  }
+
+  impl ModuleDependency for TestImportDependency {
+    fn request(&self) -> &str {
+      &self.request
+    }
+  }
+
+  impl AsDependencyTemplate for TestImportDependency {}
+  impl AsContextDependency for TestImportDependency {}
+
+  fn options(dir: &TempDir) -> CacheOptions {
+    CacheOptions::FileSystem(FileSystemCacheOptions {
+      cache_directory: dir.path().to_string_lossy().to_string(),
+      name: "test".to_string(),
+      ..Default::default()
+    })
+  }
+
+  fn snapshot() -> Snapshot {
+    Snapshot {
+      file_update_times: Default::default(),
+      file_hashes: Default::default(),
+    }
+  }
+
+  fn requests(dependencies: &[BoxDependency]) -> Vec<&str> {
+    dependencies
+      .iter()
+      .map(|dep| {
+        dep
+          .as_module_dependency()
+          .expect("should be module dependency")
+          .request()
+      })
+      .collect()
+  }
+
+  fn normal_module(resumed: Arc<Mutex<Option<Vec<u8>>>>) -> NormalModule {
+    let resource = "/project/src/logo.png";
+    NormalModule::new(
+      resource.to_string(),
+      "./logo.png".to_string(),
+      "./logo.png".to_string(),
+      crate::ModuleType::AssetResource,
+      Box::new(TestParserAndGenerator(resumed)),
+      None,
+      None,
+      None,
+      ResourceData::new(resource.to_string(), PathBuf::from(resource)),
+      None,
+      vec![],
+      false,
+      None,
+    )
+  }
+
+  #[test]
+  fn should_restore_normal_module_from_pack() {
+    let dir = TempDir::new().expect("should create temp dir");
+    let options = options(&dir);
+
+    let mut module = normal_module(Default::default());
+    let build_info = BuildInfo {
+      cacheable: true,
+      file_dependencies: [PathBuf::from("/project/src/logo.png")]
+        .into_iter()
+        .collect(),
+      ..Default::default()
+    };
+    let build_meta = BuildMeta::default();
+    *module.source_mut() =
+      NormalModuleSource::BuiltSucceed(RawSource::Buffer(vec![137, 80, 78, 71]).boxed());
+    module.set_module_build_info_and_meta(build_info.clone(), build_meta.clone());
+    let mut extra_data = HashMap::new();
+    module.parser_and_generator().store(&mut extra_data);
+
+    let storage =
+      storage::new_persistent_storage::<StorageItem>(&options, "build_module").expect("storage");
+    storage.set(
+      module.identifier(),
+      (
+        Some(snapshot()),
+        BuildResult {
+          build_info: build_info.clone(),
+          build_meta: build_meta.clone(),
+          ..Default::default()
+        }
+        .with_empty_diagnostic(),
+        Some(NormalModuleStorageData {
+          source: module.source().clone(),
+          code_generation_dependencies: None,
+          presentational_dependencies: None,
+          build_info: Some(build_info),
+          build_meta: Some(build_meta),
+        }),
+        Some(extra_data),
+      ),
+    );
+    storage.begin_idle();
+
+    let storage =
+      storage::new_persistent_storage::<StorageItem>(&options, "build_module").expect("storage");
+    let (snapshot, result, module_data, extra_data) = storage
+      .get(&module.identifier())
+      .expect("should restore from pack");
+    assert!(snapshot.is_some());
+    assert!(result.inner.build_info.cacheable);
+
+    let resumed = Arc::new(Mutex::new(None));
+    let mut restored = normal_module(resumed.clone());
+    restore_normal_module(&mut restored, module_data, extra_data);
+    let NormalModuleSource::BuiltSucceed(source) = restored.source() else {
+      panic!("should restore the built source")
+    };
+    assert_eq!(source.buffer().as_ref(), &[137, 80, 78, 71]);
+    assert!(restored
+      .build_info()
+      .expect("should restore build info")
+      .file_dependencies
+      .contains(Path::new("/project/src/logo.png")));
+    assert_eq!(
+      resumed.lock().expect("should lock").as_deref(),
+      Some([0, 1, 2, 255].as_slice())
+    );
+  }
+
+  #[test]
+  fn should_restore_javascript_module_with_dependencies_from_pack() {
+    register_serializable_module_dependency::<TestImportDependency>();
+    register_serializable_dependency_template::<ConstDependency>();
+    let dir = TempDir::new().expect("should create temp dir");
+    let options = options(&dir);
+    let id = ModuleIdentifier::from("javascript/auto|/project/src/index.js");
+
+    // import "./a"; export * from "./b"; import("./lazy");
+    let import = TestImportDependency::new("./a");
+    let star_export = TestImportDependency::new("./b");
+    let star_export_id = *star_export.id();
+    let mut block = AsyncDependenciesBlock::new(id, Some(DependencyLocation::new(35, 51)));
+    block.set_group_options(GroupOptions::ChunkGroup(ChunkGroupOptions::new(
+      Some("lazy".to_string()),
+      None,
+      None,
+    )));
+    block.add_dependency(Box::new(TestImportDependency::new("./lazy")));
+    let build_info = BuildInfo {
+      cacheable: true,
+      all_star_exports: vec![star_export_id],
+      ..Default::default()
+    };
+    let build_meta = BuildMeta::default();
+
+    let storage =
+      storage::new_persistent_storage::<StorageItem>(&options, "build_module").expect("storage");
+    storage.set(
+      id,
+      (
+        Some(snapshot()),
+        BuildResult {
+          build_info: build_info.clone(),
+          build_meta: build_meta.clone(),
+          dependencies: vec![Box::new(import), Box::new(star_export)],
+          blocks: vec![block],
+          ..Default::default()
+        }
+        .with_empty_diagnostic(),
+        Some(NormalModuleStorageData {
+          source: NormalModuleSource::BuiltSucceed(
+            RawSource::Source(
+              r#"import "./a"; export * from "./b"; import("./lazy");"#.to_string(),
+            )
+            .boxed(),
+          ),
+          code_generation_dependencies: None,
+          presentational_dependencies: Some(vec![Box::new(ConstDependency::new(
+            0,
+            13,
+            "".into(),
+            None,
+          ))]),
+          build_info: Some(build_info),
+          build_meta: Some(build_meta),
+        }),
+        None,
+      ),
+    );
+    storage.begin_idle();
+
+    let storage =
+      storage::new_persistent_storage::<StorageItem>(&options, "build_module").expect("storage");
+    let (_, result, module_data, _) = storage
+      .get(&id)
+      .expect("should restore javascript module from pack");
+    let result = result.inner;
+    assert_eq!(requests(&result.dependencies), vec!["./a", "./b"]);
+    // restored dependencies get new ids, `export *` keeps referencing its dependency
+    let restored_star_export_id = *result.dependencies[1].id();
+    assert_ne!(restored_star_export_id, star_export_id);
+    assert_eq!(
+      result.build_info.all_star_exports,
+      vec![restored_star_export_id]
+    );
+
+    let [block] = result.blocks.as_slice() else {
+      panic!("should restore the async block")
+    };
+    assert_eq!(
+      block.get_group_options().and_then(|options| options.name()),
+      Some("lazy")
+    );
+    assert_eq!(block.loc(), Some(&DependencyLocation::new(35, 51)));
+    assert_eq!(requests(block.dependencies()), vec!["./lazy"]);
+    assert_eq!(block.get_dependencies(), &[*block.dependencies()[0].id()]);
+
+    let module_data = module_data.expect("should restore module data");
+    assert_eq!(
+      module_data
+        .presentational_dependencies
+        .map(|deps| deps.len()),
+      Some(1)
+    );
+  }
+}
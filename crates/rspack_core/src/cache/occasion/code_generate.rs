@@ -1,11 +1,119 @@
 use rspack_error::Result;
+use rspack_hash::RspackHashDigest;
 use rspack_identifier::Identifier;
+use serde::{Deserialize, Serialize};
 
-use crate::{cache::storage, BoxModule, CodeGenerationResult, Compilation, NormalModuleSource};
+use super::PersistedSource;
+use crate::{
+  cache::storage::{self, Blobs, PersistentItem},
+  BoxModule, CodeGenerationData, CodeGenerationDataAssetInfo, CodeGenerationDataFilename,
+  CodeGenerationDataUrl, CodeGenerationResult, Compilation, NormalModuleSource, RuntimeGlobals,
+  SourceType,
+};
 use crate::{RuntimeSpec, RuntimeSpecSet};
 
 type Storage = dyn storage::Storage<Vec<(CodeGenerationResult, RuntimeSpec)>>;
 
+/// Code generation data of the normal modules, which is set by the asset modules
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedCodeGenerationData {
+  url: Option<CodeGenerationDataUrl>,
+  filename: Option<CodeGenerationDataFilename>,
+  asset_info: Option<CodeGenerationDataAssetInfo>,
+}
+
+impl PersistedCodeGenerationData {
+  fn new(data: &CodeGenerationData) -> Option<Self> {
+    let persisted = Self {
+      url: data.get::<CodeGenerationDataUrl>().cloned(),
+      filename: data.get::<CodeGenerationDataFilename>().cloned(),
+      asset_info: data.get::<CodeGenerationDataAssetInfo>().cloned(),
+    };
+    let len = [
+      persisted.url.is_some(),
+      persisted.filename.is_some(),
+      persisted.asset_info.is_some(),
+    ]
+    .into_iter()
+    .filter(|persisted| *persisted)
+    .count();
+    // other types of data are only set for the modules which aren't cached
+    (len == data.len()).then_some(persisted)
+  }
+
+  fn restore(self, data: &mut CodeGenerationData) {
+    if let Some(url) = self.url {
+      data.insert(url);
+    }
+    if let Some(filename) = self.filename {
+      data.insert(filename);
+    }
+    if let Some(asset_info) = self.asset_info {
+      data.insert(asset_info);
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedCodeGenerationResult {
+  sources: Vec<(SourceType, PersistedSource)>,
+  data: PersistedCodeGenerationData,
+  runtime_requirements: u64,
+  hash: Option<String>,
+  runtime: Vec<String>,
+}
+
+impl PersistentItem for Vec<(CodeGenerationResult, RuntimeSpec)> {
+  type Persisted = Vec<PersistedCodeGenerationResult>;
+
+  fn to_persisted(&self, blobs: &mut Blobs) -> Option<Self::Persisted> {
+    self
+      .iter()
+      .map(|(result, runtime)| {
+        // only the results of normal modules are cached, which never have chunk init fragments,
+        // and they are generated without a concatenation scope, see `Compilation::code_generation`
+        if !result.chunk_init_fragments.is_empty() || result.concatenation_scope.is_some() {
+          return None;
+        }
+        let sources = result
+          .inner
+          .iter()
+          .map(|(source_type, source)| Some((*source_type, PersistedSource::new(source, blobs)?)))
+          .collect::<Option<Vec<_>>>()?;
+        Some(PersistedCodeGenerationResult {
+          sources,
+          data: PersistedCodeGenerationData::new(&result.data)?,
+          runtime_requirements: result.runtime_requirements.bits(),
+          hash: result.hash.as_ref().map(|hash| hash.encoded().to_string()),
+          runtime: runtime.iter().map(|r| r.to_string()).collect(),
+        })
+      })
+      .collect()
+  }
+
+  fn from_persisted(persisted: Self::Persisted, blobs: &Blobs) -> Option<Self> {
+    persisted
+      .into_iter()
+      .map(|persisted| {
+        let mut result = CodeGenerationResult::default();
+        for (source_type, source) in persisted.sources {
+          result.add(source_type, source.restore(blobs)?);
+        }
+        persisted.data.restore(&mut result.data);
+        result.runtime_requirements = RuntimeGlobals::from_bits(persisted.runtime_requirements)?;
+        result.hash = persisted
+          .hash
+          .as_deref()
+          .map(RspackHashDigest::from_encoded);
+        let runtime = persisted.runtime.into_iter().map(Into::into).collect();
+        Some((result, runtime))
+      })
+      .collect()
+  }
+}
+
 #[derive(Debug)]
 pub struct CodeGenerateOccasion {
   storage: Option<Box<Storage>>,
@@ -16,6 +124,12 @@ impl CodeGenerateOccasion {
     Self { storage }
   }
 
+  pub fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.begin_idle();
+    }
+  }
+
   pub fn use_cache<'a, G>(
     &self,
     module: &'a BoxModule,
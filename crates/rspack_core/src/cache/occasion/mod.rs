@@ -1,3 +1,5 @@
+mod persisted_source;
+use persisted_source::PersistedSource;
 mod resolve_module;
 pub use resolve_module::*;
 mod build_module;
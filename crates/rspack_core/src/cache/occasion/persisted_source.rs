@@ -0,0 +1,53 @@
+use rspack_sources::{
+  BoxSource, MapOptions, RawSource, Source, SourceExt, SourceMap, SourceMapSource,
+  WithoutOriginalOptions,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::cache::storage::{BlobRef, Blobs};
+
+/// On-disk representation of a [BoxSource].
+///
+/// Only the generated code and its source map are kept, the structure of
+/// the original source (concat, replace, cached...) is flattened. Buffers are
+/// stored in the binary section of the pack.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum PersistedSource {
+  Buffer { content: BlobRef },
+  Raw { content: String },
+  SourceMap { content: String, map: String },
+}
+
+impl PersistedSource {
+  pub fn new(source: &BoxSource, blobs: &mut Blobs) -> Option<Self> {
+    if let Some(RawSource::Buffer(content)) = source.as_any().downcast_ref::<RawSource>() {
+      return Some(Self::Buffer {
+        content: blobs.push(content),
+      });
+    }
+    let content = source.source().to_string();
+    match source.map(&MapOptions::default()) {
+      Some(map) => Some(Self::SourceMap {
+        content,
+        map: map.to_json().ok()?,
+      }),
+      None => Some(Self::Raw { content }),
+    }
+  }
+
+  pub fn restore(self, blobs: &Blobs) -> Option<BoxSource> {
+    let source = match self {
+      Self::Buffer { content } => RawSource::Buffer(blobs.get(content)?.to_vec()).boxed(),
+      Self::Raw { content } => RawSource::from(content).boxed(),
+      Self::SourceMap { content, map } => SourceMapSource::new(WithoutOriginalOptions {
+        value: content,
+        // name is only used when combining with an original source, which is flattened here
+        name: "",
+        source_map: SourceMap::from_json(&map).ok()?,
+      })
+      .boxed(),
+    };
+    Some(source)
+  }
+}
@@ -1,17 +1,65 @@
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
 use futures::Future;
 use rspack_error::Error;
 use rspack_identifier::Identifier;
+use rspack_loader_runner::DescriptionData;
+use serde::{Deserialize, Serialize};
 
 use crate::{
   cache::snapshot::{Snapshot, SnapshotManager},
-  cache::storage,
-  ModuleIdentifier, ResolveArgs, ResolveResult,
+  cache::storage::{self, Blobs, PersistentItem},
+  ModuleIdentifier, ResolveArgs, ResolveResult, Resource,
 };
 
 type Storage = dyn storage::Storage<(Snapshot, ResolveResult)>;
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedResource {
+  path: PathBuf,
+  query: Option<String>,
+  fragment: Option<String>,
+  description_data: Option<(PathBuf, serde_json::Value)>,
+}
+
+impl PersistentItem for (Snapshot, ResolveResult) {
+  // `None` for ignored resources
+  type Persisted = (Snapshot, Option<PersistedResource>);
+
+  fn to_persisted(&self, _blobs: &mut Blobs) -> Option<Self::Persisted> {
+    let (snapshot, result) = self;
+    let resource = match result {
+      ResolveResult::Resource(resource) => Some(PersistedResource {
+        path: resource.path.clone(),
+        query: resource.query.clone(),
+        fragment: resource.fragment.clone(),
+        description_data: resource
+          .description_data
+          .as_ref()
+          .map(|data| (data.path().to_path_buf(), data.json().clone())),
+      }),
+      ResolveResult::Ignored => None,
+    };
+    Some((snapshot.clone(), resource))
+  }
+
+  fn from_persisted((snapshot, resource): Self::Persisted, _blobs: &Blobs) -> Option<Self> {
+    let result = match resource {
+      Some(resource) => ResolveResult::Resource(Resource {
+        path: resource.path,
+        query: resource.query,
+        fragment: resource.fragment,
+        description_data: resource
+          .description_data
+          .map(|(path, json)| DescriptionData::new(path, Arc::new(json))),
+      }),
+      None => ResolveResult::Ignored,
+    };
+    Some((snapshot, result))
+  }
+}
+
 #[derive(Debug)]
 pub struct ResolveModuleOccasion {
   storage: Option<Box<Storage>>,
@@ -26,6 +74,12 @@ impl ResolveModuleOccasion {
     }
   }
 
+  pub fn begin_idle(&self) {
+    if let Some(s) = self.storage.as_ref() {
+      s.begin_idle();
+    }
+  }
+
   pub async fn use_cache<'a, G, F>(
     &self,
     args: ResolveArgs<'a>,
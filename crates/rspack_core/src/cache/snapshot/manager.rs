@@ -1,11 +1,13 @@
 use std::{
   hash::BuildHasherDefault,
   path::{Path, PathBuf},
+  sync::Arc,
   time::SystemTime,
 };
 
 use dashmap::{DashMap, DashSet};
-use rspack_error::{miette::IntoDiagnostic, Result};
+use rspack_error::{error, Result};
+use rspack_fs::InputFileSystem;
 use rustc_hash::{FxHashMap as HashMap, FxHasher};
 
 use super::Snapshot;
@@ -19,6 +21,7 @@ use crate::{calc_hash, SnapshotOptions, SnapshotStrategy};
 pub struct SnapshotManager {
   /// global snapshot options
   options: SnapshotOptions,
+  fs: Arc<dyn InputFileSystem>,
   /// cache file update time
   update_time_cache: DashMap<PathBuf, SystemTime, BuildHasherDefault<FxHasher>>,
   /// cache file hash
@@ -28,9 +31,10 @@ pub struct SnapshotManager {
 }
 
 impl SnapshotManager {
-  pub fn new(options: SnapshotOptions) -> Self {
+  pub fn new(options: SnapshotOptions, fs: Arc<dyn InputFileSystem>) -> Self {
     Self {
       options,
+      fs,
       update_time_cache: Default::default(),
       hash_cache: Default::default(),
       modified_files: Default::default(),
@@ -55,19 +59,29 @@ impl SnapshotManager {
     if strategy.hash {
       let hash_cache = &self.hash_cache;
       for &path in paths {
-        let hash = match hash_cache.get(path) {
-          Some(hash) => *hash,
+        // the cache guard must not be held across the file system access below
+        let cached = hash_cache.get(path).map(|hash| *hash);
+        let hash = match cached {
+          Some(hash) => hash,
           None => {
-            let res = if path.is_dir() {
-              let dir = &mut tokio::fs::read_dir(path).await.into_diagnostic()?;
-              let mut sub_files = vec![];
-              while let Some(entry) = dir.next_entry().await.into_diagnostic()? {
-                let dir_u8 = entry.path().as_os_str().to_string_lossy().to_string();
-                sub_files.push(dir_u8);
-              }
-              calc_hash(&sub_files)
-            } else {
-              calc_hash(&tokio::fs::read(path).await.into_diagnostic()?)
+            let owned_path = path.to_owned();
+            let sub_files = self
+              .run_blocking(move |fs| {
+                if fs.metadata(&owned_path)?.is_directory {
+                  fs.read_dir(&owned_path).map(Some)
+                } else {
+                  Ok(None)
+                }
+              })
+              .await?;
+            let res = match sub_files {
+              Some(sub_files) => calc_hash(
+                &sub_files
+                  .into_iter()
+                  .map(|name| path.join(name).to_string_lossy().to_string())
+                  .collect::<Vec<_>>(),
+              ),
+              None => calc_hash(&self.read(path).await?),
             };
             hash_cache.insert(path.to_owned(), res);
             res
@@ -97,14 +111,20 @@ impl SnapshotManager {
           return Ok(false);
         }
 
-        let update_time = match update_time_cache.get(path) {
-          Some(t) => *t,
+        let cached = update_time_cache.get(path).map(|t| *t);
+        let update_time = match cached {
+          Some(t) => t,
           None => {
-            let t = tokio::fs::metadata(path)
-              .await
-              .into_diagnostic()?
-              .modified()
-              .into_diagnostic()?;
+            let owned_path = path.clone();
+            let metadata = self
+              .run_blocking(move |fs| fs.metadata(&owned_path))
+              .await?;
+            let t = metadata.modified.ok_or_else(|| {
+              error!(
+                "Failed to get the modified time of {}",
+                path.to_string_lossy()
+              )
+            })?;
             update_time_cache.insert(path.clone(), t);
             t
           }
@@ -124,10 +144,11 @@ impl SnapshotManager {
           return Ok(false);
         }
 
-        let current_hash = match hash_cache.get(path) {
-          Some(h) => *h,
+        let cached = hash_cache.get(path).map(|h| *h);
+        let current_hash = match cached {
+          Some(h) => h,
           None => {
-            let res = calc_hash(&tokio::fs::read(path).await.into_diagnostic()?);
+            let res = calc_hash(&self.read(path).await?);
             hash_cache.insert(path.clone(), res);
             res
           }
@@ -141,6 +162,25 @@ impl SnapshotManager {
     Ok(true)
   }
 
+  async fn read(&self, path: &Path) -> Result<Vec<u8>> {
+    let path = path.to_owned();
+    self.run_blocking(move |fs| fs.read(&path)).await
+  }
+
+  /// The input file system is synchronous, so it's accessed on the blocking thread pool
+  /// to keep the async workers free.
+  async fn run_blocking<R, F>(&self, f: F) -> Result<R>
+  where
+    R: Send + 'static,
+    F: FnOnce(&dyn InputFileSystem) -> rspack_fs::Result<R> + Send + 'static,
+  {
+    let fs = self.fs.clone();
+    tokio::task::spawn_blocking(move || f(fs.as_ref()))
+      .await
+      .map_err(|e| error!("Failed to access the input file system: {e}"))?
+      .map_err(Into::into)
+  }
+
   pub fn clear(&self) {
     self.update_time_cache.clear();
     self.hash_cache.clear();
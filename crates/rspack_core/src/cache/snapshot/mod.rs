@@ -1,12 +1,13 @@
 use std::{path::PathBuf, time::SystemTime};
 
 use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
 
 mod manager;
 pub use manager::SnapshotManager;
 
 /// Snapshot store dependenct files update time and hash
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Snapshot {
   pub file_update_times: HashMap<PathBuf, SystemTime>,
   pub file_hashes: HashMap<PathBuf, u64>,
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// Binary section of a pack.
+///
+/// Binary data such as rkyv archives or buffer sources is appended here as is, and the
+/// persisted item only keeps a [BlobRef] to it in the pack index.
+#[derive(Debug, Default)]
+pub struct Blobs(Vec<u8>);
+
+/// Location of a blob in [Blobs]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlobRef {
+  offset: usize,
+  len: usize,
+}
+
+impl Blobs {
+  pub fn new(data: Vec<u8>) -> Self {
+    Self(data)
+  }
+
+  pub fn push(&mut self, data: &[u8]) -> BlobRef {
+    let blob = BlobRef {
+      offset: self.0.len(),
+      len: data.len(),
+    };
+    self.0.extend_from_slice(data);
+    blob
+  }
+
+  /// `None` when the blob is out of bounds, i.e. the pack is corrupted
+  pub fn get(&self, blob: BlobRef) -> Option<&[u8]> {
+    self.0.get(blob.offset..blob.offset.checked_add(blob.len)?)
+  }
+
+  pub fn as_bytes(&self) -> &[u8] {
+    &self.0
+  }
+}
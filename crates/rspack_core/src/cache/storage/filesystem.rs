@@ -0,0 +1,412 @@
+use std::{
+  hash::BuildHasherDefault,
+  path::{Path, PathBuf},
+  sync::atomic::{AtomicBool, Ordering},
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use dashmap::DashMap;
+use rspack_identifier::{Identifier, IdentifierHasher};
+use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
+
+use super::{Blobs, PersistentItem, Storage};
+use crate::{calc_hash, FileSystemCacheOptions};
+
+/// Leading bytes of a pack file, the last byte is the version of the layout
+const PACK_MAGIC: &[u8; 8] = b"RSPACK\0\x01";
+
+/// `last_used` is only written to disk once per day for entries which are just read, since
+/// `cache.maxAge` is usually measured in days
+const LAST_USED_GRANULARITY: u64 = 24 * 60 * 60 * 1000;
+
+/// Index of a pack, the binary data of the entries follows it in the pack file.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Pack<T> {
+  /// Packs written with a different version are dropped as a whole
+  version: String,
+  entries: HashMap<String, PackEntry<T>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PackEntry<T> {
+  /// Milliseconds since unix epoch of the last build that used this entry
+  last_used: u64,
+  data: T,
+}
+
+/// FileSystemStorage keeps all items in memory and writes the persistable ones
+/// to `<cache_location>/<namespace>.pack` when the compiler becomes idle.
+///
+/// A pack file is laid out as `PACK_MAGIC | index length (u64 le) | index (json) | blobs`,
+/// see [PersistentItem] for which items are persisted.
+///
+/// The pack is invalidated when the rspack version, `cache.version` or the content
+/// of `cache.buildDependencies` changes, and entries which haven't been used for
+/// `cache.maxAge` milliseconds are evicted.
+#[derive(Debug)]
+pub struct FileSystemStorage<Item> {
+  pack_path: PathBuf,
+  version: String,
+  /// Zero means entries never expire
+  max_age: u64,
+  data: DashMap<Identifier, (u64, Item), BuildHasherDefault<IdentifierHasher>>,
+  dirty: AtomicBool,
+}
+
+impl<Item> FileSystemStorage<Item>
+where
+  Item: PersistentItem,
+{
+  pub fn new(options: &FileSystemCacheOptions, namespace: &str) -> Self {
+    let cache_location = if options.cache_location.is_empty() {
+      Path::new(&options.cache_directory).join(&options.name)
+    } else {
+      PathBuf::from(&options.cache_location)
+    };
+    let this = Self {
+      pack_path: cache_location.join(format!("{namespace}.pack")),
+      version: pack_version(options),
+      max_age: options.max_age as u64,
+      data: DashMap::default(),
+      dirty: AtomicBool::new(false),
+    };
+    this.restore();
+    this
+  }
+
+  fn restore(&self) {
+    let Ok(content) = std::fs::read(&self.pack_path) else {
+      return;
+    };
+    let Some((index, blobs)) = decode_pack(content) else {
+      tracing::warn!(
+        "Drop corrupted cache pack {}: invalid header",
+        self.pack_path.display()
+      );
+      return;
+    };
+    let pack: Pack<Item::Persisted> = match serde_json::from_slice(&index) {
+      Ok(pack) => pack,
+      Err(e) => {
+        tracing::warn!(
+          "Drop corrupted cache pack {}: {e}",
+          self.pack_path.display()
+        );
+        return;
+      }
+    };
+    if pack.version != self.version {
+      return;
+    }
+    let now = now();
+    for (id, entry) in pack.entries {
+      if self.is_expired(entry.last_used, now) {
+        // evicted entries need to be removed from disk as well
+        self.dirty.store(true, Ordering::Release);
+        continue;
+      }
+      if let Some(item) = Item::from_persisted(entry.data, &blobs) {
+        self
+          .data
+          .insert(Identifier::from(id), (entry.last_used, item));
+      }
+    }
+  }
+
+  fn is_expired(&self, last_used: u64, now: u64) -> bool {
+    self.max_age != 0 && now.saturating_sub(last_used) > self.max_age
+  }
+
+  fn save(&self) -> std::io::Result<()> {
+    let now = now();
+    self
+      .data
+      .retain(|_, (last_used, _)| !self.is_expired(*last_used, now));
+    let mut blobs = Blobs::default();
+    let entries = self
+      .data
+      .iter()
+      .filter_map(|item| {
+        let (last_used, data) = item.value();
+        data.to_persisted(&mut blobs).map(|data| {
+          (
+            item.key().to_string(),
+            PackEntry {
+              last_used: *last_used,
+              data,
+            },
+          )
+        })
+      })
+      .collect();
+    let pack = Pack {
+      version: self.version.clone(),
+      entries,
+    };
+    let content = encode_pack(&serde_json::to_vec(&pack)?, &blobs);
+
+    if let Some(dir) = self.pack_path.parent() {
+      std::fs::create_dir_all(dir)?;
+    }
+    // write to a temporary file first so an interrupted write never leaves a broken pack
+    let temp_path = self.pack_path.with_extension("pack.tmp");
+    std::fs::write(&temp_path, content)?;
+    std::fs::rename(temp_path, &self.pack_path)
+  }
+}
+
+impl<Item> Storage<Item> for FileSystemStorage<Item>
+where
+  Item: PersistentItem + Clone + std::fmt::Debug + Send + Sync,
+{
+  fn get(&self, id: &Identifier) -> Option<Item> {
+    self.data.get_mut(id).map(|mut item| {
+      let now = now();
+      if item.0 / LAST_USED_GRANULARITY != now / LAST_USED_GRANULARITY {
+        self.dirty.store(true, Ordering::Release);
+      }
+      item.0 = now;
+      item.1.clone()
+    })
+  }
+
+  fn set(&self, id: Identifier, data: Item) {
+    self.data.insert(id, (now(), data));
+    self.dirty.store(true, Ordering::Release);
+  }
+
+  fn remove(&self, id: &Identifier) {
+    if self.data.remove(id).is_some() {
+      self.dirty.store(true, Ordering::Release);
+    }
+  }
+
+  fn begin_idle(&self) {
+    if !self.dirty.swap(false, Ordering::AcqRel) {
+      return;
+    }
+    if let Err(e) = self.save() {
+      tracing::warn!(
+        "Failed to write cache pack {}: {e}",
+        self.pack_path.display()
+      );
+    }
+  }
+}
+
+fn encode_pack(index: &[u8], blobs: &Blobs) -> Vec<u8> {
+  let blobs = blobs.as_bytes();
+  let mut content = Vec::with_capacity(PACK_MAGIC.len() + 8 + index.len() + blobs.len());
+  content.extend_from_slice(PACK_MAGIC);
+  content.extend_from_slice(&(index.len() as u64).to_le_bytes());
+  content.extend_from_slice(index);
+  content.extend_from_slice(blobs);
+  content
+}
+
+fn decode_pack(mut content: Vec<u8>) -> Option<(Vec<u8>, Blobs)> {
+  let header_len = PACK_MAGIC.len() + 8;
+  if content.len() < header_len || !content.starts_with(PACK_MAGIC) {
+    return None;
+  }
+  let index_len = u64::from_le_bytes(content[PACK_MAGIC.len()..header_len].try_into().ok()?);
+  let index_end = header_len.checked_add(usize::try_from(index_len).ok()?)?;
+  if content.len() < index_end {
+    return None;
+  }
+  let blobs = content.split_off(index_end);
+  content.drain(..header_len);
+  Some((content, Blobs::new(blobs)))
+}
+
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|d| d.as_millis() as u64)
+    .unwrap_or_default()
+}
+
+fn pack_version(options: &FileSystemCacheOptions) -> String {
+  let mut build_dependencies = options.build_dependencies.clone();
+  build_dependencies.sort();
+  let build_dependencies = build_dependencies
+    .into_iter()
+    .map(|path| {
+      let hash = hash_build_dependency(Path::new(&path));
+      (path, hash)
+    })
+    .collect::<Vec<_>>();
+
+  format!(
+    "{}|{}|{:016x}",
+    env!("CARGO_PKG_VERSION"),
+    options.version,
+    calc_hash(&build_dependencies)
+  )
+}
+
+/// Hash of the content of a file, or of all the files in a directory recursively
+fn hash_build_dependency(path: &Path) -> Option<u64> {
+  if !path.is_dir() {
+    return std::fs::read(path).ok().map(|content| calc_hash(&content));
+  }
+  let mut entries = std::fs::read_dir(path)
+    .ok()?
+    .filter_map(|entry| entry.ok())
+    .collect::<Vec<_>>();
+  entries.sort_by_key(|entry| entry.file_name());
+  let entries = entries
+    .into_iter()
+    .map(|entry| {
+      // symlinked directories are not followed to avoid cycles
+      let hash = if entry.file_type().is_ok_and(|ty| ty.is_symlink()) && entry.path().is_dir() {
+        None
+      } else {
+        hash_build_dependency(&entry.path())
+      };
+      (entry.file_name().to_string_lossy().to_string(), hash)
+    })
+    .collect::<Vec<_>>();
+  Some(calc_hash(&entries))
+}
+
+#[cfg(test)]
+mod test {
+  use tempfile::TempDir;
+
+  use super::*;
+  use crate::cache::storage::BlobRef;
+
+  #[derive(Debug, Clone, PartialEq)]
+  struct Item(String);
+
+  impl PersistentItem for Item {
+    type Persisted = String;
+
+    fn to_persisted(&self, _blobs: &mut Blobs) -> Option<Self::Persisted> {
+      (!self.0.is_empty()).then(|| self.0.clone())
+    }
+
+    fn from_persisted(persisted: Self::Persisted, _blobs: &Blobs) -> Option<Self> {
+      Some(Self(persisted))
+    }
+  }
+
+  #[derive(Debug, Clone, PartialEq)]
+  struct BinaryItem(Vec<u8>);
+
+  impl PersistentItem for BinaryItem {
+    type Persisted = BlobRef;
+
+    fn to_persisted(&self, blobs: &mut Blobs) -> Option<Self::Persisted> {
+      Some(blobs.push(&self.0))
+    }
+
+    fn from_persisted(persisted: Self::Persisted, blobs: &Blobs) -> Option<Self> {
+      blobs.get(persisted).map(|data| Self(data.to_vec()))
+    }
+  }
+
+  fn options(dir: &TempDir, name: &str, version: &str) -> FileSystemCacheOptions {
+    FileSystemCacheOptions {
+      cache_directory: dir.path().to_string_lossy().to_string(),
+      name: name.to_string(),
+      version: version.to_string(),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn should_restore_persisted_items() {
+    let dir = TempDir::new().expect("should create temp dir");
+    let options = options(&dir, "restore", "1");
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    storage.set("a".into(), Item("a".to_string()));
+    storage.set("memory-only".into(), Item(String::new()));
+    storage.begin_idle();
+
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    assert_eq!(storage.get(&"a".into()), Some(Item("a".to_string())));
+    assert_eq!(storage.get(&"memory-only".into()), None);
+
+    let storage = FileSystemStorage::<Item>::new(&self::options(&dir, "restore", "2"), "test");
+    assert_eq!(storage.get(&"a".into()), None);
+  }
+
+  #[test]
+  fn should_store_blobs_as_binary() {
+    let dir = TempDir::new().expect("should create temp dir");
+    let options = options(&dir, "blobs", "1");
+    let storage = FileSystemStorage::<BinaryItem>::new(&options, "test");
+    storage.set("a".into(), BinaryItem(vec![0, 1, 2, 255]));
+    storage.set("b".into(), BinaryItem(vec![42; 3]));
+    storage.begin_idle();
+
+    let content = std::fs::read(&storage.pack_path).expect("should write pack");
+    assert!(content.starts_with(PACK_MAGIC));
+    assert!(content.ends_with(&[0, 1, 2, 255]) || content.ends_with(&[42; 3]));
+
+    let storage = FileSystemStorage::<BinaryItem>::new(&options, "test");
+    assert_eq!(
+      storage.get(&"a".into()),
+      Some(BinaryItem(vec![0, 1, 2, 255]))
+    );
+    assert_eq!(storage.get(&"b".into()), Some(BinaryItem(vec![42; 3])));
+  }
+
+  #[test]
+  fn should_invalidate_when_build_dependency_changes() {
+    let dir = TempDir::new().expect("should create temp dir");
+    let config_dir = dir.path().join("config");
+    std::fs::create_dir_all(config_dir.join("nested")).expect("should create dir");
+    std::fs::write(config_dir.join("nested/a.js"), "a").expect("should write file");
+    let options = FileSystemCacheOptions {
+      build_dependencies: vec![config_dir.to_string_lossy().to_string()],
+      ..options(&dir, "build-dependencies", "1")
+    };
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    storage.set("a".into(), Item("a".to_string()));
+    storage.begin_idle();
+
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    assert_eq!(storage.get(&"a".into()), Some(Item("a".to_string())));
+
+    std::fs::write(config_dir.join("nested/a.js"), "b").expect("should write file");
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    assert_eq!(storage.get(&"a".into()), None);
+  }
+
+  #[test]
+  fn should_not_write_pack_when_only_read() {
+    let dir = TempDir::new().expect("should create temp dir");
+    let options = options(&dir, "read-only", "1");
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    storage.set("a".into(), Item("a".to_string()));
+    storage.begin_idle();
+    assert!(!storage.dirty.load(Ordering::Acquire));
+
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    assert!(storage.get(&"a".into()).is_some());
+    assert!(!storage.dirty.load(Ordering::Acquire));
+    storage.remove(&"a".into());
+    assert!(storage.dirty.load(Ordering::Acquire));
+  }
+
+  #[test]
+  fn should_drop_corrupted_pack() {
+    let dir = TempDir::new().expect("should create temp dir");
+    let options = options(&dir, "corrupted", "1");
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    storage.set("a".into(), Item("a".to_string()));
+    storage.begin_idle();
+
+    let mut content = std::fs::read(&storage.pack_path).expect("should write pack");
+    content.truncate(PACK_MAGIC.len() + 4);
+    std::fs::write(&storage.pack_path, content).expect("should write pack");
+    let storage = FileSystemStorage::<Item>::new(&options, "test");
+    assert_eq!(storage.get(&"a".into()), None);
+  }
+}
@@ -1,21 +1,39 @@
 use std::fmt::Debug;
 
 use rspack_identifier::Identifier;
+use serde::{de::DeserializeOwned, Serialize};
 
 use crate::CacheOptions;
 
+mod blob;
+mod filesystem;
 mod memory;
+pub use blob::{BlobRef, Blobs};
+use filesystem::FileSystemStorage;
 use memory::MemoryStorage;
 
 pub trait Storage<Item>: Debug + Send + Sync {
   fn get(&self, id: &Identifier) -> Option<Item>;
   fn set(&self, id: Identifier, data: Item);
   fn remove(&self, id: &Identifier);
-  // fn begin_idle(&self);
+  /// Called when the compiler becomes idle, persistent storages flush their data here.
+  fn begin_idle(&self) {}
   // fn end_idle(&self);
   // fn clear(&self);
 }
 
+/// Conversion between a cache item and its on-disk representation.
+///
+/// Items which can't be represented on disk return `None` from [PersistentItem::to_persisted]
+/// and only live in memory. Binary data should be pushed to the [Blobs] of the pack instead of
+/// being part of the persisted value, which is encoded as json.
+pub trait PersistentItem: Sized {
+  type Persisted: Serialize + DeserializeOwned;
+
+  fn to_persisted(&self, blobs: &mut Blobs) -> Option<Self::Persisted>;
+  fn from_persisted(persisted: Self::Persisted, blobs: &Blobs) -> Option<Self>;
+}
+
 pub fn new_storage<Item>(options: &CacheOptions) -> Option<Box<dyn Storage<Item>>>
 where
   Item: Debug + Clone + Send + Sync + 'static,
@@ -25,3 +43,18 @@ where
     _ => Some(Box::new(MemoryStorage::new())),
   }
 }
+
+/// Same as [new_storage], but items are written to the `namespace` pack under the cache location
+/// when `cache.type` is `filesystem`.
+pub fn new_persistent_storage<Item>(
+  options: &CacheOptions,
+  namespace: &'static str,
+) -> Option<Box<dyn Storage<Item>>>
+where
+  Item: PersistentItem + Debug + Clone + Send + Sync + 'static,
+{
+  match options {
+    CacheOptions::FileSystem(options) => Some(Box::new(FileSystemStorage::new(options, namespace))),
+    _ => new_storage(options),
+  }
+}
@@ -7,8 +7,8 @@ use rspack_identifier::IdentifierMap;
 use rustc_hash::FxHashSet as HashSet;
 
 use crate::{get_chunk_from_ukey, Chunk, ChunkByUkey, ChunkGroupByUkey, ChunkGroupUkey};
-use crate::{ChunkLoading, ChunkUkey, Compilation, Filename};
-use crate::{LibraryOptions, ModuleIdentifier, PublicPath, RuntimeSpec};
+use crate::{ChunkLoading, ChunkUkey, Compilation, Filename, ModuleLayer};
+use crate::{DependencyLocation, LibraryOptions, ModuleIdentifier, PublicPath, RuntimeSpec};
 
 impl DatabaseItem for ChunkGroup {
   fn ukey(&self) -> rspack_database::Ukey<Self> {
@@ -16,6 +16,14 @@ impl DatabaseItem for ChunkGroup {
   }
 }
 
+#[derive(Debug, Clone)]
+pub struct OriginRecord {
+  /// The module creating the chunk group, `None` for entries
+  pub module_id: Option<ModuleIdentifier>,
+  pub loc: Option<DependencyLocation>,
+  pub request: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct ChunkGroup {
   pub ukey: ChunkGroupUkey,
@@ -24,6 +32,8 @@ pub struct ChunkGroup {
   pub info: ChunkGroupInfo,
   pub index: Option<u32>,
   pub parents: HashSet<ChunkGroupUkey>,
+  /// Where the chunk group is created, i.e. the entries or the blocks loading it
+  pub origins: Vec<OriginRecord>,
   pub(crate) module_pre_order_indices: IdentifierMap<usize>,
   pub(crate) module_post_order_indices: IdentifierMap<usize>,
   pub(crate) children: HashSet<ChunkGroupUkey>,
@@ -45,6 +55,7 @@ impl ChunkGroup {
       module_post_order_indices: Default::default(),
       module_pre_order_indices: Default::default(),
       parents: Default::default(),
+      origins: vec![],
       children: Default::default(),
       async_entrypoints: Default::default(),
       kind,
@@ -134,6 +145,19 @@ impl ChunkGroup {
     }
   }
 
+  pub fn add_origin(
+    &mut self,
+    module_id: Option<ModuleIdentifier>,
+    loc: Option<DependencyLocation>,
+    request: Option<String>,
+  ) {
+    self.origins.push(OriginRecord {
+      module_id,
+      loc,
+      request,
+    });
+  }
+
   pub fn add_async_entrypoint(&mut self, async_entrypoint: ChunkGroupUkey) -> bool {
     self.async_entrypoints.insert(async_entrypoint)
   }
@@ -311,12 +335,16 @@ pub type EntryRuntime = String;
 pub struct EntryOptions {
   pub name: Option<String>,
   pub runtime: Option<EntryRuntime>,
+  /// Names of the entries this entry depends on, it's loaded after them and shares their runtime
+  pub depend_on: Option<Vec<String>>,
   pub chunk_loading: Option<ChunkLoading>,
   pub async_chunks: Option<bool>,
   pub public_path: Option<PublicPath>,
   pub base_uri: Option<String>,
   pub filename: Option<Filename>,
   pub library: Option<LibraryOptions>,
+  /// Layer of the entry modules and the modules they depend on
+  pub layer: Option<ModuleLayer>,
 }
 
 impl EntryOptions {
@@ -334,12 +362,14 @@ impl EntryOptions {
     }
     merge_field!(name);
     merge_field!(runtime);
+    merge_field!(depend_on);
     merge_field!(chunk_loading);
     merge_field!(async_chunks);
     merge_field!(public_path);
     merge_field!(base_uri);
     merge_field!(filename);
     merge_field!(library);
+    merge_field!(layer);
     Ok(())
   }
 
@@ -364,7 +394,9 @@ pub enum ChunkGroupOrderKey {
   Prefetch,
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(
+  Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
 pub struct ChunkGroupOptions {
   pub name: Option<String>,
   pub preload_order: Option<u32>,
@@ -8,14 +8,14 @@ use rspack_hash::{HashDigest, HashFunction, HashSalt, RspackHash, RspackHashDige
 use rspack_identifier::IdentifierMap;
 use rspack_sources::BoxSource;
 use rustc_hash::FxHashMap as HashMap;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
   AssetInfo, ChunkInitFragments, ConcatenationScope, ModuleIdentifier, PublicPath, RuntimeGlobals,
   RuntimeMode, RuntimeSpec, RuntimeSpecMap, SourceType,
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CodeGenerationDataUrl {
   inner: String,
 }
@@ -30,7 +30,7 @@ impl CodeGenerationDataUrl {
   }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CodeGenerationDataFilename {
   filename: String,
   public_path: PublicPath,
@@ -53,7 +53,7 @@ impl CodeGenerationDataFilename {
   }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CodeGenerationDataAssetInfo {
   inner: AssetInfo,
 }
@@ -26,11 +26,13 @@ use tracing::instrument;
 use super::{
   hmr::CompilationRecords,
   make::{MakeParam, RebuildDepsBuilder},
+  records::Records,
 };
 use crate::{
   build_chunk_graph::build_chunk_graph,
   cache::{use_code_splitting_cache, Cache, CodeSplittingCache},
-  create_queue_handle, get_chunk_from_ukey, get_mut_chunk_from_ukey, is_source_equal,
+  create_queue_handle, get_chunk_from_ukey, get_entry_runtime, get_mut_chunk_from_ukey,
+  is_source_equal,
   tree_shaking::{optimizer, visitor::SymbolRef, BailoutFlag, OptimizeDependencyResult},
   AddQueue, AddTask, AddTaskResult, AdditionalChunkRuntimeRequirementsArgs,
   AdditionalModuleRequirementsArgs, AsyncDependenciesBlock, BoxDependency, BoxModule, BuildQueue,
@@ -41,11 +43,11 @@ use crate::{
   ContextDependency, DependencyId, DependencyParents, DependencyType, Entry, EntryData,
   EntryOptions, Entrypoint, ErrorSpan, FactorizeQueue, FactorizeTask, FactorizeTaskResult,
   Filename, Logger, Module, ModuleCreationCallback, ModuleFactory, ModuleFactoryResult,
-  ModuleGraph, ModuleGraphModule, ModuleIdentifier, ModuleProfile, NormalModuleSource, PathData,
-  ProcessAssetsArgs, ProcessDependenciesQueue, ProcessDependenciesResult, ProcessDependenciesTask,
-  QueueHandler, RenderManifestArgs, Resolve, ResolverFactory, RuntimeGlobals, RuntimeModule,
-  RuntimeRequirementsInTreeArgs, RuntimeSpec, SharedPluginDriver, SourceType, Stats, TaskResult,
-  WorkerTask,
+  ModuleGraph, ModuleGraphModule, ModuleIdentifier, ModuleLayer, ModuleProfile, NormalModuleSource,
+  PathData, ProcessAssetsArgs, ProcessDependenciesQueue, ProcessDependenciesResult,
+  ProcessDependenciesTask, QueueHandler, RenderManifestArgs, Resolve, ResolverFactory,
+  RuntimeGlobals, RuntimeModule, RuntimeRequirementsInTreeArgs, RuntimeSpec, SharedPluginDriver,
+  SourceType, Stats, TaskResult, WorkerTask,
 };
 use crate::{tree_shaking::visitor::OptimizeAnalyzeResult, Context};
 
@@ -63,6 +65,8 @@ pub struct Compilation {
   // So use compilation hash update `hot_index` to fix it.
   pub hot_index: u32,
   pub records: Option<CompilationRecords>,
+  /// Records read from `records_input_path`, only available when records are enabled
+  pub persisted_records: Option<Records>,
   pub options: Arc<CompilerOptions>,
   pub entries: Entry,
   pub global_entry: EntryData,
@@ -96,6 +100,8 @@ pub struct Compilation {
 
   pub code_generation_results: CodeGenerationResults,
   pub code_generated_modules: IdentifierSet,
+  /// Modules built in this compilation, modules restored from the cache are not included
+  pub built_modules: IdentifierSet,
   pub cache: Arc<Cache>,
   pub code_splitting_cache: CodeSplittingCache,
   pub hash: Option<RspackHashDigest>,
@@ -128,6 +134,7 @@ impl Compilation {
     Self {
       hot_index: 0,
       records,
+      persisted_records: None,
       options,
       module_graph,
       dependency_factories: Default::default(),
@@ -159,6 +166,7 @@ impl Compilation {
 
       code_generation_results: Default::default(),
       code_generated_modules: Default::default(),
+      built_modules: Default::default(),
       cache,
       code_splitting_cache: Default::default(),
       hash: None,
@@ -178,19 +186,14 @@ impl Compilation {
   }
 
   pub fn get_entry_runtime(&self, name: &String, options: Option<&EntryOptions>) -> RuntimeSpec {
-    let (_, runtime) = if let Some(options) = options {
-      ((), options.runtime.as_ref())
-    } else {
-      match self.entries.get(name) {
-        Some(entry) => ((), entry.options.runtime.as_ref()),
+    let options = match options {
+      Some(options) => options,
+      None => match self.entries.get(name) {
+        Some(entry) => &entry.options,
         None => return RuntimeSpec::from_iter([Arc::from(name.as_str())]),
-      }
+      },
     };
-    // TODO: depend on https://github.com/webpack/webpack/blob/1f99ad6367f2b8a6ef17cce0e058f7a67fb7db18/lib/util/runtime.js#L33, we don't have that field now
-    runtime
-      .or(Some(name))
-      .map(|runtime| RuntimeSpec::from_iter([Arc::from(runtime.as_ref())]))
-      .unwrap_or_default()
+    get_entry_runtime(name, options, &self.entries)
   }
 
   pub fn add_entry(&mut self, entry: BoxDependency, options: EntryOptions) -> Result<()> {
@@ -578,6 +581,7 @@ impl Compilation {
           parent_module
             .and_then(|m| m.as_normal_module())
             .and_then(|module| module.name_for_condition()),
+          parent_module.and_then(|m| m.get_layer()).cloned(),
           true,
           None,
         );
@@ -722,6 +726,7 @@ impl Compilation {
             module
               .as_normal_module()
               .and_then(|module| module.name_for_condition()),
+            module.get_layer().cloned(),
             true,
             Some(Box::new(move |_| {
               tx.send(())
@@ -834,10 +839,17 @@ impl Compilation {
                 }
               }
 
+              let resource_path = original_module_identifier
+                .and_then(|id| self.module_graph.module_by_identifier(&id))
+                .and_then(|module| module.as_normal_module())
+                .map(|module| module.resource_resolved_data().resource_path.clone());
               self.push_batch_diagnostic(
                 diagnostics
                   .into_iter()
-                  .map(|d| d.with_module_identifier(original_module_identifier))
+                  .map(|d| {
+                    d.with_module_identifier(original_module_identifier)
+                      .with_file(resource_path.clone())
+                  })
                   .collect(),
               );
 
@@ -951,6 +963,9 @@ impl Compilation {
               }
 
               tracing::trace!("Module built: {}", module.identifier());
+              if !from_cache {
+                self.built_modules.insert(module.identifier());
+              }
               self.push_batch_diagnostic(diagnostics);
 
               self
@@ -1226,6 +1241,7 @@ impl Compilation {
     resolve_options: Option<Box<Resolve>>,
     lazy_visit_modules: std::collections::HashSet<String>,
     issuer: Option<Box<str>>,
+    issuer_layer: Option<ModuleLayer>,
     connect_origin: bool,
     callback: Option<ModuleCreationCallback>,
   ) {
@@ -1246,6 +1262,7 @@ impl Compilation {
       original_module_identifier,
       original_module_source,
       issuer,
+      issuer_layer,
       original_module_context,
       dependency,
       dependencies,
@@ -2151,7 +2168,7 @@ impl CompilationAsset {
   }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
 pub struct AssetInfo {
   /// if the asset can be long term cached forever (contains a hash)
   pub immutable: bool,
@@ -2235,7 +2252,7 @@ impl AssetInfo {
   }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
 pub struct AssetInfoRelated {
   pub source_map: Option<String>,
 }
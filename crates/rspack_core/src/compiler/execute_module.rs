@@ -78,12 +78,14 @@ impl Compilation {
         options: Box::new(EntryOptions {
           name: Some("build time".into()),
           runtime: Some("runtime".into()),
+          depend_on: None,
           chunk_loading: Some(crate::ChunkLoading::Disable),
           async_chunks: None,
           public_path: options.public_path.clone().map(crate::PublicPath::String),
           base_uri: options.base_uri.clone(),
           filename: None,
           library: None,
+          layer: None,
         }),
       },
       crate::ChunkGroupInfo {
@@ -307,6 +309,7 @@ impl Compilation {
       original_module_identifier,
       original_module_context,
       issuer: None,
+      issuer_layer: None,
       dependency: Box::new(dep),
       dependencies: vec![dep_id],
       is_entry: false,
@@ -27,7 +27,6 @@ where
     let old_hash = self.compilation.hash.clone();
 
     let (old_all_modules, old_runtime_modules) = collect_changed_modules(old.compilation);
-    // TODO: should use `records`
 
     let mut all_old_runtime: RuntimeSpec = Default::default();
     for entry_ukey in old.compilation.get_chunk_graph_entries() {
@@ -84,6 +83,7 @@ where
       }
 
       new_compilation.hot_index = self.compilation.hot_index + 1;
+      new_compilation.persisted_records = self.compilation.persisted_records.take();
 
       let is_incremental_rebuild_make = self.options.is_incremental_rebuild_make_enabled();
       if is_incremental_rebuild_make {
@@ -3,6 +3,8 @@ mod execute_module;
 mod hmr;
 mod make;
 mod queue;
+mod records;
+mod watching;
 
 use std::collections::hash_map::Entry;
 use std::ops::Deref;
@@ -21,6 +23,8 @@ pub use self::compilation::*;
 pub use self::hmr::{collect_changed_modules, CompilationRecords};
 pub use self::make::MakeParam;
 pub use self::queue::*;
+pub use self::records::{ChunkRecords, HotRecords, ModuleRecords, Records};
+pub use self::watching::WatchOptions;
 use crate::cache::Cache;
 use crate::tree_shaking::symbol::{IndirectType, StarSymbolKind, DEFAULT_JS_WORD};
 use crate::tree_shaking::visitor::SymbolRef;
@@ -60,8 +64,14 @@ where
         debug_info.with_context(options.context.to_string());
       }
     }
-    let resolver_factory = Arc::new(ResolverFactory::new(options.resolve.clone()));
-    let loader_resolver_factory = Arc::new(ResolverFactory::new(options.resolve_loader.clone()));
+    let resolver_factory = Arc::new(ResolverFactory::new(
+      options.resolve.clone(),
+      options.input_filesystem.clone(),
+    ));
+    let loader_resolver_factory = Arc::new(ResolverFactory::new(
+      options.resolve_loader.clone(),
+      options.input_filesystem.clone(),
+    ));
     let (plugin_driver, options) = PluginDriver::new(options, plugins, resolver_factory.clone());
     let cache = Arc::new(Cache::new(options.clone()));
     let is_new_treeshaking = options.is_new_tree_shaking();
@@ -98,6 +108,10 @@ where
     // TODO: maybe it's better to use external entries.
     self.plugin_driver.resolver_factory.clear_cache();
 
+    let mut records = self.read_records()?;
+    let hot_records = records.as_mut().and_then(|records| records.hot.take());
+    let hot_index = hot_records.as_ref().map_or(0, |hot| hot.hot_index + 1);
+
     fast_set(
       &mut self.compilation,
       Compilation::new(
@@ -106,10 +120,12 @@ where
         self.plugin_driver.clone(),
         self.resolver_factory.clone(),
         self.loader_resolver_factory.clone(),
-        None,
+        hot_records.map(|hot| hot.into_compilation_records()),
         self.cache.clone(),
       ),
     );
+    self.compilation.persisted_records = records;
+    self.compilation.hot_index = hot_index;
 
     self
       .compile(vec![MakeParam::ForceBuildDeps(Default::default())])
@@ -119,6 +135,22 @@ where
     Ok(())
   }
 
+  /// Records of the previous build in this process, or the records at `records_input_path`
+  /// for the first build.
+  fn read_records(&mut self) -> Result<Option<Records>> {
+    if !self.options.is_records_enabled() {
+      return Ok(None);
+    }
+    if let Some(records) = self.compilation.persisted_records.take() {
+      return Ok(Some(records));
+    }
+    let records = match &self.options.records_input_path {
+      Some(path) => Records::read(self.options.input_filesystem.as_ref(), path)?,
+      None => None,
+    };
+    Ok(Some(records.unwrap_or_default()))
+  }
+
   #[instrument(name = "compile", skip_all)]
   async fn compile(&mut self, params: Vec<MakeParam>) -> Result<()> {
     let compilation_params = self.new_compilation_params();
@@ -289,6 +321,14 @@ where
     self.emit_assets().await?;
     logger.time_end(start);
 
+    if let Some(path) = &self.options.records_output_path
+      && let Some(records) = &self.compilation.persisted_records
+    {
+      let start = logger.time("emitRecords");
+      records.write(&self.output_filesystem, path).await?;
+      logger.time_end(start);
+    }
+
     let start = logger.time("done hook");
     self.compilation.done(self.plugin_driver.clone()).await?;
     logger.time_end(start);
@@ -10,8 +10,8 @@ use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
 use crate::{
   cache::Cache, BoxDependency, BuildContext, BuildResult, Compilation, CompilerContext,
   CompilerOptions, Context, Module, ModuleFactory, ModuleFactoryCreateData, ModuleFactoryResult,
-  ModuleGraph, ModuleGraphModule, ModuleIdentifier, ModuleProfile, Resolve, ResolverFactory,
-  SharedPluginDriver, WorkerQueue,
+  ModuleGraph, ModuleGraphModule, ModuleIdentifier, ModuleLayer, ModuleProfile, Resolve,
+  ResolverFactory, SharedPluginDriver, WorkerQueue,
 };
 use crate::{BoxModule, DependencyId, ExecuteModuleResult, ExportInfo, ExportsInfo, UsageState};
 
@@ -36,6 +36,7 @@ pub struct FactorizeTask {
   pub original_module_source: Option<BoxSource>,
   pub original_module_context: Option<Box<Context>>,
   pub issuer: Option<Box<str>>,
+  pub issuer_layer: Option<ModuleLayer>,
   pub dependency: BoxDependency,
   pub dependencies: Vec<DependencyId>,
   pub is_entry: bool,
@@ -125,6 +126,7 @@ impl WorkerTask for FactorizeTask {
       &self.options.context
     }
     .clone();
+    let issuer_layer = dependency.get_layer().cloned().or(self.issuer_layer);
 
     let other_exports_info = ExportInfo::new(None, UsageState::Unknown, None);
     let side_effects_only_info = ExportInfo::new(
@@ -161,6 +163,7 @@ impl WorkerTask for FactorizeTask {
       dependency,
       issuer: self.issuer,
       issuer_identifier: self.original_module_identifier,
+      issuer_layer,
 
       file_dependencies: Default::default(),
       missing_dependencies: Default::default(),
@@ -403,10 +406,16 @@ impl WorkerTask for BuildTask {
           .unwrap_or_else(|e| panic!("Run succeed_module hook failed: {}", e));
 
         result.map(|t| {
+          let resource_path = module
+            .as_normal_module()
+            .map(|m| m.resource_resolved_data().resource_path.clone());
           let diagnostics = module
             .clone_diagnostics()
             .into_iter()
-            .map(|d| d.with_module_identifier(Some(module.identifier())))
+            .map(|d| {
+              d.with_module_identifier(Some(module.identifier()))
+                .with_file(resource_path.clone())
+            })
             .collect();
           (t.with_diagnostic(diagnostics), module)
         })
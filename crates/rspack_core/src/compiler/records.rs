@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use rspack_error::{error, Result};
+use rspack_fs::{AsyncWritableFileSystem, InputFileSystem};
+use rspack_hash::RspackHashDigest;
+use serde::{Deserialize, Serialize};
+
+use super::{collect_changed_modules, CompilationRecords};
+use crate::{ChunkKind, Compilation, ModuleIdentifier, RuntimeSpec};
+
+/// Records of previous builds, read from `records_input_path` and written to
+/// `records_output_path`.
+///
+/// Id plugins revive the recorded ids of modules and chunks and record the ids they assigned,
+/// so ids stay the same across processes.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Records {
+  #[serde(default)]
+  pub modules: ModuleRecords,
+  #[serde(default)]
+  pub chunks: ChunkRecords,
+  /// State of the last build for hot module replacement
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub hot: Option<HotRecords>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleRecords {
+  /// Module identifiers relative to the context mapped to module ids
+  #[serde(default)]
+  pub by_identifier: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkRecords {
+  #[serde(default)]
+  pub by_name: BTreeMap<String, String>,
+  /// Chunks without a name keyed by the identifiers of their root modules
+  #[serde(default)]
+  pub by_source: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HotRecords {
+  pub hash: String,
+  pub hot_index: u32,
+  /// Chunk ids with the runtimes of the chunk
+  pub chunks: Vec<(String, Vec<String>)>,
+  /// Module identifiers mapped to the hash and the id of the module
+  pub modules: BTreeMap<String, (String, String)>,
+  /// Runtime module identifiers mapped to their generated source
+  pub runtime_modules: BTreeMap<String, String>,
+}
+
+impl HotRecords {
+  pub fn new(compilation: &Compilation) -> Option<Self> {
+    let hash = compilation.hash.as_ref()?.encoded().to_string();
+    let (modules, runtime_modules) = collect_changed_modules(compilation);
+    let chunks = compilation
+      .chunk_by_ukey
+      .values()
+      .filter(|chunk| chunk.kind != ChunkKind::HotUpdate)
+      .filter_map(|chunk| {
+        let mut runtime = chunk
+          .runtime
+          .iter()
+          .map(|r| r.to_string())
+          .collect::<Vec<_>>();
+        runtime.sort_unstable();
+        Some((chunk.id.clone()?, runtime))
+      })
+      .collect();
+    Some(Self {
+      hash,
+      hot_index: compilation.hot_index,
+      chunks,
+      modules: modules
+        .into_iter()
+        .map(|(identifier, (hash, id))| (identifier.to_string(), (hash.encoded().to_string(), id)))
+        .collect(),
+      runtime_modules: runtime_modules
+        .into_iter()
+        .map(|(identifier, source)| (identifier.to_string(), source))
+        .collect(),
+    })
+  }
+
+  pub fn into_compilation_records(self) -> CompilationRecords {
+    let mut all_old_runtime = RuntimeSpec::default();
+    let old_chunks = self
+      .chunks
+      .into_iter()
+      .map(|(id, runtime)| {
+        let runtime = runtime
+          .into_iter()
+          .map(|r| r.into())
+          .collect::<RuntimeSpec>();
+        all_old_runtime.extend(runtime.iter().cloned());
+        (id, runtime)
+      })
+      .collect();
+    CompilationRecords {
+      old_chunks,
+      all_old_runtime,
+      old_all_modules: self
+        .modules
+        .into_iter()
+        .map(|(identifier, (hash, id))| {
+          (
+            ModuleIdentifier::from(identifier),
+            (RspackHashDigest::from_encoded(&hash), id),
+          )
+        })
+        .collect(),
+      old_runtime_modules: self
+        .runtime_modules
+        .into_iter()
+        .map(|(identifier, source)| (ModuleIdentifier::from(identifier), source))
+        .collect(),
+      old_hash: Some(RspackHashDigest::from_encoded(&self.hash)),
+    }
+  }
+}
+
+impl Records {
+  /// Read the records at `path`, `None` is returned when there are no records yet.
+  pub fn read(fs: &dyn InputFileSystem, path: &Path) -> Result<Option<Self>> {
+    let content = match fs.read(path) {
+      Ok(content) => content,
+      Err(rspack_fs::Error::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+      Err(e) => return Err(e.into()),
+    };
+    serde_json::from_slice(&content)
+      .map(Some)
+      .map_err(|e| error!("Failed to parse records {}: {e}", path.display()))
+  }
+
+  pub async fn write<T: AsyncWritableFileSystem>(&self, fs: &T, path: &Path) -> Result<()> {
+    if let Some(dir) = path.parent() {
+      fs.create_dir_all(dir).await?;
+    }
+    let content =
+      serde_json::to_string_pretty(self).map_err(|e| error!("Failed to serialize records: {e}"))?;
+    fs.write(path, content.as_bytes()).await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,255 @@
+use std::collections::HashSet;
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use rspack_error::{error, Result};
+use rspack_fs::AsyncWritableFileSystem;
+use rustc_hash::FxHashMap as HashMap;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+
+use crate::{Compilation, Compiler};
+
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+  /// Delay the rebuild after the first change, in milliseconds.
+  /// Changes made during this period are aggregated into one rebuild.
+  pub aggregate_timeout: u64,
+  /// Glob patterns of paths which never trigger a rebuild.
+  pub ignored: Vec<String>,
+}
+
+impl Default for WatchOptions {
+  fn default() -> Self {
+    Self {
+      aggregate_timeout: 20,
+      ignored: vec!["**/.git/**".to_string()],
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+struct WatchedPaths {
+  files: HashSet<PathBuf>,
+  contexts: HashSet<PathBuf>,
+  missing: HashSet<PathBuf>,
+}
+
+impl WatchedPaths {
+  fn from_compilation(compilation: &Compilation) -> Self {
+    Self {
+      files: compilation.file_dependencies.iter().cloned().collect(),
+      contexts: compilation.context_dependencies.iter().cloned().collect(),
+      missing: compilation.missing_dependencies.iter().cloned().collect(),
+    }
+  }
+
+  /// The paths to register to the native watcher.
+  ///
+  /// Files are watched through their parent directory like watchpack does, a watch on the file
+  /// itself is bound to its inode and goes stale once an editor replaces the file by renaming
+  /// a new one over it. Missing paths can't be watched, so their nearest existing ancestor is
+  /// watched instead.
+  fn to_watch(&self) -> HashMap<PathBuf, RecursiveMode> {
+    let mut result = HashMap::default();
+    for file in &self.files {
+      let dir = file.parent().unwrap_or(file);
+      result
+        .entry(dir.to_path_buf())
+        .or_insert(RecursiveMode::NonRecursive);
+    }
+    for missing in &self.missing {
+      if let Some(ancestor) = missing.ancestors().skip(1).find(|p| p.exists()) {
+        result
+          .entry(ancestor.to_path_buf())
+          .or_insert(RecursiveMode::NonRecursive);
+      }
+    }
+    for context in &self.contexts {
+      result.insert(context.clone(), RecursiveMode::Recursive);
+    }
+    result
+  }
+
+  fn is_relevant(&self, path: &Path) -> bool {
+    self.files.contains(path)
+      || self.missing.contains(path)
+      || self
+        .contexts
+        .iter()
+        .any(|context| path.starts_with(context))
+  }
+}
+
+struct FsWatcher {
+  inner: RecommendedWatcher,
+  receiver: UnboundedReceiver<notify::Result<notify::Event>>,
+  watching: HashMap<PathBuf, RecursiveMode>,
+  paths: WatchedPaths,
+}
+
+impl FsWatcher {
+  fn new() -> Result<Self> {
+    let (sender, receiver) = unbounded_channel();
+    let inner = notify::recommended_watcher(move |event| {
+      // the receiver is dropped when watching is stopped
+      let _ = sender.send(event);
+    })
+    .map_err(|e| error!("Failed to create file watcher: {e}"))?;
+    Ok(Self {
+      inner,
+      receiver,
+      watching: Default::default(),
+      paths: Default::default(),
+    })
+  }
+
+  fn update(&mut self, paths: WatchedPaths) {
+    let to_watch = paths.to_watch();
+    for (path, mode) in &self.watching {
+      if to_watch.get(path) != Some(mode) {
+        let _ = self.inner.unwatch(path);
+      }
+    }
+    for (path, mode) in &to_watch {
+      if self.watching.get(path) != Some(mode) {
+        // paths might be removed between the build and here, the next build will pick them up
+        let _ = self.inner.watch(path, *mode);
+      }
+    }
+    self.watching = to_watch;
+    self.paths = paths;
+  }
+
+  /// Wait for the first relevant change, then keep collecting changes until none
+  /// arrives for `aggregate_timeout` milliseconds.
+  async fn wait(&mut self, options: &WatchOptions, output_path: &Path) -> Option<HashSet<PathBuf>> {
+    let mut changes = HashSet::new();
+    while changes.is_empty() {
+      let event = self.receiver.recv().await?;
+      self.collect(event, options, output_path, &mut changes);
+    }
+    let timeout = Duration::from_millis(options.aggregate_timeout);
+    while let Ok(event) = tokio::time::timeout(timeout, self.receiver.recv()).await {
+      // the watcher is gone, the changes collected so far are still rebuilt
+      let Some(event) = event else {
+        break;
+      };
+      self.collect(event, options, output_path, &mut changes);
+    }
+    Some(changes)
+  }
+
+  fn collect(
+    &self,
+    event: notify::Result<notify::Event>,
+    options: &WatchOptions,
+    output_path: &Path,
+    changes: &mut HashSet<PathBuf>,
+  ) {
+    let Ok(event) = event else {
+      return;
+    };
+    if event.kind.is_access() {
+      return;
+    }
+    changes.extend(event.paths.into_iter().filter(|path| {
+      !path.starts_with(output_path)
+        && self.paths.is_relevant(path)
+        && !options
+          .ignored
+          .iter()
+          .any(|pattern| glob_match::glob_match(pattern, &path.to_string_lossy()))
+    }));
+  }
+}
+
+impl<T> Compiler<T>
+where
+  T: AsyncWritableFileSystem + Send + Sync,
+{
+  /// Build once, then rebuild whenever a file, context or missing dependency of the last
+  /// compilation changes.
+  ///
+  /// `handler` is called after every build with its result, watching stops once it
+  /// returns [ControlFlow::Break].
+  pub async fn watch<F>(&mut self, options: WatchOptions, mut handler: F) -> Result<()>
+  where
+    F: FnMut(Result<&Compilation>) -> ControlFlow<()>,
+  {
+    let mut watcher = FsWatcher::new()?;
+    let output_path = self.options.output.path.clone();
+
+    let result = self.build().await;
+    watcher.update(WatchedPaths::from_compilation(&self.compilation));
+    if handler(result.map(|_| &self.compilation)).is_break() {
+      return Ok(());
+    }
+
+    while let Some(changes) = watcher.wait(&options, &output_path).await {
+      let (changed_files, removed_files) = changes
+        .into_iter()
+        .partition::<HashSet<_>, _>(|path| path.exists());
+      let result = self
+        .rebuild(
+          changed_files.into_iter().map(path_to_string).collect(),
+          removed_files.into_iter().map(path_to_string).collect(),
+        )
+        .await;
+      watcher.update(WatchedPaths::from_compilation(&self.compilation));
+      if handler(result.map(|_| &self.compilation)).is_break() {
+        break;
+      }
+    }
+    Ok(())
+  }
+}
+
+fn path_to_string(path: PathBuf) -> String {
+  path.to_string_lossy().to_string()
+}
+
+#[cfg(test)]
+mod test {
+  use tempfile::TempDir;
+
+  use super::*;
+
+  #[tokio::test]
+  async fn should_detect_files_replaced_by_rename() {
+    let dir = TempDir::new().expect("should create temp dir");
+    let dir = dir
+      .path()
+      .canonicalize()
+      .expect("should canonicalize temp dir");
+    let file = dir.join("index.js");
+    std::fs::write(&file, "0").expect("should write file");
+
+    let mut watcher = FsWatcher::new().expect("should create watcher");
+    watcher.update(WatchedPaths {
+      files: HashSet::from([file.clone()]),
+      ..Default::default()
+    });
+    let output_path = dir.join("dist");
+    let options = WatchOptions::default();
+
+    for content in ["1", "2"] {
+      let temp = dir.join("index.js.tmp");
+      std::fs::write(&temp, content).expect("should write file");
+      std::fs::rename(&temp, &file).expect("should rename file");
+      let changes =
+        tokio::time::timeout(Duration::from_secs(5), watcher.wait(&options, &output_path))
+          .await
+          .expect("should detect the change")
+          .expect("should keep watching");
+      assert!(changes.contains(&file));
+      assert!(!changes.contains(&temp));
+      // the watch should survive the inode change
+      watcher.update(WatchedPaths {
+        files: HashSet::from([file.clone()]),
+        ..Default::default()
+      });
+    }
+  }
+}
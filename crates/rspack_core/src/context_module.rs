@@ -1,7 +1,7 @@
 use std::{
   borrow::Cow,
+  collections::BTreeMap,
   fmt::{self, Display},
-  fs,
   hash::Hash,
   path::{Path, PathBuf},
   sync::Arc,
@@ -11,7 +11,8 @@ use itertools::Itertools;
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
 use rspack_core_macros::impl_source_map_config;
-use rspack_error::{impl_empty_diagnosable_trait, miette::IntoDiagnostic, Diagnostic, Result};
+use rspack_error::{impl_empty_diagnosable_trait, Diagnostic, Result};
+use rspack_fs::InputFileSystem;
 use rspack_hash::RspackHash;
 use rspack_identifier::{Identifiable, Identifier};
 use rspack_regex::RspackRegex;
@@ -26,7 +27,7 @@ use crate::{
   BoxDependency, BuildContext, BuildInfo, BuildMeta, BuildResult, ChunkGraph, ChunkGroupOptions,
   CodeGenerationResult, Compilation, ConcatenationScope, ContextElementDependency,
   DependenciesBlock, DependencyCategory, DependencyId, ExportsType, FakeNamespaceObjectMode,
-  GroupOptions, LibIdentOptions, Module, ModuleType, Resolve, ResolveInnerOptions,
+  GroupOptions, LibIdentOptions, Module, ModuleLayer, ModuleType, Resolve, ResolveInnerOptions,
   ResolveOptionsWithDependencyType, ResolverFactory, RuntimeGlobals, RuntimeSpec, SourceType,
 };
 
@@ -169,6 +170,11 @@ pub struct ContextModuleOptions {
   pub resource_fragment: Option<String>,
   pub context_options: ContextOptions,
   pub resolve_options: Option<Box<Resolve>>,
+  /// Content of the context given by plugins instead of reading `resource`,
+  /// requests of the context mapped to the requests of their modules
+  pub context_map: Option<BTreeMap<String, String>>,
+  /// Layer of the issuer, modules of the context are created in it
+  pub layer: Option<ModuleLayer>,
 }
 
 impl Display for ContextModuleOptions {
@@ -177,7 +183,14 @@ impl Display for ContextModuleOptions {
       f,
       "{}|{:?}|{:?}|{:?}",
       self.resource, self.resource_query, self.resource_fragment, self.context_options
-    )
+    )?;
+    if let Some(context_map) = &self.context_map {
+      write!(f, "|{context_map:?}")?;
+    }
+    if let Some(layer) = &self.layer {
+      write!(f, "|layer: {layer}")?;
+    }
+    Ok(())
   }
 }
 
@@ -610,9 +623,16 @@ impl Module for ContextModule {
       id.push_str(" recursive ");
     }
     id.push_str(&self.options.context_options.reg_str);
+    if let Some(layer) = &self.options.layer {
+      id = format!("({layer})/{id}");
+    }
     Some(Cow::Owned(id))
   }
 
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    self.options.layer.as_ref()
+  }
+
   async fn build(
     &mut self,
     build_context: BuildContext<'_>,
@@ -717,15 +737,17 @@ impl ContextModule {
     dependencies: &mut Vec<ContextElementDependency>,
     options: &ContextModuleOptions,
     resolve_options: &ResolveInnerOptions,
+    fs: &dyn InputFileSystem,
   ) -> Result<()> {
-    if !dir.is_dir() {
+    let is_dir = |path: &Path| fs.metadata(path).is_ok_and(|m| m.is_directory);
+    if !is_dir(dir) {
       return Ok(());
     }
-    for entry in fs::read_dir(dir).into_diagnostic()? {
-      let path = entry.into_diagnostic()?.path();
-      if path.is_dir() {
+    for name in fs.read_dir(dir)? {
+      let path = dir.join(name);
+      if is_dir(&path) {
         if options.context_options.recursive {
-          Self::visit_dirs(ctx, &path, dependencies, options, resolve_options)?;
+          Self::visit_dirs(ctx, &path, dependencies, options, resolve_options, fs)?;
         }
       } else if path
         .file_name()
@@ -761,27 +783,41 @@ impl ContextModule {
           if !reg_exp.test(&r.request) {
             return;
           }
-          dependencies.push(ContextElementDependency {
-            id: DependencyId::new(),
-            request: format!(
-              "{}{}{}",
-              r.request,
-              options.resource_query.clone().unwrap_or_default(),
-              options.resource_fragment.clone().unwrap_or_default()
-            ),
-            user_request: r.request.to_string(),
-            category: options.context_options.category,
-            context: options.resource.clone().into(),
-            options: options.context_options.clone(),
-            resource_identifier: format!("context{}|{}", &options.resource, path.to_string_lossy()),
-            referenced_exports: None,
-          });
+          dependencies.push(Self::create_element_dependency(
+            options,
+            &r.request,
+            r.request.to_string(),
+            &path.to_string_lossy(),
+          ));
         })
       }
     }
     Ok(())
   }
 
+  fn create_element_dependency(
+    options: &ContextModuleOptions,
+    request: &str,
+    user_request: String,
+    resource: &str,
+  ) -> ContextElementDependency {
+    ContextElementDependency {
+      id: DependencyId::new(),
+      request: format!(
+        "{}{}{}",
+        request,
+        options.resource_query.clone().unwrap_or_default(),
+        options.resource_fragment.clone().unwrap_or_default()
+      ),
+      user_request,
+      category: options.context_options.category,
+      context: options.resource.clone().into(),
+      options: options.context_options.clone(),
+      resource_identifier: format!("context{}|{}", &options.resource, resource),
+      referenced_exports: None,
+    }
+  }
+
   fn resolve_dependencies(&self, build_context: BuildContext<'_>) -> Result<BuildResult> {
     tracing::trace!("resolving context module path {}", self.options.resource);
 
@@ -792,13 +828,20 @@ impl ContextModule {
     });
 
     let mut context_element_dependencies = vec![];
-    Self::visit_dirs(
-      &self.options.resource,
-      Path::new(&self.options.resource),
-      &mut context_element_dependencies,
-      &self.options,
-      &resolver.options(),
-    )?;
+    if let Some(context_map) = &self.options.context_map {
+      context_element_dependencies.extend(context_map.iter().map(|(user_request, request)| {
+        Self::create_element_dependency(&self.options, request, user_request.clone(), request)
+      }));
+    } else {
+      Self::visit_dirs(
+        &self.options.resource,
+        Path::new(&self.options.resource),
+        &mut context_element_dependencies,
+        &self.options,
+        &resolver.options(),
+        &*build_context.compiler_options.input_filesystem,
+      )?;
+    }
     context_element_dependencies.sort_by_cached_key(|d| d.user_request.to_string());
 
     tracing::trace!(
@@ -4,9 +4,10 @@ use rspack_error::Result;
 use tracing::instrument;
 
 use crate::{
-  cache::Cache, resolve, BoxModule, ContextModule, ContextModuleOptions, ModuleExt, ModuleFactory,
-  ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier, NormalModuleBeforeResolveArgs,
-  RawModule, ResolveArgs, ResolveResult, SharedPluginDriver,
+  cache::Cache, resolve, BoxModule, ContextModule, ContextModuleAfterResolveArgs,
+  ContextModuleOptions, ModuleExt, ModuleFactory, ModuleFactoryCreateData, ModuleFactoryResult,
+  ModuleIdentifier, NormalModuleBeforeResolveArgs, RawModule, ResolveArgs, ResolveResult,
+  SharedPluginDriver,
 };
 
 #[derive(Debug)]
@@ -97,16 +98,34 @@ impl ContextModuleFactory {
     };
 
     let module = match resource_data {
-      Ok(ResolveResult::Resource(resource)) => Box::new(ContextModule::new(
-        ContextModuleOptions {
+      Ok(ResolveResult::Resource(resource)) => {
+        let mut after_resolve_args = ContextModuleAfterResolveArgs {
           resource: resource.path.to_string_lossy().to_string(),
-          resource_query: resource.query,
-          resource_fragment: resource.fragment,
-          resolve_options: data.resolve_options.clone(),
           context_options: dependency.options().clone(),
-        },
-        plugin_driver.resolver_factory.clone(),
-      )) as BoxModule,
+          context_map: None,
+        };
+        if let Some(false) = plugin_driver
+          .context_module_after_resolve(&mut after_resolve_args)
+          .await?
+        {
+          // ignored
+          data.add_file_dependencies(file_dependencies);
+          data.add_missing_dependencies(missing_dependencies);
+          return Ok(ModuleFactoryResult::default());
+        }
+        Box::new(ContextModule::new(
+          ContextModuleOptions {
+            resource: after_resolve_args.resource,
+            resource_query: resource.query,
+            resource_fragment: resource.fragment,
+            resolve_options: data.resolve_options.clone(),
+            context_options: after_resolve_args.context_options,
+            context_map: after_resolve_args.context_map,
+            layer: data.issuer_layer.clone(),
+          },
+          plugin_driver.resolver_factory.clone(),
+        )) as BoxModule
+      }
       Ok(ResolveResult::Ignored) => {
         let ident = format!("{}/{}", data.context, specifier);
         let module_identifier = ModuleIdentifier::from(format!("ignored|{ident}"));
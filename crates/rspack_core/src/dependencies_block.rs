@@ -113,6 +113,10 @@ impl AsyncDependenciesBlock {
     self.dependencies.push(dependency);
   }
 
+  pub fn dependencies(&self) -> &[BoxDependency] {
+    &self.dependencies
+  }
+
   pub fn take_dependencies(&mut self) -> Vec<BoxDependency> {
     std::mem::take(&mut self.dependencies)
   }
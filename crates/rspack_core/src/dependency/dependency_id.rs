@@ -1,7 +1,9 @@
+use std::cell::RefCell;
 use std::sync::atomic::Ordering::Relaxed;
 use std::{collections::hash_map::Entry, sync::atomic::AtomicU32};
 
-use serde::Serialize;
+use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
 use swc_core::ecma::atoms::Atom;
 
 use crate::{BoxDependency, DependencyExtraMeta, ModuleGraph};
@@ -11,6 +13,10 @@ pub struct DependencyId(pub u32);
 
 pub static DEPENDENCY_ID: AtomicU32 = AtomicU32::new(0);
 
+thread_local! {
+  static RESTORED_IDS: RefCell<Option<HashMap<u32, DependencyId>>> = Default::default();
+}
+
 impl DependencyId {
   pub fn get_dependency<'a>(&self, mg: &'a ModuleGraph) -> &'a BoxDependency {
     mg.dependency_by_id(self).expect("should have dependency")
@@ -20,6 +26,26 @@ impl DependencyId {
     Self(DEPENDENCY_ID.fetch_add(1, Relaxed))
   }
 
+  /// Dependency ids deserialized in `f` are replaced with new ids, since the persisted ones may
+  /// already be used in this process. The same persisted id is always replaced with the same new
+  /// id, so dependencies referencing each other by id keep doing so.
+  pub fn with_restored_ids<R>(f: impl FnOnce() -> R) -> R {
+    let previous = RESTORED_IDS.with(|ids| ids.borrow_mut().replace(Default::default()));
+    let result = f();
+    RESTORED_IDS.with(|ids| *ids.borrow_mut() = previous);
+    result
+  }
+
+  /// The new id of a persisted id, `None` outside of [DependencyId::with_restored_ids]
+  pub fn restored(id: u32) -> Option<Self> {
+    RESTORED_IDS.with(|ids| {
+      ids
+        .borrow_mut()
+        .as_mut()
+        .map(|ids| *ids.entry(id).or_insert_with(DependencyId::new))
+    })
+  }
+
   pub fn set_ids(&self, ids: Vec<Atom>, mg: &mut ModuleGraph) {
     match mg.dep_meta_map.entry(*self) {
       Entry::Occupied(mut occ) => {
@@ -41,6 +67,18 @@ impl DependencyId {
   }
 }
 
+impl<'de> Deserialize<'de> for DependencyId {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let id = u32::deserialize(deserializer)?;
+    Self::restored(id).ok_or_else(|| {
+      serde::de::Error::custom("dependency ids can only be deserialized when they are restored")
+    })
+  }
+}
+
 impl Default for DependencyId {
   fn default() -> Self {
     Self::new()
@@ -10,7 +10,9 @@ use super::module_dependency::*;
 use super::ExportsSpec;
 use super::{DependencyCategory, DependencyId, DependencyType};
 use crate::AsContextDependency;
-use crate::{ConnectionState, Context, ErrorSpan, ModuleGraph, ModuleIdentifier, UsedByExports};
+use crate::{
+  ConnectionState, Context, ErrorSpan, ModuleGraph, ModuleIdentifier, ModuleLayer, UsedByExports,
+};
 
 pub trait Dependency:
   AsDependencyTemplate
@@ -39,6 +41,11 @@ pub trait Dependency:
     None
   }
 
+  /// Layer the module of this dependency is created in, instead of the layer of the issuer
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    None
+  }
+
   fn get_exports(&self, _mg: &ModuleGraph) -> Option<ExportsSpec> {
     None
   }
@@ -5,7 +5,7 @@ use crate::ErrorSpan;
 
 // Used to describe dependencies' types, see webpack's `type` getter in `Dependency`
 // Note: This is almost the same with the old `ResolveKind`
-#[derive(Default, Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Default, Clone, PartialEq, Eq, Hash, Debug, serde::Serialize, serde::Deserialize)]
 pub enum DependencyType {
   #[default]
   Unknown,
@@ -24,6 +24,8 @@ pub enum DependencyType {
   DynamicImport,
   // import() eager
   DynamicImportEager,
+  // import() of a module activated by lazy compilation
+  LazyImport,
   // cjs require
   CjsRequire,
   // cjs full require
@@ -123,6 +125,7 @@ impl DependencyType {
       // TODO: mode
       DependencyType::ImportContext => Cow::Borrowed("import context"),
       DependencyType::DynamicImportEager => Cow::Borrowed("import() eager"),
+      DependencyType::LazyImport => Cow::Borrowed("lazy import()"),
       DependencyType::CommonJSRequireContext => Cow::Borrowed("commonjs require context"),
       DependencyType::RequireContext => Cow::Borrowed("require.context"),
       DependencyType::RequireResolve => Cow::Borrowed("require.resolve"),
@@ -13,6 +13,7 @@ mod loader_import_dependency;
 mod module_dependency;
 mod runtime_requirements_dependency;
 mod runtime_template;
+mod serializable_dependency;
 mod span;
 mod static_exports_dependency;
 
@@ -32,6 +33,7 @@ pub use loader_import_dependency::LoaderImportDependency;
 pub use module_dependency::*;
 pub use runtime_requirements_dependency::RuntimeRequirementsDependency;
 pub use runtime_template::*;
+pub use serializable_dependency::*;
 pub use span::SpanExt;
 pub use static_exports_dependency::StaticExportsDependency;
 use swc_core::ecma::atoms::Atom;
@@ -163,15 +165,15 @@ pub mod needs_refactor {
     common::{EqIgnoreSpan, Spanned, SyntaxContext, DUMMY_SP},
     ecma::{
       ast::{
-        Expr, ExprOrSpread, Id, Ident, ImportDecl, Lit, MemberExpr, MemberProp, MetaPropExpr,
-        MetaPropKind, ModuleExportName, NewExpr,
+        CallExpr, Callee, Expr, ExprOrSpread, Id, Ident, ImportDecl, Lit, MemberExpr, MemberProp,
+        MetaPropExpr, MetaPropKind, ModuleExportName, NewExpr,
       },
       atoms::Atom,
       visit::Visit,
     },
   };
 
-  use crate::SpanExt;
+  use crate::{CompilerOptions, ModuleType, SpanExt};
 
   static IMPORT_META: Lazy<Expr> = Lazy::new(|| {
     Expr::Member(MemberExpr {
@@ -224,6 +226,7 @@ pub mod needs_refactor {
   pub struct WorkerSyntaxList {
     variables: Vec<WorkerSyntax>,
     globals: Vec<WorkerSyntax>,
+    calls: Vec<WorkerCallSyntax>,
   }
 
   impl WorkerSyntaxList {
@@ -235,6 +238,10 @@ pub mod needs_refactor {
       }
     }
 
+    pub fn push_call(&mut self, syntax: WorkerCallSyntax) {
+      self.calls.push(syntax);
+    }
+
     fn find_worker_syntax(&self, ident: &Ident) -> Option<&WorkerSyntax> {
       (self.variables.iter().chain(self.globals.iter())).find(|s| s.matches(ident))
     }
@@ -242,6 +249,31 @@ pub mod needs_refactor {
     pub fn match_new_worker(&self, new_expr: &NewExpr) -> bool {
       matches!(&*new_expr.callee, Expr::Ident(ident) if self.find_worker_syntax(ident).is_some())
     }
+
+    pub fn match_worker_call(&self, call_expr: &CallExpr) -> Option<&WorkerCallSyntax> {
+      let Callee::Expr(callee) = &call_expr.callee else {
+        return None;
+      };
+      let chain = get_member_chain(callee)?;
+      self.calls.iter().find(|s| s.matches(&chain))
+    }
+  }
+
+  /// `navigator.serviceWorker.register` as `["navigator", "serviceWorker", "register"]`
+  fn get_member_chain(expr: &Expr) -> Option<Vec<&Atom>> {
+    match expr {
+      Expr::Ident(ident) => Some(vec![&ident.sym]),
+      Expr::Member(MemberExpr {
+        obj,
+        prop: MemberProp::Ident(prop),
+        ..
+      }) => {
+        let mut chain = get_member_chain(obj)?;
+        chain.push(&prop.sym);
+        Some(chain)
+      }
+      _ => None,
+    }
   }
 
   impl Extend<WorkerSyntax> for WorkerSyntaxList {
@@ -273,24 +305,108 @@ pub mod needs_refactor {
     }
   }
 
+  /// Calls creating workers, e.g. `navigator.serviceWorker.register()`.
+  #[derive(Debug, PartialEq, Eq)]
+  pub struct WorkerCallSyntax {
+    members: Vec<Atom>,
+    /// Created from `*audioWorklet.addModule()`, matches the members on any object,
+    /// e.g. `context.audioWorklet.addModule()`
+    any_object: bool,
+  }
+
+  impl WorkerCallSyntax {
+    pub fn new(members: Vec<Atom>, any_object: bool) -> Self {
+      Self {
+        members,
+        any_object,
+      }
+    }
+
+    /// Worklets, e.g. `CSS.paintWorklet.addModule()`, can't load chunks
+    pub fn is_worklet(&self) -> bool {
+      self
+        .members
+        .iter()
+        .rev()
+        .nth(1)
+        .is_some_and(|object| object.ends_with("Worklet"))
+    }
+
+    fn matches(&self, chain: &[&Atom]) -> bool {
+      if self.any_object {
+        chain.len() >= self.members.len()
+          && chain[chain.len() - self.members.len()..]
+            .iter()
+            .zip(&self.members)
+            .all(|(a, b)| *a == b)
+      } else {
+        chain.len() == self.members.len() && chain.iter().zip(&self.members).all(|(a, b)| *a == b)
+      }
+    }
+  }
+
   pub struct WorkerSyntaxScanner<'a> {
     pub result: WorkerSyntaxList,
     caps: Vec<(&'a str, &'a str)>,
   }
 
-  pub const DEFAULT_WORKER_SYNTAX: &[&str] =
-    &["Worker", "SharedWorker", "Worker from worker_threads"];
+  /// Default of `module.parser.javascript.worker`, `"..."` in the option is replaced by it.
+  pub const DEFAULT_WORKER_SYNTAX: &[&str] = &[
+    "Worker",
+    "SharedWorker",
+    "navigator.serviceWorker.register()",
+    "Worker from worker_threads",
+  ];
+
+  /// Worker syntaxes configured by `module.parser.javascript.worker` for the module type.
+  pub fn get_worker_syntax<'a>(
+    compiler_options: &'a CompilerOptions,
+    module_type: &'a ModuleType,
+  ) -> Vec<&'a str> {
+    match compiler_options
+      .module
+      .parser
+      .as_ref()
+      .and_then(|p| p.get(module_type))
+      .and_then(|p| p.get_javascript(module_type))
+    {
+      Some(options) => options.worker.iter().map(String::as_str).collect(),
+      None => DEFAULT_WORKER_SYNTAX.to_vec(),
+    }
+  }
 
   impl<'a> WorkerSyntaxScanner<'a> {
+    /// Syntaxes are in the format of `module.parser.javascript.worker`:
+    /// - `Worker`: `new Worker(new URL(...))`
+    /// - `Worker from worker_threads`: `new Worker(new URL(...))` with `Worker` imported from
+    ///   `worker_threads`
+    /// - `navigator.serviceWorker.register()`: `navigator.serviceWorker.register(new URL(...))`
+    /// - `*audioWorklet.addModule()`: `context.audioWorklet.addModule(new URL(...))`
     pub fn new(syntax: &'a [&'a str]) -> Self {
       let mut result = WorkerSyntaxList::default();
       let mut caps = Vec::new();
+      let syntax = syntax.iter().flat_map(|s| {
+        if *s == "..." {
+          DEFAULT_WORKER_SYNTAX
+        } else {
+          std::slice::from_ref(s)
+        }
+      });
       for s in syntax {
         if let Some(captures) = WORKER_FROM_REGEX.captures(s)
           && let Some(ids) = captures.get(1)
           && let Some(source) = captures.get(3)
         {
           caps.push((ids.as_str(), source.as_str()));
+        } else if let Some(call) = s.strip_suffix("()") {
+          let (call, any_object) = match call.strip_prefix('*') {
+            Some(call) => (call, true),
+            None => (call, false),
+          };
+          result.push_call(WorkerCallSyntax::new(
+            call.split('.').map(Atom::from).collect(),
+            any_object,
+          ));
         } else {
           result.push(WorkerSyntax::new(Atom::from(*s), None))
         }
@@ -1,6 +1,6 @@
 use crate::{DependencyTemplate, RuntimeGlobals, TemplateContext, TemplateReplaceSource};
 
-#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+#[derive(Debug, Eq, PartialEq, Clone, Hash, serde::Serialize, serde::Deserialize)]
 pub struct RuntimeRequirementsDependency {
   pub runtime_requirements: RuntimeGlobals,
 }
@@ -0,0 +1,132 @@
+//! Dependencies and dependency templates which can be written to the persistent cache.
+//!
+//! Dependencies are trait objects implemented across the plugin crates, so they are persisted
+//! through a registry keyed by their type, like `makeSerializable` of webpack. Plugins register
+//! the dependencies they create when they are applied, which is before the persistent cache is
+//! restored. Modules with a dependency which isn't registered are not persisted.
+
+use std::any::{type_name, Any, TypeId};
+
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+  BoxDependency, BoxDependencyTemplate, Dependency, DependencyTemplate, ModuleDependency,
+};
+
+/// On-disk representation of a dependency or a dependency template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistedDependency {
+  /// Name of the registered type
+  r#type: String,
+  data: serde_json::Value,
+}
+
+struct Registration {
+  name: &'static str,
+  serialize: fn(&dyn Any) -> Option<serde_json::Value>,
+  dependency: Option<fn(serde_json::Value) -> Option<BoxDependency>>,
+  module_dependency: Option<fn(serde_json::Value) -> Option<Box<dyn ModuleDependency>>>,
+  dependency_template: Option<fn(serde_json::Value) -> Option<BoxDependencyTemplate>>,
+}
+
+static REGISTRATIONS: Lazy<DashMap<TypeId, Registration>> = Lazy::new(Default::default);
+static REGISTERED_NAMES: Lazy<DashMap<&'static str, TypeId>> = Lazy::new(Default::default);
+
+fn register<T: Serialize + 'static>(update: impl FnOnce(&mut Registration)) {
+  let name = type_name::<T>();
+  let mut registration = REGISTRATIONS
+    .entry(TypeId::of::<T>())
+    .or_insert_with(|| Registration {
+      name,
+      serialize: |value| serde_json::to_value(value.downcast_ref::<T>()?).ok(),
+      dependency: None,
+      module_dependency: None,
+      dependency_template: None,
+    });
+  update(&mut registration);
+  REGISTERED_NAMES.insert(name, TypeId::of::<T>());
+}
+
+fn serialize(value: &dyn Any) -> Option<PersistedDependency> {
+  let registration = REGISTRATIONS.get(&value.type_id())?;
+  Some(PersistedDependency {
+    r#type: registration.name.to_string(),
+    data: (registration.serialize)(value)?,
+  })
+}
+
+fn deserialize<T>(
+  persisted: PersistedDependency,
+  deserializer: impl FnOnce(&Registration) -> Option<fn(serde_json::Value) -> Option<T>>,
+) -> Option<T> {
+  let type_id = *REGISTERED_NAMES.get(persisted.r#type.as_str())?;
+  let deserialize = deserializer(&*REGISTRATIONS.get(&type_id)?)?;
+  deserialize(persisted.data)
+}
+
+pub fn register_serializable_dependency<D>()
+where
+  D: Dependency + Serialize + DeserializeOwned + 'static,
+{
+  register::<D>(|registration| {
+    registration.dependency = Some(|data| Some(Box::new(serde_json::from_value::<D>(data).ok()?)));
+  });
+}
+
+/// Registers `D` for both the dependencies and the code generation dependencies of modules
+pub fn register_serializable_module_dependency<D>()
+where
+  D: ModuleDependency + Serialize + DeserializeOwned + 'static,
+{
+  register_serializable_dependency::<D>();
+  register::<D>(|registration| {
+    registration.module_dependency =
+      Some(|data| Some(Box::new(serde_json::from_value::<D>(data).ok()?)));
+  });
+}
+
+pub fn register_serializable_dependency_template<T>()
+where
+  T: DependencyTemplate + Serialize + DeserializeOwned + 'static,
+{
+  register::<T>(|registration| {
+    registration.dependency_template =
+      Some(|data| Some(Box::new(serde_json::from_value::<T>(data).ok()?)));
+  });
+}
+
+/// `None` when the type of the dependency isn't registered
+pub fn serialize_dependency(dependency: &dyn Dependency) -> Option<PersistedDependency> {
+  serialize(dependency.as_any())
+}
+
+pub fn serialize_module_dependency(
+  dependency: &dyn ModuleDependency,
+) -> Option<PersistedDependency> {
+  serialize(dependency.as_any())
+}
+
+pub fn serialize_dependency_template(
+  template: &dyn DependencyTemplate,
+) -> Option<PersistedDependency> {
+  serialize(template.as_any())
+}
+
+/// Ids of the dependencies are restored as well, see [crate::DependencyId::with_restored_ids]
+pub fn deserialize_dependency(persisted: PersistedDependency) -> Option<BoxDependency> {
+  deserialize(persisted, |registration| registration.dependency)
+}
+
+pub fn deserialize_module_dependency(
+  persisted: PersistedDependency,
+) -> Option<Box<dyn ModuleDependency>> {
+  deserialize(persisted, |registration| registration.module_dependency)
+}
+
+pub fn deserialize_dependency_template(
+  persisted: PersistedDependency,
+) -> Option<BoxDependencyTemplate> {
+  deserialize(persisted, |registration| registration.dependency_template)
+}
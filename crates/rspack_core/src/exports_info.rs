@@ -484,6 +484,13 @@ pub enum ProvidedExports {
   True,
   Vec(Vec<Atom>),
 }
+
+/// Webpack returns `null | boolean | SortableSet<string>`
+pub enum UsedExports {
+  Null,
+  Bool(bool),
+  Vec(Vec<Atom>),
+}
 impl ExportsInfo {
   pub fn new(other_exports_info: ExportInfoId, _side_effects_only_info: ExportInfoId) -> Self {
     Self {
@@ -538,6 +545,52 @@ impl ExportsInfo {
     ProvidedExports::Vec(ret)
   }
 
+  pub fn get_used_exports(&self, runtime: Option<&RuntimeSpec>, mg: &ModuleGraph) -> UsedExports {
+    if self.redirect_to.is_none() {
+      match self.other_exports_info.get_used(mg, runtime) {
+        UsageState::NoInfo => return UsedExports::Null,
+        UsageState::Unknown | UsageState::OnlyPropertiesUsed | UsageState::Used => {
+          return UsedExports::Bool(true)
+        }
+        UsageState::Unused => {}
+      }
+    }
+    let mut ret = vec![];
+    for export_info_id in self.exports.values() {
+      let export_info = export_info_id.get_export_info(mg);
+      match export_info.get_used(runtime) {
+        UsageState::NoInfo => return UsedExports::Null,
+        UsageState::Unknown => return UsedExports::Bool(true),
+        UsageState::OnlyPropertiesUsed | UsageState::Used => {
+          ret.push(export_info.name.clone().unwrap_or("".into()));
+        }
+        UsageState::Unused => {}
+      }
+    }
+    if let Some(id) = self.redirect_to {
+      match id.get_exports_info(mg).get_used_exports(runtime, mg) {
+        UsedExports::Null => return UsedExports::Null,
+        UsedExports::Bool(true) => return UsedExports::Bool(true),
+        UsedExports::Bool(false) => {}
+        UsedExports::Vec(inner) => {
+          for item in inner {
+            if !ret.contains(&item) {
+              ret.push(item);
+            }
+          }
+        }
+      }
+    }
+    if ret.is_empty() {
+      match self._side_effects_only_info.get_used(mg, runtime) {
+        UsageState::NoInfo => return UsedExports::Null,
+        UsageState::Unused => return UsedExports::Bool(false),
+        _ => {}
+      }
+    }
+    UsedExports::Vec(ret)
+  }
+
   /// exports that are relevant (not unused and potential provided)
   pub fn get_relevant_exports(
     &self,
@@ -611,6 +664,21 @@ impl ExportsInfo {
     true
   }
 
+  /// Modules with the same usage key have their exports used in the same way in the runtime
+  pub fn get_usage_key(&self, runtime: Option<&RuntimeSpec>, mg: &ModuleGraph) -> Vec<UsageState> {
+    let mut key = vec![];
+    if let Some(redirect_to) = self.redirect_to {
+      key.extend(redirect_to.get_exports_info(mg).get_usage_key(runtime, mg));
+    } else {
+      key.push(self.other_exports_info.get_used(mg, runtime));
+    }
+    key.push(self._side_effects_only_info.get_used(mg, runtime));
+    for export_info_id in self.owned_exports() {
+      key.push(export_info_id.get_used(mg, runtime));
+    }
+    key
+  }
+
   pub fn get_used(
     &self,
     name: UsedName,
@@ -1644,7 +1712,7 @@ pub enum RuntimeUsageStateType {
   Used,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, serde::Deserialize)]
 pub enum UsedByExports {
   Set(HashSet<Atom>),
   Bool(bool),
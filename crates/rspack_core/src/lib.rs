@@ -104,7 +104,9 @@ pub use rspack_sources;
 #[cfg(debug_assertions)]
 pub mod debug_info;
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(
+  Default, Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
 pub enum SourceType {
   JavaScript,
   Css,
@@ -1,6 +1,6 @@
 use std::sync::Mutex;
 
-use rspack_error::Result;
+use rspack_error::{error, Result};
 use rspack_loader_runner::{Content, LoaderContext, LoaderRunnerPlugin, ResourceData};
 
 use crate::{CompilerContext, NormalModule, SharedPluginDriver};
@@ -37,6 +37,20 @@ impl LoaderRunnerPlugin for RspackLoaderRunnerPlugin<'_> {
       return Ok(result);
     }
 
-    Ok(None)
+    if resource_data.resource_path.as_os_str().is_empty() {
+      return Ok(None);
+    }
+    let content = self
+      .plugin_driver
+      .options
+      .input_filesystem
+      .read(&resource_data.resource_path)
+      .map_err(|e| {
+        error!(
+          "{e}, failed to read {}",
+          resource_data.resource_path.to_string_lossy()
+        )
+      })?;
+    Ok(Some(Content::from(content)))
   }
 }
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::hash::Hash;
 use std::ops::Deref;
@@ -13,6 +14,7 @@ use rspack_sources::Source;
 use rspack_util::ext::{AsAny, DynEq, DynHash};
 use rspack_util::source_map::ModuleSourceMapConfig;
 use rustc_hash::FxHashSet as HashSet;
+use serde::{Deserialize, Serialize};
 use swc_core::ecma::atoms::Atom;
 
 use crate::tree_shaking::visitor::OptimizeAnalyzeResult;
@@ -29,7 +31,7 @@ pub struct BuildContext<'a> {
   pub compiler_options: &'a CompilerOptions,
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BuildExtraDataType {
   CssParserAndGenerator,
   AssetParserAndGenerator,
@@ -53,7 +55,7 @@ pub struct BuildInfo {
   pub json_data: Option<JsonValue>,
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BuildMetaExportsType {
   #[default]
   Unset,
@@ -71,7 +73,7 @@ pub enum ExportsType {
   Dynamic,
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash)]
+#[derive(Debug, Default, Clone, Copy, Hash, Serialize, Deserialize)]
 pub enum BuildMetaDefaultObject {
   #[default]
   False,
@@ -79,7 +81,7 @@ pub enum BuildMetaDefaultObject {
   RedirectWarn,
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash)]
+#[derive(Debug, Default, Clone, Copy, Hash, Serialize, Deserialize)]
 pub enum ModuleArgument {
   #[default]
   Module,
@@ -95,7 +97,7 @@ impl Display for ModuleArgument {
   }
 }
 
-#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ExportsArgument {
   #[default]
   Exports,
@@ -111,7 +113,7 @@ impl Display for ExportsArgument {
   }
 }
 
-#[derive(Debug, Default, Clone, Hash)]
+#[derive(Debug, Default, Clone, Hash, Serialize, Deserialize)]
 pub struct BuildMeta {
   pub strict_harmony_module: bool,
   pub has_top_level_await: bool,
@@ -121,6 +123,8 @@ pub struct BuildMeta {
   pub module_argument: ModuleArgument,
   pub exports_argument: ExportsArgument,
   pub side_effect_free: Option<bool>,
+  /// Exports of a wasm module mapped to their signatures, which can't be called from JavaScript
+  pub js_incompatible_exports: Option<BTreeMap<String, String>>,
 }
 
 // webpack build info
@@ -185,6 +189,10 @@ impl From<ModuleIdentifier> for WrappedModuleIdentifier {
   }
 }
 
+/// Name of the layer of a module, modules of the same resource in different layers are
+/// different modules
+pub type ModuleLayer = String;
+
 #[async_trait]
 pub trait Module:
   Debug
@@ -381,6 +389,12 @@ pub trait Module:
     None
   }
 
+  /// Layer of the module, dependencies of the module are created in this layer unless a rule
+  /// assigns another one.
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    None
+  }
+
   fn chunk_condition(&self, _chunk_key: &ChunkUkey, _compilation: &Compilation) -> Option<bool> {
     None
   }
@@ -9,7 +9,9 @@ use rspack_identifier::{Identifiable, IdentifierMap};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use swc_core::ecma::atoms::Atom;
 
-use crate::{AsyncDependenciesBlock, AsyncDependenciesBlockId, ProvidedExports};
+use crate::{
+  AsyncDependenciesBlock, AsyncDependenciesBlockId, ProvidedExports, RuntimeSpec, UsedExports,
+};
 mod connection;
 pub use connection::*;
 mod vec_map;
@@ -1031,6 +1033,34 @@ impl ModuleGraph {
       .get_exports_info(self)
       .get_provided_exports(self)
   }
+
+  pub fn get_used_exports(
+    &self,
+    module_id: &ModuleIdentifier,
+    runtime: Option<&RuntimeSpec>,
+  ) -> UsedExports {
+    let mgm = self
+      .module_graph_module_by_identifier(module_id)
+      .expect("should have module graph module");
+    mgm
+      .exports
+      .get_exports_info(self)
+      .get_used_exports(runtime, self)
+  }
+
+  pub fn get_optimization_bailout(&self, module_id: &ModuleIdentifier) -> &[String] {
+    let mgm = self
+      .module_graph_module_by_identifier(module_id)
+      .expect("should have module graph module");
+    &mgm.optimization_bailout
+  }
+
+  pub fn get_optimization_bailout_mut(&mut self, module_id: &ModuleIdentifier) -> &mut Vec<String> {
+    let mgm = self
+      .module_graph_module_by_identifier_mut(module_id)
+      .expect("should have module graph module");
+    &mut mgm.optimization_bailout
+  }
 }
 
 fn get_connections_by_origin_module(
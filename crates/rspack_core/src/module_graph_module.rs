@@ -29,6 +29,8 @@ pub struct ModuleGraphModule {
   pub profile: Option<Box<ModuleProfile>>,
   pub is_async: bool,
   pub depth: Option<usize>,
+  /// Reasons why optimizations (e.g. module concatenation) were not applied to the module
+  pub optimization_bailout: Vec<String>,
 }
 
 impl ModuleGraphModule {
@@ -53,6 +55,7 @@ impl ModuleGraphModule {
       profile: None,
       is_async: false,
       depth: None,
+      optimization_bailout: vec![],
     }
   }
 
@@ -30,9 +30,9 @@ use crate::{
   impl_build_info_meta, AsyncDependenciesBlockId, BoxLoader, BoxModule, BuildContext, BuildInfo,
   BuildMeta, BuildResult, CodeGenerationResult, Compilation, ConcatenationScope, ConnectionState,
   Context, DependenciesBlock, DependencyId, DependencyTemplate, GenerateContext, GeneratorOptions,
-  LibIdentOptions, Module, ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleType,
-  ParseContext, ParseResult, ParserAndGenerator, ParserOptions, Resolve, RspackLoaderRunnerPlugin,
-  RuntimeSpec, SourceType,
+  LibIdentOptions, Module, ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleLayer,
+  ModuleType, ParseContext, ParseResult, ParserAndGenerator, ParserOptions, Resolve,
+  RspackLoaderRunnerPlugin, RuntimeSpec, SourceType,
 };
 
 bitflags! {
@@ -105,6 +105,8 @@ pub struct NormalModule {
   loaders: Vec<BoxLoader>,
   /// Whether loaders list contains inline loader
   contains_inline_loader: bool,
+  /// Layer assigned by [Rule.layer] or inherited from the issuer
+  layer: Option<ModuleLayer>,
 
   /// Original content of this module, will be available after module build
   original_source: Option<BoxSource>,
@@ -153,8 +155,10 @@ impl NormalModuleSource {
 pub static DEBUG_ID: AtomicUsize = AtomicUsize::new(1);
 
 impl NormalModule {
-  fn create_id(module_type: &ModuleType, request: &str) -> String {
-    if *module_type == ModuleType::Js {
+  fn create_id(module_type: &ModuleType, request: &str, layer: Option<&ModuleLayer>) -> String {
+    if let Some(layer) = layer {
+      format!("{module_type}|{request}|{layer}")
+    } else if *module_type == ModuleType::Js {
       request.to_string()
     } else {
       format!("{module_type}|{request}")
@@ -175,9 +179,10 @@ impl NormalModule {
     resolve_options: Option<Box<Resolve>>,
     loaders: Vec<BoxLoader>,
     contains_inline_loader: bool,
+    layer: Option<ModuleLayer>,
   ) -> Self {
     let module_type = module_type.into();
-    let id = Self::create_id(&module_type, &request);
+    let id = Self::create_id(&module_type, &request, layer.as_ref());
     Self {
       blocks: Vec::new(),
       dependencies: Vec::new(),
@@ -195,6 +200,7 @@ impl NormalModule {
       resolve_options,
       loaders,
       contains_inline_loader,
+      layer,
       original_source: None,
       source: NormalModuleSource::Unbuild,
       debug_id: DEBUG_ID.fetch_add(1, Ordering::Relaxed),
@@ -533,7 +539,11 @@ impl Module for NormalModule {
 
   fn lib_ident(&self, options: LibIdentOptions) -> Option<Cow<str>> {
     // Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/NormalModule.js#L362
-    Some(Cow::Owned(contextify(options.context, self.user_request())))
+    let mut ident = contextify(options.context, self.user_request());
+    if let Some(layer) = &self.layer {
+      ident = format!("({layer})/{ident}");
+    }
+    Some(Cow::Owned(ident))
   }
 
   fn get_resolve_options(&self) -> Option<Box<Resolve>> {
@@ -564,6 +574,10 @@ impl Module for NormalModule {
     Some(self.context.clone())
   }
 
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    self.layer.as_ref()
+  }
+
   // Port from https://github.com/webpack/webpack/blob/main/lib/NormalModule.js#L1120
   fn get_side_effects_connection_state(
     &self,
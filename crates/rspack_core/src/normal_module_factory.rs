@@ -14,7 +14,7 @@ use crate::{
   tree_shaking::visitor::{get_side_effects_from_package_json, SideEffects},
   BoxLoader, CompilerContext, CompilerOptions, DependencyCategory, FactorizeArgs, FactoryMeta,
   FuncUseCtx, GeneratorOptions, ModuleExt, ModuleFactory, ModuleFactoryCreateData,
-  ModuleFactoryResult, ModuleIdentifier, ModuleRule, ModuleRuleEnforce, ModuleRuleUse,
+  ModuleFactoryResult, ModuleIdentifier, ModuleLayer, ModuleRule, ModuleRuleEnforce, ModuleRuleUse,
   ModuleRuleUseLoader, ModuleType, NormalModule, NormalModuleAfterResolveArgs,
   NormalModuleBeforeResolveArgs, NormalModuleCreateData, ParserOptions, RawModule, Resolve,
   ResolveArgs, ResolveOptionsWithDependencyType, ResolveResult, Resolver, ResolverFactory,
@@ -35,12 +35,7 @@ impl ModuleFactory for NormalModuleFactory {
     if let Ok(Some(before_resolve_data)) = self.before_resolve(data).await {
       return Ok(before_resolve_data);
     }
-    let factory_result = self.factorize(data).await?;
-    if let Ok(Some(after_resolve_data)) = self.after_resolve(data, &factory_result).await {
-      return Ok(after_resolve_data);
-    }
-
-    Ok(factory_result)
+    self.factorize(data).await
   }
 }
 
@@ -97,35 +92,6 @@ impl NormalModuleFactory {
     Ok(None)
   }
 
-  async fn after_resolve(
-    &self,
-    data: &mut ModuleFactoryCreateData,
-    factory_result: &ModuleFactoryResult,
-  ) -> Result<Option<ModuleFactoryResult>> {
-    let dependency = data
-      .dependency
-      .as_module_dependency()
-      .expect("should be module dependency");
-    if let Ok(Some(false)) = self
-      .plugin_driver
-      .after_resolve(&mut NormalModuleAfterResolveArgs {
-        request: dependency.request(),
-        context: data.context.as_ref(),
-        file_dependencies: &data.file_dependencies,
-        context_dependencies: &data.context_dependencies,
-        missing_dependencies: &data.missing_dependencies,
-        factory_meta: &factory_result.factory_meta,
-        diagnostics: &mut data.diagnostics,
-      })
-      .await
-    {
-      // ignored
-      // See https://github.com/webpack/webpack/blob/6be4065ade1e252c1d8dcba4af0f43e32af1bdc1/lib/NormalModuleFactory.js#L301
-      return Ok(Some(ModuleFactoryResult::default()));
-    }
-    Ok(None)
-  }
-
   fn get_loader_resolver(&self) -> Arc<Resolver> {
     self
       .loader_resolver_factory
@@ -163,8 +129,22 @@ impl NormalModuleFactory {
     let mut no_auto_loaders = false;
     let mut no_pre_post_auto_loaders = false;
 
+    // resource within scheme, e.g. a relative request of a module fetched over http
+    let resource_in_scheme = if scheme == Scheme::None && context_scheme.is_http() {
+      resolve_in_http_scheme(data.context.as_ref(), request_without_match_resource)
+    } else {
+      None
+    };
+
     // with scheme, windows absolute path is considered scheme by `url`
-    let (resource_data, from_cache) = if scheme != Scheme::None
+    let (mut resource_data, from_cache) = if let Some(resource) = resource_in_scheme {
+      (
+        plugin_driver
+          .normal_module_factory_resolve_for_scheme(ResourceData::new(resource, "".into()))
+          .await?,
+        false,
+      )
+    } else if scheme != Scheme::None
       && !Path::is_absolute(Path::new(request_without_match_resource))
     {
       // resource with scheme
@@ -177,9 +157,7 @@ impl NormalModuleFactory {
           .await?,
         false,
       )
-    }
-    // TODO: resource within scheme, call resolveInScheme hook
-    else {
+    } else {
       {
         request_without_match_resource = {
           let match_resource_match = MATCH_RESOURCE_REGEX.captures(request_without_match_resource);
@@ -375,10 +353,20 @@ impl NormalModuleFactory {
           },
           data.dependency.category(),
           data.issuer.as_deref(),
+          data.issuer_layer.as_deref(),
         )
         .await?
     };
 
+    let layer = self
+      .calculate_layer(&resolved_module_rules)
+      .or_else(|| data.issuer_layer.clone());
+    if layer.is_some() && !self.options.experiments.layers {
+      return Err(error!(
+        "'Rule.layer' is only allowed when 'experiments.layers' is enabled"
+      ));
+    }
+
     let user_request = {
       let suffix = stringify_loaders_and_resource(&inline_loaders, &resource_data.resource);
       if let Some(ResourceData { resource, .. }) = match_resource_data.as_ref() {
@@ -524,6 +512,40 @@ impl NormalModuleFactory {
       all_loaders
     };
 
+    let resolved_module_type =
+      self.calculate_module_type(match_module_type, &resolved_module_rules);
+    let resolved_resolve_options = self.calculate_resolve_options(&resolved_module_rules);
+    let (resolved_parser_options, resolved_generator_options) =
+      self.calculate_parser_and_generator_options(&resolved_module_rules);
+    let factory_meta = FactoryMeta {
+      side_effect_free: self
+        .calculate_side_effects(&resolved_module_rules, &resource_data)
+        .map(|side_effects| !side_effects),
+    };
+
+    data.file_dependencies.extend(file_dependencies);
+    data.missing_dependencies.extend(missing_dependencies);
+    if let Some(false) = self
+      .plugin_driver
+      .after_resolve(&mut NormalModuleAfterResolveArgs {
+        request: dependency.request(),
+        context: data.context.as_ref(),
+        file_dependencies: &data.file_dependencies,
+        context_dependencies: &data.context_dependencies,
+        missing_dependencies: &data.missing_dependencies,
+        factory_meta: &factory_meta,
+        diagnostics: &mut data.diagnostics,
+        resource_data: &mut resource_data,
+      })
+      .await?
+    {
+      // ignored
+      // See https://github.com/webpack/webpack/blob/6be4065ade1e252c1d8dcba4af0f43e32af1bdc1/lib/NormalModuleFactory.js#L301
+      return Ok(Some(ModuleFactoryResult::default()));
+    }
+    let file_dependency = resource_data.resource_path.clone();
+
+    // The resource may be replaced by the plugins after resolve
     let request = if !loaders.is_empty() {
       let s = loaders
         .iter()
@@ -536,19 +558,6 @@ impl NormalModuleFactory {
     };
     tracing::trace!("resolved uri {:?}", request);
 
-    let file_dependency = resource_data.resource_path.clone();
-
-    let resolved_module_type =
-      self.calculate_module_type(match_module_type, &resolved_module_rules);
-    let resolved_resolve_options = self.calculate_resolve_options(&resolved_module_rules);
-    let (resolved_parser_options, resolved_generator_options) =
-      self.calculate_parser_and_generator_options(&resolved_module_rules);
-    let factory_meta = FactoryMeta {
-      side_effect_free: self
-        .calculate_side_effects(&resolved_module_rules, &resource_data)
-        .map(|side_effects| !side_effects),
-    };
-
     let resolved_parser_and_generator = self
       .plugin_driver
       .registered_parser_and_generator_builder
@@ -587,6 +596,7 @@ impl NormalModuleFactory {
         resolved_resolve_options,
         loaders,
         contains_inline,
+        layer,
       );
       Box::new(normal_module)
     };
@@ -596,9 +606,7 @@ impl NormalModuleFactory {
       .normal_module_factory_module(module, &mut create_data)
       .await?;
 
-    data.add_file_dependencies(file_dependencies);
     data.add_file_dependency(file_dependency);
-    data.add_missing_dependencies(missing_dependencies);
 
     Ok(Some(
       ModuleFactoryResult::new_with_module(module)
@@ -612,12 +620,14 @@ impl NormalModuleFactory {
     resource_data: &ResourceData,
     dependency: &DependencyCategory,
     issuer: Option<&'a str>,
+    issuer_layer: Option<&'a str>,
   ) -> Result<Vec<&'a ModuleRule>> {
     let mut rules = Vec::new();
     module_rules_matcher(
       &self.options.module.rules,
       resource_data,
       issuer,
+      issuer_layer,
       dependency,
       &mut rules,
     )
@@ -635,6 +645,13 @@ impl NormalModuleFactory {
     resolved
   }
 
+  fn calculate_layer(&self, module_rules: &[&ModuleRule]) -> Option<ModuleLayer> {
+    module_rules
+      .iter()
+      .rev()
+      .find_map(|rule| rule.layer.clone())
+  }
+
   fn calculate_side_effects(
     &self,
     module_rules: &[&ModuleRule],
@@ -729,12 +746,33 @@ impl NormalModuleFactory {
   }
 }
 
+/// Join a relative or root relative request with the url of the issuer's directory.
+fn resolve_in_http_scheme(context: &str, request: &str) -> Option<String> {
+  if !(request.starts_with("./") || request.starts_with("../") || request.starts_with('/')) {
+    return None;
+  }
+  let base = url::Url::parse(&format!("{}/", context.trim_end_matches('/'))).ok()?;
+  base.join(request).ok().map(|url| url.to_string())
+}
+
 /// Using `u32` instead of `usize` to reduce memory usage,
 /// `u32` is 4 bytes on 64bit machine, comparing to `usize` which is 8 bytes.
 /// Rspan aka `Rspack span`, just avoiding conflict with span in other crate
 /// ## Warning
 /// RSpan is zero based, `Span` of `swc` is 1 based. see https://swc-css.netlify.app/?code=eJzLzC3ILypRSFRIK8rPVVAvSS0u0csqVgcAZaoIKg
-#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord)]
+#[derive(
+  Debug,
+  Hash,
+  PartialEq,
+  Eq,
+  Clone,
+  Copy,
+  Default,
+  PartialOrd,
+  Ord,
+  serde::Serialize,
+  serde::Deserialize,
+)]
 pub struct ErrorSpan {
   pub start: u32,
   pub end: u32,
@@ -755,6 +793,22 @@ impl From<Span> for ErrorSpan {
   }
 }
 
+#[test]
+fn resolve_relative_request_in_http_scheme() {
+  assert_eq!(
+    resolve_in_http_scheme("https://example.com/lib", "../a.js").as_deref(),
+    Some("https://example.com/a.js")
+  );
+  assert_eq!(
+    resolve_in_http_scheme("https://example.com/lib", "/b.js").as_deref(),
+    Some("https://example.com/b.js")
+  );
+  assert_eq!(
+    resolve_in_http_scheme("https://example.com/lib", "react"),
+    None
+  );
+}
+
 #[test]
 fn match_webpack_ext() {
   assert!(MATCH_WEBPACK_EXT_REGEX.is_match("foo.webpack[type/javascript]"));
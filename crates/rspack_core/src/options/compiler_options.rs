@@ -1,3 +1,8 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use rspack_fs::{InputFileSystem, IntermediateFileSystem};
+
 use crate::{
   Builtins, CacheOptions, Context, DevServerOptions, Experiments, IncrementalRebuildMakeState,
   Mode, ModuleOptions, NodeOption, Optimization, OutputOptions, Resolve, SnapshotOptions,
@@ -23,7 +28,15 @@ pub struct CompilerOptions {
   pub optimization: Optimization,
   pub profile: bool,
   pub bail: bool,
+  /// Read module and chunk ids of previous builds from this file
+  pub records_input_path: Option<PathBuf>,
+  /// Write module and chunk ids to this file after emitting assets
+  pub records_output_path: Option<PathBuf>,
   pub builtins: Builtins,
+  /// The file system modules, contexts and snapshots are read from
+  pub input_filesystem: Arc<dyn InputFileSystem>,
+  /// The file system caches and lockfiles of plugins are written to
+  pub intermediate_filesystem: Arc<dyn IntermediateFileSystem>,
 }
 
 impl CompilerOptions {
@@ -39,6 +52,10 @@ impl CompilerOptions {
     self.experiments.incremental_rebuild.emit_asset
   }
 
+  pub fn is_records_enabled(&self) -> bool {
+    self.records_input_path.is_some() || self.records_output_path.is_some()
+  }
+
   pub fn is_new_tree_shaking(&self) -> bool {
     self.experiments.rspack_future.new_treeshaking
   }
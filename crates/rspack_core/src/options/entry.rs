@@ -10,6 +10,7 @@ pub type EntryItem = Vec<String>;
 pub struct EntryDescription {
   pub import: EntryItem,
   pub runtime: Option<String>,
+  pub depend_on: Option<Vec<String>>,
   pub chunk_loading: Option<ChunkLoading>,
   pub async_chunks: Option<bool>,
   pub public_path: Option<PublicPath>,
@@ -31,5 +31,7 @@ pub struct Experiments {
   pub incremental_rebuild: IncrementalRebuild,
   pub new_split_chunks: bool,
   pub top_level_await: bool,
+  /// Allow `Rule.layer` and `entry.layer` to build modules in different layers
+  pub layers: bool,
   pub rspack_future: RspackFuture,
 }
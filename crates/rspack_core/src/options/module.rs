@@ -128,6 +128,8 @@ pub struct JavascriptParserOptions {
   pub dynamic_import_preload: JavascriptParserOrder,
   pub dynamic_import_prefetch: JavascriptParserOrder,
   pub url: JavascriptParserUrl,
+  /// Syntaxes creating workers, see `needs_refactor::WorkerSyntaxScanner`, empty to disable
+  pub worker: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -387,6 +389,9 @@ pub struct ModuleRule {
   pub resource_fragment: Option<RuleSetCondition>,
   pub dependency: Option<RuleSetCondition>,
   pub issuer: Option<RuleSetCondition>,
+  /// A condition matcher against the layer of the issuer, modules without a layer are matched
+  /// as an empty string.
+  pub issuer_layer: Option<RuleSetCondition>,
   pub scheme: Option<RuleSetCondition>,
   pub mimetype: Option<RuleSetCondition>,
   pub description_data: Option<DescriptionData>,
@@ -398,6 +403,8 @@ pub struct ModuleRule {
   pub parser: Option<ParserOptions>,
   pub generator: Option<GeneratorOptions>,
   pub resolve: Option<Resolve>,
+  /// The layer of the matched module, requires `experiments.layers`.
+  pub layer: Option<String>,
   pub one_of: Option<Vec<ModuleRule>>,
   pub rules: Option<Vec<ModuleRule>>,
   pub enforce: ModuleRuleEnforce,
@@ -1,22 +1,27 @@
-use std::{fmt::Debug, path::Path};
+use std::{
+  any::{Any, TypeId},
+  fmt::Debug,
+  path::Path,
+};
 
 use dashmap::DashMap;
 use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_hash::RspackHashDigest;
 use rspack_loader_runner::{Content, LoaderContext, ResourceData};
 use rspack_sources::BoxSource;
+use rustc_hash::FxHashMap as HashMap;
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::{
   AdditionalChunkRuntimeRequirementsArgs, AdditionalModuleRequirementsArgs, AssetEmittedArgs,
   AssetInfo, BoxLoader, BoxModule, BuildTimeExecutionOption, Chunk, ChunkAssetArgs, ChunkHashArgs,
   CodeGenerationResults, Compilation, CompilationArgs, CompilationParams, CompilerOptions,
-  ContentHashArgs, DependencyId, DoneArgs, FactorizeArgs, JsChunkHashArgs, LoaderRunnerContext,
-  MakeParam, Module, ModuleFactoryResult, ModuleIdentifier, ModuleType, NormalModule,
-  NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs, NormalModuleCreateData,
-  OptimizeChunksArgs, ParserAndGenerator, PluginContext, ProcessAssetsArgs, RenderArgs,
-  RenderChunkArgs, RenderManifestArgs, RenderModuleContentArgs, RenderStartupArgs, Resolver,
-  RuntimeModule, RuntimeRequirementsInTreeArgs, SourceType, ThisCompilationArgs,
+  ContentHashArgs, ContextModuleAfterResolveArgs, DependencyId, DoneArgs, FactorizeArgs,
+  JsChunkHashArgs, LoaderRunnerContext, MakeParam, Module, ModuleFactoryResult, ModuleIdentifier,
+  ModuleType, NormalModule, NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs,
+  NormalModuleCreateData, OptimizeChunksArgs, ParserAndGenerator, PluginContext, ProcessAssetsArgs,
+  RenderArgs, RenderChunkArgs, RenderManifestArgs, RenderModuleContentArgs, RenderStartupArgs,
+  Resolver, RuntimeModule, RuntimeRequirementsInTreeArgs, SourceType, ThisCompilationArgs,
 };
 
 // use anyhow::{Context, Result};
@@ -32,6 +37,7 @@ pub type PluginNormalModuleFactoryModuleHookOutput = Result<BoxModule>;
 pub type PluginNormalModuleFactoryResolveForSchemeOutput = Result<(ResourceData, bool)>;
 pub type PluginNormalModuleFactoryBeforeResolveOutput = Result<Option<bool>>;
 pub type PluginNormalModuleFactoryAfterResolveOutput = Result<Option<bool>>;
+pub type PluginContextModuleFactoryAfterResolveOutput = Result<Option<bool>>;
 pub type PluginContentHashHookOutput = Result<Option<(SourceType, RspackHashDigest)>>;
 pub type PluginChunkHashHookOutput = Result<()>;
 pub type PluginRenderManifestHookOutput = Result<TWithDiagnosticArray<Vec<RenderManifestEntry>>>;
@@ -135,6 +141,14 @@ pub trait Plugin: Debug + Send + Sync {
     Ok(None)
   }
 
+  async fn context_module_after_resolve(
+    &self,
+    _ctx: PluginContext,
+    _args: &mut ContextModuleAfterResolveArgs,
+  ) -> PluginContextModuleFactoryAfterResolveOutput {
+    Ok(None)
+  }
+
   async fn normal_module_factory_create_module(
     &self,
     _ctx: PluginContext,
@@ -621,10 +635,12 @@ pub type BoxedParserAndGenerator = Box<dyn ParserAndGenerator>;
 pub type BoxedParserAndGeneratorBuilder =
   Box<dyn 'static + Send + Sync + Fn() -> BoxedParserAndGenerator>;
 
+/// Shared by every plugin of a compiler while they are applied.
 #[derive(Default)]
 pub struct ApplyContext {
   pub(crate) registered_parser_and_generator_builder:
     DashMap<ModuleType, BoxedParserAndGeneratorBuilder>,
+  hooks: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
 }
 
 impl ApplyContext {
@@ -637,4 +653,17 @@ impl ApplyContext {
       .registered_parser_and_generator_builder
       .insert(module_type, parser_and_generator_builder);
   }
+
+  /// Hooks of type `T`, created on the first access and shared by all plugins.
+  ///
+  /// This is how plugins outside of `rspack_core` expose extension points to other plugins,
+  /// e.g. the javascript parser plugins of `rspack_plugin_javascript`.
+  pub fn hooks<T: Default + Send + Sync + 'static>(&mut self) -> &mut T {
+    self
+      .hooks
+      .entry(TypeId::of::<T>())
+      .or_insert_with(|| Box::<T>::default())
+      .downcast_mut::<T>()
+      .expect("hooks should be keyed by their type id")
+  }
 }
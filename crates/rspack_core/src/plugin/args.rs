@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -10,8 +11,8 @@ use rustc_hash::FxHashSet as HashSet;
 
 use crate::{
   BoxModule, Chunk, ChunkInitFragments, ChunkUkey, Compilation, Context, ContextModuleFactory,
-  DependencyCategory, DependencyType, ErrorSpan, FactoryMeta, ModuleDependency, ModuleIdentifier,
-  NormalModuleFactory, Resolve, RuntimeGlobals, SharedPluginDriver, Stats,
+  ContextOptions, DependencyCategory, DependencyType, ErrorSpan, FactoryMeta, ModuleDependency,
+  ModuleIdentifier, NormalModuleFactory, Resolve, RuntimeGlobals, SharedPluginDriver, Stats,
 };
 
 #[derive(Debug)]
@@ -87,6 +88,16 @@ pub struct NormalModuleBeforeResolveArgs {
   pub request: String,
   pub context: String,
 }
+#[derive(Debug)]
+pub struct ContextModuleAfterResolveArgs {
+  /// Directory of the context
+  pub resource: String,
+  pub context_options: ContextOptions,
+  /// Requests of the context mapped to the requests of their modules, relative to `resource`.
+  /// The directory is not read when it's set.
+  pub context_map: Option<BTreeMap<String, String>>,
+}
+
 #[derive(Debug)]
 pub struct NormalModuleAfterResolveArgs<'a> {
   pub request: &'a str,
@@ -96,6 +107,9 @@ pub struct NormalModuleAfterResolveArgs<'a> {
   pub missing_dependencies: &'a HashSet<PathBuf>,
   pub factory_meta: &'a FactoryMeta,
   pub diagnostics: &'a mut Vec<Diagnostic>,
+  /// Resolved resource of the module to be created, i.e. `createData.resource` of webpack.
+  /// Plugins may replace it, the request and the loaders of the module are kept.
+  pub resource_data: &'a mut ResourceData,
 }
 
 #[derive(Debug)]
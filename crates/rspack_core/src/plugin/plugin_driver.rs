@@ -13,12 +13,13 @@ use crate::{
   AdditionalChunkRuntimeRequirementsArgs, AdditionalModuleRequirementsArgs, ApplyContext,
   AssetEmittedArgs, BoxLoader, BoxModule, BoxedParserAndGeneratorBuilder, BuildTimeExecutionOption,
   Chunk, ChunkAssetArgs, ChunkContentHash, ChunkHashArgs, CodeGenerationResults, Compilation,
-  CompilationArgs, CompilationParams, CompilerOptions, Content, ContentHashArgs, DependencyId,
-  DoneArgs, FactorizeArgs, JsChunkHashArgs, LoaderRunnerContext, MakeParam, Module,
-  ModuleIdentifier, ModuleType, NormalModule, NormalModuleAfterResolveArgs,
-  NormalModuleBeforeResolveArgs, NormalModuleCreateData, OptimizeChunksArgs, Plugin,
-  PluginAdditionalChunkRuntimeRequirementsOutput, PluginAdditionalModuleRequirementsOutput,
-  PluginBuildEndHookOutput, PluginChunkHashHookOutput, PluginCompilationHookOutput, PluginContext,
+  CompilationArgs, CompilationParams, CompilerOptions, Content, ContentHashArgs,
+  ContextModuleAfterResolveArgs, DependencyId, DoneArgs, FactorizeArgs, JsChunkHashArgs,
+  LoaderRunnerContext, MakeParam, Module, ModuleIdentifier, ModuleType, NormalModule,
+  NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs, NormalModuleCreateData,
+  OptimizeChunksArgs, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput,
+  PluginAdditionalModuleRequirementsOutput, PluginBuildEndHookOutput, PluginChunkHashHookOutput,
+  PluginCompilationHookOutput, PluginContext, PluginContextModuleFactoryAfterResolveOutput,
   PluginFactorizeHookOutput, PluginJsChunkHashHookOutput, PluginMakeHookOutput,
   PluginNormalModuleFactoryAfterResolveOutput, PluginNormalModuleFactoryBeforeResolveOutput,
   PluginNormalModuleFactoryCreateModuleHookOutput, PluginNormalModuleFactoryModuleHookOutput,
@@ -58,24 +59,18 @@ impl PluginDriver {
     plugins: Vec<Box<dyn Plugin>>,
     resolver_factory: Arc<ResolverFactory>,
   ) -> (Arc<Self>, Arc<CompilerOptions>) {
-    let registered_parser_and_generator_builder = plugins
-      .iter()
-      .map(|plugin| {
-        let mut apply_context = ApplyContext::default();
-        plugin
-          .apply(
-            PluginContext::with_context(&mut apply_context),
-            &mut options,
-          )
-          .expect("TODO:");
-        apply_context
-      })
-      .flat_map(|apply_context| {
-        apply_context
-          .registered_parser_and_generator_builder
-          .into_iter()
-          .collect::<Vec<_>>()
-      })
+    let mut apply_context = ApplyContext::default();
+    for plugin in &plugins {
+      plugin
+        .apply(
+          PluginContext::with_context(&mut apply_context),
+          &mut options,
+        )
+        .expect("TODO:");
+    }
+    let registered_parser_and_generator_builder = apply_context
+      .registered_parser_and_generator_builder
+      .into_iter()
       .collect::<HashMap<ModuleType, BoxedParserAndGeneratorBuilder>>();
 
     let options = Arc::new(options);
@@ -427,6 +422,22 @@ impl PluginDriver {
     Ok(None)
   }
 
+  pub async fn context_module_after_resolve(
+    &self,
+    args: &mut ContextModuleAfterResolveArgs,
+  ) -> PluginContextModuleFactoryAfterResolveOutput {
+    for plugin in &self.plugins {
+      tracing::trace!("running context module after resolve:{}", plugin.name());
+      if let Some(data) = plugin
+        .context_module_after_resolve(PluginContext::new(), args)
+        .await?
+      {
+        return Ok(Some(data));
+      }
+    }
+    Ok(None)
+  }
+
   pub async fn normal_module_factory_resolve_for_scheme(
     &self,
     args: ResourceData,
@@ -1,6 +1,7 @@
 use std::{hash::BuildHasherDefault, sync::Arc};
 
 use dashmap::DashMap;
+use rspack_fs::{InputFileSystem, NativeFileSystem};
 use rustc_hash::FxHasher;
 
 use super::resolver_impl::Resolver;
@@ -25,7 +26,7 @@ pub struct ResolverFactory {
 
 impl Default for ResolverFactory {
   fn default() -> Self {
-    Self::new(Resolve::default())
+    Self::new(Resolve::default(), Arc::new(NativeFileSystem))
   }
 }
 
@@ -34,10 +35,10 @@ impl ResolverFactory {
     self.resolver.clear_cache();
   }
 
-  pub fn new(options: Resolve) -> Self {
+  pub fn new(options: Resolve, fs: Arc<dyn InputFileSystem>) -> Self {
     Self {
       base_options: options.clone(),
-      resolver: Resolver::new(options),
+      resolver: Resolver::new(options, fs),
       resolvers: Default::default(),
     }
   }
@@ -1,5 +1,5 @@
 use std::{
-  fmt,
+  fmt, io,
   path::{Path, PathBuf},
   sync::Arc,
 };
@@ -8,6 +8,7 @@ use rspack_error::{
   miette::{diagnostic, Diagnostic},
   DiagnosticExt, Severity, TraceableError,
 };
+use rspack_fs::InputFileSystem;
 use rspack_loader_runner::DescriptionData;
 use rustc_hash::FxHashSet as HashSet;
 
@@ -72,22 +73,59 @@ impl<'a> ResolveInnerOptions<'a> {
   }
 }
 
+/// Routes the file system accesses of the resolver through the [InputFileSystem] of the compiler.
+#[derive(Debug, Clone)]
+pub struct ResolverFileSystem(Arc<dyn InputFileSystem>);
+
+impl oxc_resolver::FileSystem for ResolverFileSystem {
+  fn read_to_string(&self, path: &Path) -> io::Result<String> {
+    let content = self.0.read(path).map_err(into_io_error)?;
+    String::from_utf8(content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+  }
+
+  fn metadata(&self, path: &Path) -> io::Result<oxc_resolver::FileMetadata> {
+    let metadata = self.0.metadata(path).map_err(into_io_error)?;
+    Ok(oxc_resolver::FileMetadata::new(
+      metadata.is_file,
+      metadata.is_directory,
+      metadata.is_symlink,
+    ))
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> io::Result<oxc_resolver::FileMetadata> {
+    // `is_symlink` describes the path itself, which is all the resolver needs before calling
+    // `canonicalize`
+    self.metadata(path)
+  }
+
+  fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+    self.0.realpath(path).map_err(into_io_error)
+  }
+}
+
+fn into_io_error(error: rspack_fs::Error) -> io::Error {
+  match error {
+    rspack_fs::Error::Io(error) => error,
+  }
+}
+
 /// Proxy to [oxc_resolver::Resolver]
 ///
 /// Internal caches are shared.
 #[derive(Debug)]
 pub enum Resolver {
-  OxcResolver(oxc_resolver::Resolver),
+  OxcResolver(oxc_resolver::ResolverGeneric<ResolverFileSystem>),
 }
 
 impl Resolver {
-  pub fn new(options: Resolve) -> Self {
-    Self::new_oxc_resolver(options)
+  pub fn new(options: Resolve, fs: Arc<dyn InputFileSystem>) -> Self {
+    Self::new_oxc_resolver(options, fs)
   }
 
-  fn new_oxc_resolver(options: Resolve) -> Self {
+  fn new_oxc_resolver(options: Resolve, fs: Arc<dyn InputFileSystem>) -> Self {
     let options = to_oxc_resolver_options(options, false, DependencyCategory::Unknown);
-    let resolver = oxc_resolver::Resolver::new(options);
+    let resolver =
+      oxc_resolver::ResolverGeneric::new_with_file_system(ResolverFileSystem(fs), options);
     Self::OxcResolver(resolver)
   }
 
@@ -243,6 +243,11 @@ bitflags! {
 
     // rspack only
     const RSPACK_VERSION = 1 << 62;
+
+    /**
+     * object with all WebAssembly.instance exports
+     */
+    const WASM_INSTANCES = 1 << 63;
   }
 }
 
@@ -253,6 +258,20 @@ impl fmt::Display for RuntimeGlobals {
   }
 }
 
+impl serde::Serialize for RuntimeGlobals {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(self.bits())
+  }
+}
+
+impl<'de> serde::Deserialize<'de> for RuntimeGlobals {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let bits = u64::deserialize(deserializer)?;
+    Self::from_bits(bits)
+      .ok_or_else(|| serde::de::Error::custom(format!("invalid runtime globals {bits:#x}")))
+  }
+}
+
 impl Default for RuntimeGlobals {
   fn default() -> Self {
     Self::empty()
@@ -294,6 +313,7 @@ impl RuntimeGlobals {
       R::GLOBAL => "__webpack_require__.g",
       R::RETURN_EXPORTS_FROM_RUNTIME => "return-exports-from-runtime",
       R::INSTANTIATE_WASM => "__webpack_require__.v",
+      R::WASM_INSTANCES => "__webpack_require__.w",
       R::ASYNC_MODULE => "__webpack_require__.a",
       R::BASE_URI => "__webpack_require__.b",
       R::STARTUP_ENTRYPOINT => "__webpack_require__.X",
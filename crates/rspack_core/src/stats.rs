@@ -8,7 +8,9 @@ use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use crate::{get_chunk_from_ukey, get_chunk_group_from_ukey};
 use crate::{BoxModule, BoxRuntimeModule, Chunk};
-use crate::{ChunkGroupUkey, Compilation, LogType, ModuleIdentifier, ModuleType, SourceType};
+use crate::{ChunkGroupUkey, Compilation, DependencyLocation, LogType, ModuleIdentifier};
+use crate::{ModuleType, SourceType};
+use crate::{ProvidedExports, UsedExports};
 
 #[derive(Debug, Clone)]
 pub struct Stats<'compilation> {
@@ -193,6 +195,7 @@ impl Stats<'_> {
         } else {
           (None, None, None)
         };
+        let origins = self.get_chunk_origins(c);
         Ok(StatsChunk {
           r#type: "chunk",
           files,
@@ -209,6 +212,7 @@ impl Stats<'_> {
           parents,
           children,
           siblings,
+          origins,
         })
       })
       .collect::<Result<_>>()?;
@@ -481,11 +485,37 @@ impl Stats<'_> {
       None
     };
 
+    let provided_exports = match self
+      .compilation
+      .module_graph
+      .get_provided_exports(identifier)
+    {
+      ProvidedExports::Vec(v) => Some(v.iter().map(|i| i.to_string()).collect()),
+      _ => None,
+    };
+    let used_exports = match self
+      .compilation
+      .module_graph
+      .get_used_exports(&identifier, None)
+    {
+      UsedExports::Null => None,
+      UsedExports::Bool(b) => Some(StatsUsedExports::Bool(b)),
+      UsedExports::Vec(v) => Some(StatsUsedExports::Vec(
+        v.iter().map(|i| i.to_string()).collect(),
+      )),
+    };
+    let built = self.compilation.built_modules.contains(&identifier);
+    let code_generated = self
+      .compilation
+      .code_generated_modules
+      .contains(&identifier);
+
     Ok(StatsModule {
       r#type: "module",
       module_type: *module.module_type(),
       identifier,
       name_for_condition: module.name_for_condition().map(|n| n.to_string()),
+      layer: module.get_layer().cloned(),
       name: module
         .readable_identifier(&self.compilation.options.context)
         .into(),
@@ -510,6 +540,16 @@ impl Stats<'_> {
         .chunk_graph
         .get_number_of_module_chunks(identifier)
         == 0,
+      depth: mgm.depth,
+      provided_exports,
+      used_exports,
+      optimization_bailout: mgm.optimization_bailout.clone(),
+      cacheable: module
+        .build_info()
+        .map(|info| info.cacheable)
+        .unwrap_or_default(),
+      built,
+      cached: !built && !code_generated,
     })
   }
 
@@ -535,6 +575,7 @@ impl Stats<'_> {
       module_type: *module.module_type(),
       identifier: module.identifier(),
       name_for_condition: module.name_for_condition().map(|n| n.to_string()),
+      layer: None,
       name: module.name().to_string(),
       id: Some(String::new()),
       chunks,
@@ -553,8 +594,53 @@ impl Stats<'_> {
         .chunk_graph
         .get_number_of_module_chunks(*identifier)
         == 0,
+      depth: None,
+      provided_exports: None,
+      used_exports: None,
+      optimization_bailout: Vec::new(),
+      cacheable: false,
+      built: false,
+      cached: false,
     })
   }
+
+  fn get_chunk_origins(&self, chunk: &Chunk) -> Vec<StatsOriginRecord> {
+    let mut origins = chunk
+      .groups
+      .iter()
+      .filter_map(|ukey| get_chunk_group_from_ukey(ukey, &self.compilation.chunk_group_by_ukey))
+      .flat_map(|cg| cg.origins.iter())
+      .map(|origin| {
+        let module = origin
+          .module_id
+          .and_then(|id| self.compilation.module_graph.module_by_identifier(&id));
+        let (module_name, module_id) = module
+          .map(|m| get_stats_module_name_and_id(m, self.compilation))
+          .unzip();
+        StatsOriginRecord {
+          module: origin
+            .module_id
+            .map(|id| id.to_string())
+            .unwrap_or_default(),
+          module_identifier: origin
+            .module_id
+            .map(|id| id.to_string())
+            .unwrap_or_default(),
+          module_name: module_name.unwrap_or_default(),
+          module_id: module_id.flatten().unwrap_or_default(),
+          loc: origin
+            .loc
+            .zip(module.and_then(|m| m.original_source()))
+            .map(|(loc, source)| format_location(&source.source(), loc))
+            .unwrap_or_default(),
+          request: origin.request.clone().unwrap_or_default(),
+        }
+      })
+      .collect::<Vec<_>>();
+    origins.sort_unstable();
+    origins.dedup();
+    origins
+  }
   fn get_chunk_relations(&self, chunk: &Chunk) -> (Vec<String>, Vec<String>, Vec<String>) {
     let mut parents = HashSet::default();
     let mut children = HashSet::default();
@@ -617,6 +703,31 @@ fn get_stats_module_name_and_id(
   (name.to_string(), id)
 }
 
+/// Format the location as `line:column-line:column` like webpack, lines are 1-based and
+/// columns are 0-based.
+fn format_location(source: &str, loc: DependencyLocation) -> String {
+  let (start_line, start_column) = get_line_and_column(source, loc.start() as usize);
+  let (end_line, end_column) = get_line_and_column(source, loc.end() as usize);
+  format!("{start_line}:{start_column}-{end_line}:{end_column}")
+}
+
+fn get_line_and_column(source: &str, offset: usize) -> (usize, usize) {
+  let mut line = 1;
+  let mut column = 0;
+  for (index, char) in source.char_indices() {
+    if index >= offset {
+      break;
+    }
+    if char == '\n' {
+      line += 1;
+      column = 0;
+    } else {
+      column += 1;
+    }
+  }
+  (line, column)
+}
+
 #[derive(Debug)]
 pub struct StatsError {
   pub message: String,
@@ -666,6 +777,7 @@ pub struct StatsModule<'a> {
   pub identifier: ModuleIdentifier,
   pub name: String,
   pub name_for_condition: Option<String>,
+  pub layer: Option<String>,
   pub id: Option<String>,
   pub chunks: Vec<Option<String>>, // has id after the call of chunkIds hook
   pub size: f64,
@@ -679,6 +791,21 @@ pub struct StatsModule<'a> {
   pub source: Option<&'a dyn Source>,
   pub profile: Option<StatsModuleProfile>,
   pub orphan: bool,
+  pub depth: Option<usize>,
+  /// `None` when the exports of the module are unknown
+  pub provided_exports: Option<Vec<String>>,
+  /// `None` when there is no usage info
+  pub used_exports: Option<StatsUsedExports>,
+  pub optimization_bailout: Vec<String>,
+  pub cacheable: bool,
+  pub built: bool,
+  pub cached: bool,
+}
+
+#[derive(Debug)]
+pub enum StatsUsedExports {
+  Bool(bool),
+  Vec(Vec<String>),
 }
 
 #[derive(Debug)]
@@ -702,6 +829,18 @@ pub struct StatsChunk<'a> {
   pub parents: Option<Vec<String>>,
   pub children: Option<Vec<String>>,
   pub siblings: Option<Vec<String>>,
+  pub origins: Vec<StatsOriginRecord>,
+}
+
+/// Fields are declared in the order origins are sorted by: module id, location and request
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct StatsOriginRecord {
+  pub module_id: String,
+  pub loc: String,
+  pub request: String,
+  pub module: String,
+  pub module_identifier: String,
+  pub module_name: String,
 }
 
 #[derive(Debug)]
@@ -5,12 +5,13 @@ use super::{
   analyzer::OptimizeAnalyzer,
   visitor::{ModuleRefAnalyze, OptimizeAnalyzeResult, SyntaxContextInfo},
 };
-use crate::{BoxDependency, CompilerOptions, ModuleIdentifier};
+use crate::{BoxDependency, CompilerOptions, ModuleIdentifier, ModuleType};
 
 pub struct JsModule<'b, 'a: 'b> {
   ast: &'a Ast,
   dependencies: &'b Vec<BoxDependency>,
   module_identifier: ModuleIdentifier,
+  module_type: &'a ModuleType,
   compiler_options: &'a CompilerOptions,
 }
 
@@ -19,12 +20,14 @@ impl<'a, 'b> JsModule<'b, 'a> {
     ast: &'a Ast,
     dependencies: &'b Vec<BoxDependency>,
     module_identifier: ModuleIdentifier,
+    module_type: &'a ModuleType,
     compiler_options: &'a CompilerOptions,
   ) -> Self {
     Self {
       ast,
       dependencies,
       module_identifier,
+      module_type,
       compiler_options,
     }
   }
@@ -38,9 +41,10 @@ impl<'a, 'b> OptimizeAnalyzer for JsModule<'a, 'b> {
 
       let unresolved_ctxt = SyntaxContext::empty().apply_mark(unresolved_mark);
       let top_level_ctxt = SyntaxContext::empty().apply_mark(top_level_mark);
-      let mut worker_syntax_scanner = crate::needs_refactor::WorkerSyntaxScanner::new(
-        crate::needs_refactor::DEFAULT_WORKER_SYNTAX,
-      );
+      let worker_syntax =
+        crate::needs_refactor::get_worker_syntax(self.compiler_options, self.module_type);
+      let mut worker_syntax_scanner =
+        crate::needs_refactor::WorkerSyntaxScanner::new(&worker_syntax);
       program.visit_with(&mut worker_syntax_scanner);
       let mut analyzer = ModuleRefAnalyze::new(
         SyntaxContextInfo::new(top_level_ctxt, unresolved_ctxt),
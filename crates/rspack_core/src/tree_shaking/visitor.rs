@@ -445,6 +445,35 @@ impl<'a> ModuleRefAnalyze<'a> {
       }
     }
   }
+
+  /// Reference the worker of `new Worker(new URL(...), options)` or
+  /// `navigator.serviceWorker.register(new URL(...), options)`, returns `false` if the first
+  /// argument isn't a `new URL(...)`.
+  fn visit_worker_args(&mut self, args: &[ExprOrSpread]) -> bool {
+    let Some(ExprOrSpread {
+      expr: box Expr::New(new_url_expr),
+      ..
+    }) = args.first()
+    else {
+      return false;
+    };
+    let Some((_, _, request)) = crate::needs_refactor::match_new_url(new_url_expr) else {
+      return false;
+    };
+    let src = Part::Worker(request.into());
+    match self.current_body_owner_symbol_ext {
+      Some(ref body_owner_symbol_ext) => {
+        self.add_reference(body_owner_symbol_ext.clone(), src, false);
+      }
+      None => {
+        self.used_id_set.insert(src);
+      }
+    }
+    for ele in args.iter().skip(1) {
+      ele.visit_with(self);
+    }
+    true
+  }
 }
 
 impl<'a> Visit for ModuleRefAnalyze<'a> {
@@ -724,28 +753,8 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
       && let Some(args) = &new_expr.args
     {
       new_expr.callee.visit_with(self);
-      if let Some(ExprOrSpread {
-        expr: box Expr::New(new_expr),
-        ..
-      }) = args.first()
-      {
-        if let Some((_, _, request)) = crate::needs_refactor::match_new_url(new_expr) {
-          let src = Part::Worker(request.into());
-          match self.current_body_owner_symbol_ext {
-            Some(ref body_owner_symbol_ext) => {
-              self.add_reference(body_owner_symbol_ext.clone(), src, false);
-            }
-            None => {
-              self.used_id_set.insert(src);
-            }
-          }
-          if let Some(args) = &new_expr.args {
-            for ele in args.iter().skip(1) {
-              ele.visit_with(self);
-            }
-          }
-          return;
-        }
+      if self.visit_worker_args(args) {
+        return;
       }
     }
     if let Some((_, _, request)) = crate::needs_refactor::match_new_url(new_expr) {
@@ -1104,6 +1113,12 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
     }
   }
   fn visit_call_expr(&mut self, node: &CallExpr) {
+    if self.worker_syntax_list.match_worker_call(node).is_some() {
+      node.callee.visit_with(self);
+      if self.visit_worker_args(&node.args) {
+        return;
+      }
+    }
     if let Some(require_lit) = get_require_literal(node, self.unresolved_ctxt) {
       self.module_syntax.insert(ModuleSyntax::COMMONJS);
       match self.resolve_module_identifier(&require_lit, &DependencyType::CjsRequire) {
@@ -1,17 +1,43 @@
 use std::{collections::HashMap, sync::Arc};
 
+use indexmap::IndexSet;
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
 
 use crate::{
-  EntryOptions, Filename, RuntimeSpec, CHUNK_HASH_PLACEHOLDER, CONTENT_HASH_PLACEHOLDER,
+  Entry, EntryOptions, Filename, RuntimeSpec, CHUNK_HASH_PLACEHOLDER, CONTENT_HASH_PLACEHOLDER,
   FULL_HASH_PLACEHOLDER, HASH_PLACEHOLDER,
 };
 
-pub fn get_entry_runtime(name: &str, options: &EntryOptions) -> RuntimeSpec {
-  RuntimeSpec::from_iter([Arc::from(
-    options.runtime.clone().unwrap_or_else(|| name.to_string()),
-  )])
+/// Entries with `depend_on` don't have their own runtime, they use the runtimes of the entries
+/// they transitively depend on.
+pub fn get_entry_runtime(name: &str, options: &EntryOptions, entries: &Entry) -> RuntimeSpec {
+  let Some(depend_on) = &options.depend_on else {
+    return RuntimeSpec::from_iter([Arc::from(
+      options.runtime.clone().unwrap_or_else(|| name.to_string()),
+    )]);
+  };
+  let mut result = RuntimeSpec::default();
+  let mut queue = depend_on.iter().collect::<IndexSet<_>>();
+  let mut i = 0;
+  while let Some(dep_name) = queue.get_index(i).copied() {
+    i += 1;
+    let Some(dep) = entries.get(dep_name) else {
+      continue;
+    };
+    match &dep.options.depend_on {
+      Some(depend_on) => queue.extend(depend_on),
+      None => {
+        result.insert(Arc::from(
+          dep.options.runtime.as_deref().unwrap_or(dep_name.as_str()),
+        ));
+      }
+    }
+  }
+  if result.is_empty() {
+    result.insert(Arc::from(name));
+  }
+  result
 }
 
 static HASH_REPLACERS: Lazy<Vec<(&Lazy<Regex>, &str)>> = Lazy::new(|| {
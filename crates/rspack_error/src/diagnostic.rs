@@ -1,4 +1,9 @@
-use std::{fmt, ops::Deref, sync::Arc};
+use std::{
+  fmt,
+  ops::Deref,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 
 use miette::{GraphicalTheme, IntoDiagnostic, MietteDiagnostic};
 use rspack_identifier::Identifier;
@@ -125,17 +130,32 @@ impl Diagnostic {
     self.1.set_module_identifier(module_identifier);
     self
   }
+
+  /// Resource path of the module the diagnostic belongs to
+  pub fn file(&self) -> Option<&Path> {
+    self.1.file.as_deref()
+  }
+
+  pub fn with_file(mut self, file: Option<PathBuf>) -> Self {
+    self.1.set_file(file);
+    self
+  }
 }
 
 #[derive(Debug, Default, Clone)]
 struct DiagnosticMeta {
   module_identifier: Option<Identifier>,
+  file: Option<PathBuf>,
 }
 
 impl DiagnosticMeta {
   fn set_module_identifier(&mut self, module_identifier: Option<Identifier>) {
     self.module_identifier = module_identifier;
   }
+
+  fn set_file(&mut self, file: Option<PathBuf>) {
+    self.file = file;
+  }
 }
 
 pub trait Diagnosable {
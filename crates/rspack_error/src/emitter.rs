@@ -1,11 +1,13 @@
 use std::io::Write;
 
 use anyhow::Context;
-use miette::IntoDiagnostic;
+use miette::{IntoDiagnostic, SourceCode, SourceSpan};
+use serde::Serialize;
 use termcolor::{Buffer, ColorSpec, StandardStreamLock, WriteColor};
 use termcolor::{ColorChoice, StandardStream};
+use url::Url;
 
-use crate::Diagnostic;
+use crate::{Diagnostic, TraceableError};
 
 pub trait FlushDiagnostic {
   fn flush_diagnostic(&mut self) {}
@@ -200,3 +202,396 @@ impl DiagnosticDisplay for DiagnosticDisplayer {
     }
   }
 }
+
+/// 1-based line and column in the source of a diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct DiagnosticPosition {
+  pub line: usize,
+  pub column: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct DiagnosticRange {
+  pub start: DiagnosticPosition,
+  pub end: DiagnosticPosition,
+}
+
+/// A [Diagnostic] flattened for machine-readable emitters.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticRecord {
+  pub severity: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub code: Option<String>,
+  pub message: String,
+  /// Resource path of the module the diagnostic belongs to
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub file: Option<String>,
+  /// Range of the first label of the diagnostic
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub range: Option<DiagnosticRange>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub help: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub url: Option<String>,
+}
+
+impl From<&Diagnostic> for DiagnosticRecord {
+  fn from(diagnostic: &Diagnostic) -> Self {
+    let range = diagnostic.source_code().and_then(|source| {
+      let label = diagnostic.labels()?.next()?;
+      get_range(source, *label.inner())
+    });
+    // the title of a traceable error names the kind of the diagnostic
+    let code = diagnostic
+      .downcast_ref::<TraceableError>()
+      .map(|error| error.title().to_string())
+      .or_else(|| diagnostic.code().map(|code| code.to_string()));
+    Self {
+      severity: get_severity(diagnostic).to_string(),
+      code,
+      message: diagnostic.message(),
+      file: diagnostic
+        .file()
+        .map(|file| file.to_string_lossy().to_string()),
+      range,
+      help: diagnostic.help().map(|help| help.to_string()),
+      url: diagnostic.url().map(|url| url.to_string()),
+    }
+  }
+}
+
+/// `Diagnostic::severity` doesn't support advices, read it from miette instead.
+fn get_severity(diagnostic: &Diagnostic) -> &'static str {
+  match (**diagnostic).severity().unwrap_or_default() {
+    miette::Severity::Error => "error",
+    miette::Severity::Warning => "warning",
+    miette::Severity::Advice => "advice",
+  }
+}
+
+fn get_position(source: &dyn SourceCode, offset: usize) -> Option<DiagnosticPosition> {
+  let contents = source
+    .read_span(&SourceSpan::new(offset.into(), 0.into()), 0, 0)
+    .ok()?;
+  Some(DiagnosticPosition {
+    line: contents.line() + 1,
+    column: contents.column() + 1,
+  })
+}
+
+fn get_range(source: &dyn SourceCode, span: SourceSpan) -> Option<DiagnosticRange> {
+  let start = get_position(source, span.offset())?;
+  let end = get_position(source, span.offset() + span.len()).unwrap_or(start);
+  Some(DiagnosticRange { start, end })
+}
+
+/// Emits diagnostics as JSON, a single diagnostic as an object and a batch as an array.
+#[derive(Debug, Default, Clone)]
+pub struct JsonDiagnosticDisplay {
+  pretty: bool,
+}
+
+impl JsonDiagnosticDisplay {
+  pub fn with_pretty(self, pretty: bool) -> Self {
+    Self { pretty }
+  }
+
+  fn to_string(&self, value: &impl Serialize) -> crate::Result<String> {
+    if self.pretty {
+      serde_json::to_string_pretty(value).into_diagnostic()
+    } else {
+      serde_json::to_string(value).into_diagnostic()
+    }
+  }
+}
+
+impl DiagnosticDisplay for JsonDiagnosticDisplay {
+  type Output = crate::Result<String>;
+
+  fn emit_batch_diagnostic(
+    &mut self,
+    diagnostics: impl Iterator<Item = &Diagnostic>,
+  ) -> Self::Output {
+    let records = diagnostics.map(DiagnosticRecord::from).collect::<Vec<_>>();
+    self.to_string(&records)
+  }
+
+  fn emit_diagnostic(&mut self, diagnostic: &Diagnostic) -> Self::Output {
+    self.to_string(&DiagnosticRecord::from(diagnostic))
+  }
+}
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+#[derive(Debug, Serialize)]
+struct SarifLog {
+  #[serde(rename = "$schema")]
+  schema: &'static str,
+  version: &'static str,
+  runs: Vec<SarifRun>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRun {
+  tool: SarifTool,
+  results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool {
+  driver: SarifDriver,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+  name: &'static str,
+  information_uri: &'static str,
+  rules: Vec<SarifRule>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+  id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  help_uri: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  rule_id: Option<String>,
+  level: &'static str,
+  message: SarifMessage,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  locations: Vec<SarifLocation>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  properties: Option<SarifProperties>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifMessage {
+  text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+  physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+  artifact_location: SarifArtifactLocation,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  region: Option<SarifRegion>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifArtifactLocation {
+  uri: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+  start_line: usize,
+  start_column: usize,
+  end_line: usize,
+  end_column: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifProperties {
+  help: String,
+}
+
+impl From<DiagnosticRecord> for SarifResult {
+  fn from(record: DiagnosticRecord) -> Self {
+    let region = record.range.map(|range| SarifRegion {
+      start_line: range.start.line,
+      start_column: range.start.column,
+      end_line: range.end.line,
+      end_column: range.end.column,
+    });
+    let locations = record
+      .file
+      .map(|file| SarifLocation {
+        physical_location: SarifPhysicalLocation {
+          artifact_location: SarifArtifactLocation {
+            uri: to_file_uri(&file),
+          },
+          region,
+        },
+      })
+      .into_iter()
+      .collect();
+    Self {
+      rule_id: record.code,
+      level: match record.severity.as_str() {
+        "error" => "error",
+        "warning" => "warning",
+        _ => "note",
+      },
+      message: SarifMessage {
+        text: record.message,
+      },
+      locations,
+      properties: record.help.map(|help| SarifProperties { help }),
+    }
+  }
+}
+
+/// Artifact locations of SARIF are URIs, absolute paths are converted to `file://` URIs.
+fn to_file_uri(file: &str) -> String {
+  Url::from_file_path(file).map_or_else(|_| file.replace('\\', "/"), |url| url.to_string())
+}
+
+/// Emits diagnostics as a [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+/// log with a single run.
+#[derive(Debug, Default, Clone)]
+pub struct SarifDiagnosticDisplay;
+
+impl DiagnosticDisplay for SarifDiagnosticDisplay {
+  type Output = crate::Result<String>;
+
+  fn emit_batch_diagnostic(
+    &mut self,
+    diagnostics: impl Iterator<Item = &Diagnostic>,
+  ) -> Self::Output {
+    let mut rules: Vec<SarifRule> = vec![];
+    let results = diagnostics
+      .map(|diagnostic| {
+        let record = DiagnosticRecord::from(diagnostic);
+        if let Some(code) = &record.code
+          && !rules.iter().any(|rule| &rule.id == code)
+        {
+          rules.push(SarifRule {
+            id: code.clone(),
+            help_uri: record.url.clone(),
+          });
+        }
+        SarifResult::from(record)
+      })
+      .collect();
+    let log = SarifLog {
+      schema: SARIF_SCHEMA,
+      version: SARIF_VERSION,
+      runs: vec![SarifRun {
+        tool: SarifTool {
+          driver: SarifDriver {
+            name: "rspack",
+            information_uri: "https://www.rspack.dev",
+            rules,
+          },
+        },
+        results,
+      }],
+    };
+    serde_json::to_string_pretty(&log).into_diagnostic()
+  }
+
+  fn emit_diagnostic(&mut self, diagnostic: &Diagnostic) -> Self::Output {
+    self.emit_batch_diagnostic(std::iter::once(diagnostic))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn redeclared_diagnostic() -> Diagnostic {
+    Diagnostic::from(miette::Error::new(TraceableError::from_file(
+      "const a = 1;\nconst a = 2;\n".to_string(),
+      19,
+      20,
+      "JavaScript parsing error".to_string(),
+      "Identifier `a` has already been declared".to_string(),
+    )))
+    .with_module_identifier(Some("builtin:swc-loader!/project/src/index.js".into()))
+    .with_file(Some("/project/src/index.js".into()))
+  }
+
+  #[test]
+  fn diagnostic_record_should_have_range() {
+    let record = DiagnosticRecord::from(&redeclared_diagnostic());
+    assert_eq!(record.severity, "error");
+    assert_eq!(
+      record.range,
+      Some(DiagnosticRange {
+        start: DiagnosticPosition { line: 2, column: 7 },
+        end: DiagnosticPosition { line: 2, column: 8 },
+      })
+    );
+  }
+
+  #[test]
+  fn diagnostic_record_should_have_code_and_file() {
+    let record = DiagnosticRecord::from(&redeclared_diagnostic());
+    assert_eq!(record.code.as_deref(), Some("JavaScript parsing error"));
+    assert_eq!(record.file.as_deref(), Some("/project/src/index.js"));
+
+    let json = JsonDiagnosticDisplay::default()
+      .emit_diagnostic(&redeclared_diagnostic())
+      .expect("should emit json");
+    let json: serde_json::Value = serde_json::from_str(&json).expect("should be valid json");
+    assert_eq!(json["code"], "JavaScript parsing error");
+    assert_eq!(json["file"], "/project/src/index.js");
+  }
+
+  #[test]
+  fn sarif_should_have_rules_and_locations() {
+    let warning = Diagnostic::warn(
+      "Module not found".to_string(),
+      "Can't resolve './a'".to_string(),
+    );
+    let sarif = SarifDiagnosticDisplay
+      .emit_batch_diagnostic([redeclared_diagnostic(), warning].iter())
+      .expect("should emit sarif");
+    let sarif: serde_json::Value = serde_json::from_str(&sarif).expect("should be valid json");
+    assert_eq!(sarif["version"], "2.1.0");
+
+    let run = &sarif["runs"][0];
+    let rules = run["tool"]["driver"]["rules"]
+      .as_array()
+      .expect("should have rules");
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[0]["id"], "JavaScript parsing error");
+    assert_eq!(rules[1]["id"], "Module not found");
+
+    let error = &run["results"][0];
+    assert_eq!(error["ruleId"], "JavaScript parsing error");
+    assert_eq!(error["level"], "error");
+    let location = &error["locations"][0]["physicalLocation"];
+    assert_eq!(
+      location["artifactLocation"]["uri"],
+      "file:///project/src/index.js"
+    );
+    assert_eq!(location["region"]["startLine"], 2);
+    assert_eq!(location["region"]["startColumn"], 7);
+
+    let warning = &run["results"][1];
+    assert_eq!(warning["ruleId"], "Module not found");
+    assert_eq!(warning["level"], "warning");
+    assert!(warning.get("locations").is_none());
+  }
+
+  #[test]
+  fn sarif_should_report_advices_as_notes() {
+    let advice = Diagnostic::from(miette::Error::from(
+      miette::MietteDiagnostic::new("Consider splitting the chunk")
+        .with_severity(miette::Severity::Advice),
+    ));
+    assert_eq!(DiagnosticRecord::from(&advice).severity, "advice");
+    let sarif = SarifDiagnosticDisplay
+      .emit_diagnostic(&advice)
+      .expect("should emit sarif");
+    let sarif: serde_json::Value = serde_json::from_str(&sarif).expect("should be valid json");
+    assert_eq!(sarif["runs"][0]["results"][0]["level"], "note");
+  }
+}
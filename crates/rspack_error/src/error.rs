@@ -95,6 +95,10 @@ impl Diagnostic for TraceableError {
 }
 
 impl TraceableError {
+  pub fn title(&self) -> &str {
+    &self.title
+  }
+
   pub fn with_severity(mut self, severity: impl Into<Severity>) -> Self {
     self.severity = severity.into();
     self
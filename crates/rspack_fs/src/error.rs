@@ -32,6 +32,14 @@ impl From<Error> for rspack_error::Error {
   }
 }
 
+impl std::error::Error for Error {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      Error::Io(err) => Some(err),
+    }
+  }
+}
+
 impl Display for Error {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     match self {
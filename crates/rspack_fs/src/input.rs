@@ -0,0 +1,38 @@
+use std::{
+  fmt::Debug,
+  path::{Path, PathBuf},
+  time::SystemTime,
+};
+
+use super::Result;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileMetadata {
+  pub is_file: bool,
+  pub is_directory: bool,
+  pub is_symlink: bool,
+  /// Size of the file in bytes
+  pub size: u64,
+  /// Last modification time, `None` if the platform doesn't support it
+  pub modified: Option<SystemTime>,
+}
+
+/// The file system modules, contexts and snapshots are read from.
+///
+/// Unlike [`ReadableFileSystem`](crate::ReadableFileSystem), this trait is object safe
+/// so it can be shared by the compiler and plugins as `Arc<dyn InputFileSystem>`.
+pub trait InputFileSystem: Debug + Send + Sync {
+  /// Read the entire contents of a file into a bytes vector.
+  ///
+  /// Error: This function will return an error if path does not already exist.
+  fn read(&self, file: &Path) -> Result<Vec<u8>>;
+
+  /// Returns the names of the entries in a directory, in no particular order.
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>>;
+
+  /// Query the metadata of a path, symbolic links are followed.
+  fn metadata(&self, path: &Path) -> Result<FileMetadata>;
+
+  /// Returns the canonical, absolute form of a path with all symbolic links resolved.
+  fn realpath(&self, path: &Path) -> Result<PathBuf>;
+}
@@ -0,0 +1,38 @@
+use std::path::Path;
+
+use super::{cfg_native, InputFileSystem, MemoryFileSystem, Result, WritableFileSystem};
+
+/// The file system caches and lockfiles are read from and written to, they are neither
+/// inputs nor outputs of the build.
+///
+/// Like [`InputFileSystem`], this trait is object safe.
+pub trait IntermediateFileSystem: InputFileSystem {
+  /// Recursively create a directory and all of its parent components if they are missing.
+  fn create_dir_all(&self, dir: &Path) -> Result<()>;
+
+  /// Write a slice as the entire contents of a file.
+  /// This function will create a file if it does not exist, and will entirely replace its contents if it does.
+  fn write(&self, file: &Path, data: &[u8]) -> Result<()>;
+}
+
+impl IntermediateFileSystem for MemoryFileSystem {
+  fn create_dir_all(&self, dir: &Path) -> Result<()> {
+    WritableFileSystem::create_dir_all(self, dir)
+  }
+
+  fn write(&self, file: &Path, data: &[u8]) -> Result<()> {
+    WritableFileSystem::write(self, file, data)
+  }
+}
+
+cfg_native! {
+  impl IntermediateFileSystem for crate::NativeFileSystem {
+    fn create_dir_all(&self, dir: &Path) -> Result<()> {
+      WritableFileSystem::create_dir_all(self, dir)
+    }
+
+    fn write(&self, file: &Path, data: &[u8]) -> Result<()> {
+      WritableFileSystem::write(self, file, data)
+    }
+  }
+}
@@ -7,6 +7,15 @@ cfg_async! {
 pub mod sync;
 pub use sync::{FileSystem, ReadableFileSystem, WritableFileSystem};
 
+mod input;
+pub use input::{FileMetadata, InputFileSystem};
+
+mod intermediate;
+pub use intermediate::IntermediateFileSystem;
+
+mod memory;
+pub use memory::MemoryFileSystem;
+
 mod error;
 pub use error::{Error, Result};
 
@@ -0,0 +1,281 @@
+use std::{
+  collections::{HashMap, HashSet},
+  io::{Error as IoError, ErrorKind},
+  path::{Component, Path, PathBuf},
+  sync::RwLock,
+  time::SystemTime,
+};
+
+use super::{cfg_async, Error, FileMetadata, InputFileSystem, Result, WritableFileSystem};
+
+#[derive(Debug)]
+struct MemoryFile {
+  content: Vec<u8>,
+  modified: SystemTime,
+}
+
+/// A file system living in memory, paths are normalized but never touch the disk.
+///
+/// Useful for building virtual projects in tests and editor integrations.
+#[derive(Debug, Default)]
+pub struct MemoryFileSystem {
+  files: RwLock<HashMap<PathBuf, MemoryFile>>,
+  dirs: RwLock<HashSet<PathBuf>>,
+}
+
+impl MemoryFileSystem {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Create a file system containing the given files, their parent directories are created as well.
+  pub fn from_files<P: AsRef<Path>, D: Into<Vec<u8>>>(
+    files: impl IntoIterator<Item = (P, D)>,
+  ) -> Self {
+    let fs = Self::new();
+    for (path, content) in files {
+      fs.insert_file(normalize(path.as_ref()), content.into());
+    }
+    fs
+  }
+
+  /// Remove a file or a directory with all its contents.
+  pub fn remove<P: AsRef<Path>>(&self, path: P) {
+    let path = normalize(path.as_ref());
+    self
+      .files
+      .write()
+      .expect("should lock files")
+      .retain(|file, _| !file.starts_with(&path));
+    self
+      .dirs
+      .write()
+      .expect("should lock dirs")
+      .retain(|dir| !dir.starts_with(&path));
+  }
+
+  fn insert_file(&self, path: PathBuf, content: Vec<u8>) {
+    if let Some(parent) = path.parent() {
+      self.insert_dir_all(parent);
+    }
+    self.files.write().expect("should lock files").insert(
+      path,
+      MemoryFile {
+        content,
+        modified: SystemTime::now(),
+      },
+    );
+  }
+
+  fn insert_dir_all(&self, dir: &Path) {
+    let mut dirs = self.dirs.write().expect("should lock dirs");
+    for ancestor in dir.ancestors() {
+      if !dirs.insert(ancestor.to_path_buf()) {
+        break;
+      }
+    }
+  }
+
+  fn is_dir(&self, path: &Path) -> bool {
+    self.dirs.read().expect("should lock dirs").contains(path)
+  }
+}
+
+impl InputFileSystem for MemoryFileSystem {
+  fn read(&self, file: &Path) -> Result<Vec<u8>> {
+    let file = normalize(file);
+    self
+      .files
+      .read()
+      .expect("should lock files")
+      .get(&file)
+      .map(|file| file.content.clone())
+      .ok_or_else(|| not_found(&file))
+  }
+
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>> {
+    let dir = normalize(dir);
+    if !self.is_dir(&dir) {
+      return Err(not_found(&dir));
+    }
+    let files = self.files.read().expect("should lock files");
+    let dirs = self.dirs.read().expect("should lock dirs");
+    Ok(
+      files
+        .keys()
+        .chain(dirs.iter())
+        .filter(|path| path.parent() == Some(dir.as_path()))
+        .filter_map(|path| path.file_name())
+        .map(|name| name.to_string_lossy().to_string())
+        .collect(),
+    )
+  }
+
+  fn metadata(&self, path: &Path) -> Result<FileMetadata> {
+    let path = normalize(path);
+    if let Some(file) = self.files.read().expect("should lock files").get(&path) {
+      return Ok(FileMetadata {
+        is_file: true,
+        is_directory: false,
+        is_symlink: false,
+        size: file.content.len() as u64,
+        modified: Some(file.modified),
+      });
+    }
+    if self.is_dir(&path) {
+      return Ok(FileMetadata {
+        is_file: false,
+        is_directory: true,
+        is_symlink: false,
+        size: 0,
+        modified: None,
+      });
+    }
+    Err(not_found(&path))
+  }
+
+  fn realpath(&self, path: &Path) -> Result<PathBuf> {
+    let path = normalize(path);
+    if self
+      .files
+      .read()
+      .expect("should lock files")
+      .contains_key(&path)
+      || self.is_dir(&path)
+    {
+      Ok(path)
+    } else {
+      Err(not_found(&path))
+    }
+  }
+}
+
+impl WritableFileSystem for MemoryFileSystem {
+  fn create_dir<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    let dir = normalize(dir.as_ref());
+    if let Some(parent) = dir.parent() {
+      if !parent.as_os_str().is_empty() && !self.is_dir(parent) {
+        return Err(not_found(parent));
+      }
+    }
+    if !self
+      .dirs
+      .write()
+      .expect("should lock dirs")
+      .insert(dir.clone())
+    {
+      return Err(Error::Io(IoError::new(
+        ErrorKind::AlreadyExists,
+        format!("{} already exists", dir.display()),
+      )));
+    }
+    Ok(())
+  }
+
+  fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> Result<()> {
+    self.insert_dir_all(&normalize(dir.as_ref()));
+    Ok(())
+  }
+
+  fn write<P: AsRef<Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> Result<()> {
+    self.insert_file(normalize(file.as_ref()), data.as_ref().to_vec());
+    Ok(())
+  }
+}
+
+cfg_async! {
+  use futures::future::BoxFuture;
+
+  use crate::AsyncWritableFileSystem;
+
+  impl AsyncWritableFileSystem for MemoryFileSystem {
+    fn create_dir<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      let result = WritableFileSystem::create_dir(self, dir);
+      Box::pin(async move { result })
+    }
+
+    fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      let result = WritableFileSystem::create_dir_all(self, dir);
+      Box::pin(async move { result })
+    }
+
+    fn write<P: AsRef<Path>, D: AsRef<[u8]>>(
+      &self,
+      file: P,
+      data: D,
+    ) -> BoxFuture<'_, Result<()>> {
+      let result = WritableFileSystem::write(self, file, data);
+      Box::pin(async move { result })
+    }
+
+    fn remove_file<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<()>> {
+      let file = normalize(file.as_ref());
+      let removed = self
+        .files
+        .write()
+        .expect("should lock files")
+        .remove(&file)
+        .is_some();
+      let result = if removed { Ok(()) } else { Err(not_found(&file)) };
+      Box::pin(async move { result })
+    }
+
+    fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      self.remove(dir);
+      Box::pin(async { Ok(()) })
+    }
+  }
+}
+
+/// Resolve `.` and `..` lexically, there are no symbolic links in memory.
+fn normalize(path: &Path) -> PathBuf {
+  let mut result = PathBuf::new();
+  for component in path.components() {
+    match component {
+      Component::CurDir => {}
+      Component::ParentDir => {
+        result.pop();
+      }
+      c => result.push(c),
+    }
+  }
+  result
+}
+
+fn not_found(path: &Path) -> Error {
+  Error::Io(IoError::new(
+    ErrorKind::NotFound,
+    format!("{} does not exist", path.display()),
+  ))
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn should_read_written_files() {
+    let fs = MemoryFileSystem::from_files([("/project/src/index.js", "index")]);
+    fs.write("/project/src/./lib/../a.js", "a")
+      .expect("should write");
+
+    assert_eq!(
+      fs.read(Path::new("/project/src/a.js"))
+        .expect("should read"),
+      b"a"
+    );
+    let mut entries = fs
+      .read_dir(Path::new("/project/src"))
+      .expect("should read dir");
+    entries.sort();
+    assert_eq!(entries, vec!["a.js", "index.js"]);
+    assert!(
+      fs.metadata(Path::new("/project"))
+        .expect("should have metadata")
+        .is_directory
+    );
+
+    fs.remove("/project/src/a.js");
+    assert!(fs.read(Path::new("/project/src/a.js")).is_err());
+  }
+}
@@ -1,11 +1,15 @@
-use std::{fs, path::Path};
+use std::{
+  fs,
+  path::{Path, PathBuf},
+};
 
 use super::{
   cfg_async,
   sync::{ReadableFileSystem, WritableFileSystem},
-  Error, Result,
+  Error, FileMetadata, InputFileSystem, Result,
 };
 
+#[derive(Debug)]
 pub struct NativeFileSystem;
 
 impl WritableFileSystem for NativeFileSystem {
@@ -28,6 +32,33 @@ impl ReadableFileSystem for NativeFileSystem {
   }
 }
 
+impl InputFileSystem for NativeFileSystem {
+  fn read(&self, file: &Path) -> Result<Vec<u8>> {
+    fs::read(file).map_err(Error::from)
+  }
+
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>> {
+    fs::read_dir(dir)?
+      .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+      .collect()
+  }
+
+  fn metadata(&self, path: &Path) -> Result<FileMetadata> {
+    let metadata = fs::metadata(path)?;
+    Ok(FileMetadata {
+      is_file: metadata.is_file(),
+      is_directory: metadata.is_dir(),
+      is_symlink: fs::symlink_metadata(path)?.is_symlink(),
+      size: metadata.len(),
+      modified: metadata.modified().ok(),
+    })
+  }
+
+  fn realpath(&self, path: &Path) -> Result<PathBuf> {
+    fs::canonicalize(path).map_err(Error::from)
+  }
+}
+
 cfg_async! {
   use futures::future::BoxFuture;
 
@@ -1,9 +1,10 @@
 use std::{
   fmt,
   hash::{Hash, Hasher},
+  str::FromStr,
 };
 
-use data_encoding::HEXLOWER_PERMISSIVE;
+use data_encoding::{BASE64, BASE64URL_NOPAD, HEXLOWER_PERMISSIVE};
 use md4::Digest;
 use smol_str::SmolStr;
 use xxhash_rust::xxh3;
@@ -11,15 +12,26 @@ use xxhash_rust::xxh3;
 #[derive(Debug, Clone, Copy)]
 pub enum HashFunction {
   Xxhash64,
+  /// 128-bit variant of xxh3
+  Xxhash128,
   MD4,
+  Sha256,
+  Sha512,
 }
 
-impl From<&str> for HashFunction {
-  fn from(value: &str) -> Self {
+impl FromStr for HashFunction {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
     match value {
-      "xxhash64" => HashFunction::Xxhash64,
-      "md4" => HashFunction::MD4,
-      _ => unimplemented!("{}", value),
+      "xxhash64" => Ok(HashFunction::Xxhash64),
+      "xxhash128" => Ok(HashFunction::Xxhash128),
+      "md4" => Ok(HashFunction::MD4),
+      "sha256" => Ok(HashFunction::Sha256),
+      "sha512" => Ok(HashFunction::Sha512),
+      _ => Err(format!(
+        "Unsupported hash function \"{value}\", expected one of \"xxhash64\", \"xxhash128\", \"md4\", \"sha256\" or \"sha512\""
+      )),
     }
   }
 }
@@ -27,13 +39,26 @@ impl From<&str> for HashFunction {
 #[derive(Debug, Clone, Copy)]
 pub enum HashDigest {
   Hex,
+  /// Standard base64 with padding
+  Base64,
+  /// URL and filename safe base64 without padding
+  Base64Url,
+  /// Base58 with the bitcoin alphabet, no ambiguous characters like `0`, `O`, `I` and `l`
+  Base58,
 }
 
-impl From<&str> for HashDigest {
-  fn from(value: &str) -> Self {
+impl FromStr for HashDigest {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
     match value {
-      "hex" => HashDigest::Hex,
-      _ => unimplemented!(),
+      "hex" => Ok(HashDigest::Hex),
+      "base64" => Ok(HashDigest::Base64),
+      "base64url" => Ok(HashDigest::Base64Url),
+      "base58" => Ok(HashDigest::Base58),
+      _ => Err(format!(
+        "Unsupported hash digest \"{value}\", expected one of \"hex\", \"base64\", \"base64url\" or \"base58\""
+      )),
     }
   }
 }
@@ -56,14 +81,20 @@ impl From<Option<String>> for HashSalt {
 #[derive(Clone)]
 pub enum RspackHash {
   Xxhash64(Box<xxh3::Xxh3>),
+  Xxhash128(Box<xxh3::Xxh3>),
   MD4(md4::Md4),
+  Sha256(sha2::Sha256),
+  Sha512(sha2::Sha512),
 }
 
 impl fmt::Debug for RspackHash {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     match self {
       Self::Xxhash64(_) => write!(f, "RspackHash(Xxhash64)"),
+      Self::Xxhash128(_) => write!(f, "RspackHash(Xxhash128)"),
       Self::MD4(_) => write!(f, "RspackHash(MD4)"),
+      Self::Sha256(_) => write!(f, "RspackHash(Sha256)"),
+      Self::Sha512(_) => write!(f, "RspackHash(Sha512)"),
     }
   }
 }
@@ -72,7 +103,10 @@ impl RspackHash {
   pub fn new(function: &HashFunction) -> Self {
     match function {
       HashFunction::Xxhash64 => Self::Xxhash64(Box::new(xxh3::Xxh3::new())),
+      HashFunction::Xxhash128 => Self::Xxhash128(Box::new(xxh3::Xxh3::new())),
       HashFunction::MD4 => Self::MD4(md4::Md4::new()),
+      HashFunction::Sha256 => Self::Sha256(sha2::Sha256::new()),
+      HashFunction::Sha512 => Self::Sha512(sha2::Sha512::new()),
     }
   }
 
@@ -85,7 +119,10 @@ impl RspackHash {
   pub fn digest(self, digest: &HashDigest) -> RspackHashDigest {
     let inner = match self {
       RspackHash::Xxhash64(hasher) => hasher.finish().to_le_bytes().to_vec(),
+      RspackHash::Xxhash128(hasher) => hasher.digest128().to_le_bytes().to_vec(),
       RspackHash::MD4(hash) => hash.finalize().to_vec(),
+      RspackHash::Sha256(hash) => hash.finalize().to_vec(),
+      RspackHash::Sha512(hash) => hash.finalize().to_vec(),
     };
     RspackHashDigest::new(inner, digest)
   }
@@ -95,28 +132,61 @@ impl Hasher for RspackHash {
   fn finish(&self) -> u64 {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.finish(),
-      RspackHash::MD4(hasher) => {
-        // finalize take ownership, so we need to clone it
-        let hash = hasher.clone().finalize();
-        let msb_u64: u64 = ((hash[0] as u64) << 56)
-          | ((hash[1] as u64) << 48)
-          | ((hash[2] as u64) << 40)
-          | ((hash[3] as u64) << 32)
-          | ((hash[4] as u64) << 24)
-          | ((hash[5] as u64) << 16)
-          | ((hash[6] as u64) << 8)
-          | (hash[7] as u64);
-        msb_u64
-      }
+      RspackHash::Xxhash128(hasher) => hasher.finish(),
+      // finalize take ownership, so we need to clone it
+      RspackHash::MD4(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::Sha256(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::Sha512(hasher) => msb_u64(&hasher.clone().finalize()),
     }
   }
 
   fn write(&mut self, bytes: &[u8]) {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.write(bytes),
+      RspackHash::Xxhash128(hasher) => hasher.write(bytes),
       RspackHash::MD4(hasher) => hasher.update(bytes),
+      RspackHash::Sha256(hasher) => hasher.update(bytes),
+      RspackHash::Sha512(hasher) => hasher.update(bytes),
+    }
+  }
+}
+
+/// The first 8 bytes of a digest as a big endian `u64`
+fn msb_u64(hash: &[u8]) -> u64 {
+  let mut bytes = [0u8; 8];
+  bytes.copy_from_slice(&hash[..8]);
+  u64::from_be_bytes(bytes)
+}
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+fn encode_base58(input: &[u8]) -> String {
+  // little endian base58 digits of the input
+  let mut digits: Vec<u8> = Vec::with_capacity(input.len() * 138 / 100 + 1);
+  for &byte in input {
+    let mut carry = byte as u32;
+    for digit in digits.iter_mut() {
+      carry += (*digit as u32) << 8;
+      *digit = (carry % 58) as u8;
+      carry /= 58;
+    }
+    while carry > 0 {
+      digits.push((carry % 58) as u8);
+      carry /= 58;
     }
   }
+  // each leading zero byte is encoded as a leading "1"
+  let zeros = input.iter().take_while(|&&byte| byte == 0).count();
+  std::iter::repeat(BASE58_ALPHABET[0])
+    .take(zeros)
+    .chain(
+      digits
+        .iter()
+        .rev()
+        .map(|&digit| BASE58_ALPHABET[digit as usize]),
+    )
+    .map(char::from)
+    .collect()
 }
 
 #[derive(Debug, Clone, Eq)]
@@ -128,10 +198,20 @@ impl RspackHashDigest {
   pub fn new(inner: Vec<u8>, digest: &HashDigest) -> Self {
     let encoded = match digest {
       HashDigest::Hex => HEXLOWER_PERMISSIVE.encode(&inner).into(),
+      HashDigest::Base64 => BASE64.encode(&inner).into(),
+      HashDigest::Base64Url => BASE64URL_NOPAD.encode(&inner).into(),
+      HashDigest::Base58 => encode_base58(&inner).into(),
     };
     Self { encoded }
   }
 
+  /// Restore a digest from its encoded form, e.g. when reading it from a persistent cache.
+  pub fn from_encoded(encoded: &str) -> Self {
+    Self {
+      encoded: encoded.into(),
+    }
+  }
+
   pub fn encoded(&self) -> &str {
     &self.encoded
   }
@@ -153,3 +233,35 @@ impl PartialEq for RspackHashDigest {
     self.encoded == other.encoded
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn base58() {
+    assert_eq!(encode_base58(b""), "");
+    assert_eq!(encode_base58(b"Hello World!"), "2NEpo7TZRRrLZSi2U");
+    assert_eq!(encode_base58(&[0, 0, 1]), "112");
+  }
+
+  #[test]
+  fn digests() {
+    let digest = |function: &str, digest: &str| {
+      let mut hash = RspackHash::new(&function.parse().expect("should be a hash function"));
+      hash.write(b"rspack");
+      hash
+        .digest(&digest.parse().expect("should be a hash digest"))
+        .encoded()
+        .to_string()
+    };
+    assert_eq!(digest("sha256", "hex").len(), 64);
+    assert_eq!(digest("sha512", "hex").len(), 128);
+    assert_eq!(digest("xxhash128", "hex").len(), 32);
+    assert_eq!(digest("sha256", "base64").len(), 44);
+    assert_eq!(digest("sha256", "base64url").len(), 43);
+    assert!(!digest("sha256", "base58").contains(['0', 'O', 'I', 'l']));
+    assert!("sha1".parse::<HashFunction>().is_err());
+    assert!("latin1".parse::<HashDigest>().is_err());
+  }
+}
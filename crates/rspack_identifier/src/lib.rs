@@ -3,7 +3,7 @@ use std::hash::BuildHasherDefault;
 use std::{convert::From, fmt, ops::Deref};
 
 use hashlink::{LinkedHashMap, LinkedHashSet};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
 pub trait Identifiable {
@@ -58,6 +58,12 @@ impl From<Identifier> for Ustr {
   }
 }
 
+impl<'de> Deserialize<'de> for Identifier {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    String::deserialize(deserializer).map(Self::from)
+  }
+}
+
 impl fmt::Display for Identifier {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     write!(f, "{}", self.0.as_str())
@@ -3,7 +3,7 @@ use rspack_error::Result;
 
 use crate::id_helpers::{
   assign_deterministic_ids, compare_modules_by_pre_order_index_or_identifier, get_full_module_name,
-  get_used_module_ids_and_modules,
+  get_used_module_ids_and_modules, record_module_ids, revive_module_ids,
 };
 
 #[derive(Debug, Default)]
@@ -11,6 +11,8 @@ pub struct DeterministicModuleIdsPlugin;
 
 impl Plugin for DeterministicModuleIdsPlugin {
   fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+    revive_module_ids(compilation);
+
     let (mut used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
 
     let module_graph = &compilation.module_graph;
@@ -50,6 +52,7 @@ impl Plugin for DeterministicModuleIdsPlugin {
       // TODO: better error msg
       panic!("Assigning deterministic module ids has lead to conflicts {conflicts}");
     }
+    record_module_ids(compilation);
     Ok(())
   }
 }
@@ -58,6 +58,49 @@ pub fn get_used_module_ids_and_modules(
   (used_ids, modules)
 }
 
+/// Assign the ids recorded by a previous build to modules without an id.
+pub fn revive_module_ids(compilation: &mut Compilation) {
+  let Some(records) = &compilation.persisted_records else {
+    return;
+  };
+  let context = compilation.options.context.as_str();
+  let (mut used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
+  let revived = modules
+    .into_iter()
+    .filter_map(|identifier| {
+      let module = compilation.module_graph.module_by_identifier(&identifier)?;
+      let id = records
+        .modules
+        .by_identifier
+        .get(&get_full_module_name(module, context))?;
+      used_ids
+        .insert(id.clone())
+        .then(|| (identifier, id.clone()))
+    })
+    .collect::<Vec<_>>();
+  for (identifier, id) in revived {
+    compilation.chunk_graph.set_module_id(identifier, id);
+  }
+}
+
+/// Record the ids of all modules so the next build can revive them.
+pub fn record_module_ids(compilation: &mut Compilation) {
+  let Some(records) = &mut compilation.persisted_records else {
+    return;
+  };
+  let context = compilation.options.context.as_str();
+  let chunk_graph = &compilation.chunk_graph;
+  records.modules.by_identifier = compilation
+    .module_graph
+    .modules()
+    .values()
+    .filter_map(|module| {
+      let id = chunk_graph.get_module_id(module.identifier()).as_ref()?;
+      Some((get_full_module_name(module, context), id.clone()))
+    })
+    .collect();
+}
+
 pub fn get_short_module_name(module: &BoxModule, context: &str) -> String {
   let lib_ident = module.lib_ident(rspack_core::LibIdentOptions { context });
   if let Some(lib_ident) = lib_ident {
@@ -457,6 +500,67 @@ pub fn get_used_chunk_ids(compilation: &Compilation) -> HashSet<String> {
   used_ids
 }
 
+/// Assign the ids recorded by a previous build to chunks without an id, chunks are matched by
+/// their name or by their root modules.
+pub fn revive_chunk_ids(compilation: &mut Compilation) {
+  let Some(records) = &compilation.persisted_records else {
+    return;
+  };
+  let context = compilation.options.context.as_str();
+  let mut used_ids = get_used_chunk_ids(compilation);
+  let revived = compilation
+    .chunk_by_ukey
+    .values()
+    .filter(|chunk| chunk.id.is_none())
+    .filter_map(|chunk| {
+      let id = match &chunk.name {
+        Some(name) => records.chunks.by_name.get(name),
+        None => records.chunks.by_source.get(&get_full_chunk_name(
+          chunk,
+          &compilation.chunk_graph,
+          &compilation.module_graph,
+          context,
+        )),
+      }?;
+      used_ids
+        .insert(id.clone())
+        .then(|| (chunk.ukey, id.clone()))
+    })
+    .collect::<Vec<_>>();
+  for (chunk_ukey, id) in revived {
+    let chunk = compilation.chunk_by_ukey.expect_get_mut(&chunk_ukey);
+    chunk.id = Some(id.clone());
+    chunk.ids = vec![id];
+  }
+}
+
+/// Record the ids of all chunks so the next build can revive them.
+pub fn record_chunk_ids(compilation: &mut Compilation) {
+  let Some(records) = &mut compilation.persisted_records else {
+    return;
+  };
+  let context = compilation.options.context.as_str();
+  records.chunks.by_name.clear();
+  records.chunks.by_source.clear();
+  for chunk in compilation.chunk_by_ukey.values() {
+    let Some(id) = &chunk.id else {
+      continue;
+    };
+    match &chunk.name {
+      Some(name) => records.chunks.by_name.insert(name.clone(), id.clone()),
+      None => records.chunks.by_source.insert(
+        get_full_chunk_name(
+          chunk,
+          &compilation.chunk_graph,
+          &compilation.module_graph,
+          context,
+        ),
+        id.clone(),
+      ),
+    };
+  }
+}
+
 pub fn assign_ascending_chunk_ids(chunks: &[ChunkUkey], compilation: &mut Compilation) {
   let used_ids = get_used_chunk_ids(compilation);
 
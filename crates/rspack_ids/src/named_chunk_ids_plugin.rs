@@ -4,7 +4,7 @@ use rspack_core::{Chunk, Plugin};
 
 use crate::id_helpers::{
   assign_ascending_chunk_ids, assign_names_par, compare_chunks_natural, get_long_chunk_name,
-  get_short_chunk_name, get_used_chunk_ids,
+  get_short_chunk_name, get_used_chunk_ids, record_chunk_ids, revive_chunk_ids,
 };
 
 #[derive(Debug)]
@@ -28,6 +28,8 @@ impl Plugin for NamedChunkIdsPlugin {
   }
 
   fn chunk_ids(&self, compilation: &mut rspack_core::Compilation) -> rspack_error::Result<()> {
+    revive_chunk_ids(compilation);
+
     let mut used_ids = get_used_chunk_ids(compilation);
     let chunk_graph = &compilation.chunk_graph;
     let module_graph = &compilation.module_graph;
@@ -76,6 +78,7 @@ impl Plugin for NamedChunkIdsPlugin {
       assign_ascending_chunk_ids(&unnamed_chunks, compilation)
     }
 
+    record_chunk_ids(compilation);
     Ok(())
   }
 }
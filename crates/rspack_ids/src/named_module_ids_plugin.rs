@@ -3,7 +3,7 @@ use rspack_error::Result;
 
 use crate::id_helpers::{
   assign_ascending_module_ids, assign_names_par, get_long_module_name, get_short_module_name,
-  get_used_module_ids_and_modules,
+  get_used_module_ids_and_modules, record_module_ids, revive_module_ids,
 };
 
 #[derive(Debug, Default)]
@@ -15,6 +15,8 @@ impl Plugin for NamedModuleIdsPlugin {
   }
 
   fn module_ids(&self, compilation: &mut rspack_core::Compilation) -> Result<()> {
+    revive_module_ids(compilation);
+
     // Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ids/NamedModuleIdsPlugin.js
     let context: &str = compilation.options.context.as_ref();
     let (mut used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
@@ -36,6 +38,7 @@ impl Plugin for NamedModuleIdsPlugin {
     if !unnamed_modules.is_empty() {
       assign_ascending_module_ids(&used_ids, unnamed_modules, chunk_graph)
     }
+    record_module_ids(compilation);
     Ok(())
   }
 }
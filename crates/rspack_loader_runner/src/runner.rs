@@ -230,6 +230,7 @@ async fn process_resource<C: Send>(loader_context: &mut LoaderContext<'_, C>) ->
     }
   }
 
+  // Embedders provide their own file system through plugins, read from disk otherwise
   if loader_context.content.is_none()
     && !loader_context
       .__resource_data
@@ -6,6 +6,7 @@ pub enum Scheme {
   Data,
   File,
   Http,
+  Https,
   Custom(String),
 }
 
@@ -18,6 +19,10 @@ impl Scheme {
     matches!(self, Self::Data)
   }
 
+  pub fn is_http(&self) -> bool {
+    matches!(self, Self::Http | Self::Https)
+  }
+
   pub fn is_none(&self) -> bool {
     matches!(self, Self::None)
   }
@@ -32,6 +37,7 @@ impl From<&str> for Scheme {
       "data" => Self::Data,
       "file" => Self::File,
       "http" => Self::Http,
+      "https" => Self::Https,
       v => Self::Custom(v.to_string()),
     }
   }
@@ -47,6 +53,7 @@ impl fmt::Display for Scheme {
         Self::Data => "data",
         Self::File => "file",
         Self::Http => "http",
+        Self::Https => "https",
         Self::Custom(v) => v,
       }
     )
@@ -132,6 +139,11 @@ mod tests {
     assert_eq!(get_scheme("http://localhost"), Scheme::Http);
   }
 
+  #[test]
+  fn https_for_https_url() {
+    assert_eq!(get_scheme("https://localhost"), Scheme::Https);
+  }
+
   #[test]
   fn file_for_file_url() {
     assert_eq!(get_scheme("file:/a.js"), Scheme::File);
@@ -395,6 +395,31 @@ impl SwcCompiler {
     program
   }
 
+  /// Names of the Wasm plugins configured by the options of the loader or the .swcrc file.
+  pub fn plugin_names(&self) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for config in [&self.options.config, &self.config] {
+      for plugin in config.jsc.experimental.plugins.iter().flatten() {
+        if !names.contains(&plugin.0) {
+          names.push(plugin.0.clone());
+        }
+      }
+    }
+    names
+  }
+
+  /// `jsc.experimental.cacheRoot` of the options of the loader or the .swcrc file.
+  pub fn plugin_cache_root(&self) -> Option<String> {
+    self
+      .options
+      .config
+      .jsc
+      .experimental
+      .cache_root
+      .clone()
+      .or_else(|| self.config.jsc.experimental.cache_root.clone())
+  }
+
   pub fn comments(&self) -> &SingleThreadedComments {
     &self.comments
   }
@@ -2,6 +2,7 @@
 
 mod compiler;
 mod options;
+mod plugin;
 mod transformer;
 
 use std::default::Default;
@@ -68,13 +69,6 @@ impl Loader<LoaderRunnerContext> for SwcLoader {
         }
       }
 
-      if swc_options.config.jsc.experimental.plugins.is_some() {
-        loader_context.emit_diagnostic(Diagnostic::warn(
-          SWC_LOADER_IDENTIFIER.to_string(),
-          "Experimental plugins are not currently supported.".to_string(),
-        ));
-      }
-
       if swc_options.config.jsc.target.is_some() && swc_options.config.env.is_some() {
         loader_context.emit_diagnostic(Diagnostic::warn(
           SWC_LOADER_IDENTIFIER.to_string(),
@@ -98,6 +92,11 @@ impl Loader<LoaderRunnerContext> for SwcLoader {
       .unresolved_mark
       .expect("`unresolved_mark` should be initialized");
 
+    let plugin_names = c.plugin_names();
+    if !plugin_names.is_empty() {
+      plugin::load_plugins(&c, &plugin_names)?;
+    }
+
     let built = c
       .parse(None, |_| {
         transformer::transform(
@@ -130,7 +129,11 @@ impl Loader<LoaderRunnerContext> for SwcLoader {
       inline_script: Some(false),
       keep_comments: Some(true),
     };
-    let program = c.transform(built).map_err(AnyhowError::from)?;
+    let program = if plugin_names.is_empty() {
+      c.transform(built).map_err(AnyhowError::from)?
+    } else {
+      plugin::transform_with_plugins(&c, built, &plugin_names)?
+    };
     let ast = c.into_js_ast(program);
 
     // If swc-loader is the latest loader available,
@@ -0,0 +1,89 @@
+//! SWC Wasm plugins, configured with `jsc.experimental.plugins`.
+//!
+//! Plugins are resolved and compiled into the module cache of `swc_plugin_runner` before the
+//! module is parsed, so the transform only runs them. Compiled modules are keyed by the name of
+//! the plugin, so every plugin is compiled once per process. `jsc.experimental.cacheRoot` sets the
+//! directory of the filesystem cache shared by processes.
+
+use std::path::PathBuf;
+
+use rspack_error::{catch_unwind, error, Result};
+use swc_core::base::config::{init_plugin_module_cache_once, BuiltInput, PLUGIN_MODULE_CACHE};
+use swc_core::common::FileName;
+use swc_core::ecma::ast::Program;
+use swc_core::ecma::loader::resolve::Resolve;
+use swc_core::ecma::loader::resolvers::{lru::CachingResolver, node::NodeModulesResolver};
+use swc_core::ecma::loader::TargetEnv;
+use swc_core::ecma::visit::Fold;
+use tokio::runtime::{Handle, RuntimeFlavor};
+
+use crate::compiler::SwcCompiler;
+
+const ABI_HINT: &str = "Make sure the plugins are built with a version of `swc_core` that is compatible with the one used by Rspack.";
+
+/// Resolves and compiles the plugins which are not in the module cache yet.
+pub(crate) fn load_plugins(compiler: &SwcCompiler, plugin_names: &[String]) -> Result<()> {
+  init_plugin_module_cache_once(true, &compiler.plugin_cache_root());
+  let resolver = CachingResolver::new(
+    40,
+    NodeModulesResolver::new(TargetEnv::Node, Default::default(), true),
+  );
+  let mut cache = PLUGIN_MODULE_CACHE
+    .inner
+    .get()
+    .expect("plugin module cache should be initialized")
+    .lock()
+    .expect("plugin module cache should not be poisoned");
+  for plugin_name in plugin_names {
+    if cache.contains(plugin_name) {
+      continue;
+    }
+    let load = || -> anyhow::Result<()> {
+      let resolved = resolver.resolve(&FileName::Real(PathBuf::from(plugin_name)), plugin_name)?;
+      let FileName::Real(path) = resolved else {
+        anyhow::bail!("Failed to resolve plugin path: {resolved:?}");
+      };
+      cache.store_bytes_from_path(&path, plugin_name)
+    };
+    // Compiling a module with an incompatible ABI may panic as well.
+    match catch_unwind(load) {
+      Ok(Ok(())) => {}
+      Ok(Err(e)) => {
+        return Err(error!(
+          "Failed to load SWC plugin {plugin_name}: {e:?}\n{ABI_HINT}"
+        ))
+      }
+      Err(e) => {
+        return Err(error!(
+          "Failed to load SWC plugin {plugin_name}: {e}\n{ABI_HINT}"
+        ))
+      }
+    }
+  }
+  Ok(())
+}
+
+pub(crate) fn transform_with_plugins(
+  compiler: &SwcCompiler,
+  built: BuiltInput<impl Fold>,
+  plugin_names: &[String],
+) -> Result<Program> {
+  let transform = || catch_unwind(|| compiler.transform(built));
+  // The plugin runner blocks on the current tokio runtime, which is not allowed in async contexts.
+  // `block_in_place` panics outside of a multi thread runtime, the transform runs as is there.
+  let result = match Handle::try_current() {
+    Ok(handle) if matches!(handle.runtime_flavor(), RuntimeFlavor::MultiThread) => {
+      tokio::task::block_in_place(transform)
+    }
+    _ => transform(),
+  };
+  let plugin_names = plugin_names.join(", ");
+  match result {
+    Ok(Ok(program)) => Ok(program),
+    Ok(Err(e)) => Err(error!("Failed to run SWC plugins {plugin_names}: {e:?}")),
+    // The runner panics when a plugin can't be invoked, usually an incompatible ABI.
+    Err(e) => Err(error!(
+      "Failed to run SWC plugins {plugin_names}: {e}\n{ABI_HINT}"
+    )),
+  }
+}
@@ -9,27 +9,19 @@ use rspack_core::{
   run_loaders, CompilerContext, CompilerOptions, Loader, LoaderRunnerContext, PluginDriver,
   ResourceData, SideEffectOption,
 };
+use rspack_error::Result;
+use rspack_fs::NativeFileSystem;
 use rspack_loader_swc::{SwcLoader, SwcLoaderJsOptions};
 use rspack_testing::{fixture, test_fixture};
 use rspack_util::source_map::SourceMapKind;
 use serde_json::json;
 use swc_core::base::config::PluginConfig;
 
-// UPDATE=1 cargo test --package rspack_loader_swc -- --nocapture
-#[allow(dead_code)]
-async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
-  let tests_path = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"))).join("tests");
-  let expected_path = tests_path.join(expected);
-  let actual_path = tests_path.join(actual);
-  let plugin_path = tests_path.join("my_first_plugin.wasm");
-  let mut options = SwcLoaderJsOptions::default();
-  options.jsc.experimental.plugins = Some(vec![PluginConfig(
-    plugin_path.to_string_lossy().to_string(),
-    json!(null),
-  )]);
-
-  let compiler_options = CompilerOptions {
+fn compiler_options() -> CompilerOptions {
+  CompilerOptions {
     bail: false,
+    records_input_path: None,
+    records_output_path: None,
     context: rspack_core::Context::default(),
     dev_server: rspack_core::DevServerOptions::default(),
     mode: rspack_core::Mode::None,
@@ -76,6 +68,8 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
     resolve: rspack_core::Resolve::default(),
     resolve_loader: rspack_core::Resolve::default(),
     builtins: Default::default(),
+    input_filesystem: Arc::new(NativeFileSystem),
+    intermediate_filesystem: Arc::new(NativeFileSystem),
     module: Default::default(),
     stats: Default::default(),
     cache: Default::default(),
@@ -92,81 +86,22 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
       concatenate_modules: Default::default(),
     },
     profile: false,
-  };
+  }
+}
 
+async fn run_swc_loader(options: SwcLoaderJsOptions, actual_path: &Path) -> Result<String> {
   let (plugin_driver, compiler_options) =
-    PluginDriver::new(compiler_options, vec![], Default::default());
+    PluginDriver::new(compiler_options(), vec![], Default::default());
 
   let (result, _) = run_loaders(
     &[Arc::new(SwcLoader::new(options)) as Arc<dyn Loader<LoaderRunnerContext>>],
-    &ResourceData::new(actual_path.to_string_lossy().to_string(), actual_path),
+    &ResourceData::new(
+      actual_path.to_string_lossy().to_string(),
+      actual_path.to_path_buf(),
+    ),
     &[],
     CompilerContext {
-      options: std::sync::Arc::new(CompilerOptions {
-        bail: false,
-        context: rspack_core::Context::default(),
-        dev_server: rspack_core::DevServerOptions::default(),
-        mode: rspack_core::Mode::None,
-        output: rspack_core::OutputOptions {
-          clean: false,
-          path: Default::default(),
-          public_path: Default::default(),
-          filename: rspack_core::Filename::from_str("").expect("TODO:"),
-          asset_module_filename: rspack_core::Filename::from_str("").expect("TODO:"),
-          wasm_loading: rspack_core::WasmLoading::Disable,
-          webassembly_module_filename: rspack_core::Filename::from_str("").expect("TODO:"),
-          chunk_filename: rspack_core::Filename::from_str("").expect("TODO:"),
-          cross_origin_loading: rspack_core::CrossOriginLoading::Disable,
-          unique_name: Default::default(),
-          chunk_loading: rspack_core::ChunkLoading::Enable(rspack_core::ChunkLoadingType::Jsonp),
-          chunk_loading_global: "webpackChunkwebpack".to_string(),
-          css_chunk_filename: rspack_core::Filename::from_str("").expect("TODO:"),
-          css_filename: rspack_core::Filename::from_str("").expect("TODO:"),
-          hot_update_chunk_filename: rspack_core::Filename::from_str("").expect("Should exist"),
-          hot_update_main_filename: rspack_core::Filename::from_str("").expect("Should exist"),
-          hot_update_global: "webpackHotUpdate".to_string(),
-          library: None,
-          enabled_library_types: None,
-          strict_module_error_handling: false,
-          global_object: "self".to_string(),
-          import_function_name: "import".to_string(),
-          iife: true,
-          module: false,
-          trusted_types: None,
-          source_map_filename: rspack_core::Filename::from_str("./a.map.js").expect("TODO:"),
-          hash_function: rspack_core::HashFunction::Xxhash64,
-          hash_digest: rspack_core::HashDigest::Hex,
-          hash_digest_length: 16,
-          hash_salt: rspack_core::HashSalt::None,
-          async_chunks: true,
-          worker_chunk_loading: rspack_core::ChunkLoading::Enable(
-            rspack_core::ChunkLoadingType::ImportScripts,
-          ),
-          worker_wasm_loading: rspack_core::WasmLoading::Disable,
-          worker_public_path: String::new(),
-          script_type: String::from("false"),
-        },
-        target: rspack_core::Target::new(&vec![String::from("web")]).expect("TODO:"),
-        resolve: rspack_core::Resolve::default(),
-        resolve_loader: rspack_core::Resolve::default(),
-        builtins: Default::default(),
-        module: Default::default(),
-        stats: Default::default(),
-        cache: Default::default(),
-        snapshot: Default::default(),
-        experiments: Default::default(),
-        node: Default::default(),
-        optimization: rspack_core::Optimization {
-          remove_available_modules: false,
-          side_effects: SideEffectOption::False,
-          provided_exports: Default::default(),
-          used_exports: Default::default(),
-          inner_graph: Default::default(),
-          mangle_exports: Default::default(),
-          concatenate_modules: Default::default(),
-        },
-        profile: false,
-      }),
+      options: Arc::new(compiler_options()),
       resolver_factory: Default::default(),
       module: "".into(),
       module_context: None,
@@ -176,10 +111,25 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
       cache: Arc::new(rspack_core::cache::Cache::new(compiler_options)),
     },
   )
-  .await
-  .expect("TODO:")
+  .await?
   .split_into_parts();
-  let result = result.content.try_into_string().expect("TODO:");
+  result.content.try_into_string()
+}
+
+// UPDATE=1 cargo test --package rspack_loader_swc -- --nocapture
+#[allow(dead_code)]
+async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
+  let tests_path = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"))).join("tests");
+  let expected_path = tests_path.join(expected);
+  let actual_path = tests_path.join(actual);
+  let plugin_path = tests_path.join("my_first_plugin.wasm");
+  let mut options = SwcLoaderJsOptions::default();
+  options.jsc.experimental.plugins = Some(vec![PluginConfig(
+    plugin_path.to_string_lossy().to_string(),
+    json!(null),
+  )]);
+
+  let result = run_swc_loader(options, &actual_path).await.expect("TODO:");
 
   if env::var("UPDATE").is_ok() {
     fs::write(expected_path, result).expect("TODO:");
@@ -194,6 +144,33 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
 //   loader_test("swc-plugin/index.js", "swc-plugin/expected/index.js").await;
 // }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn should_report_plugins_failed_to_load() {
+  let tests_path = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"))).join("tests");
+  let plugin_path = tests_path.join("not_exist_plugin.wasm");
+  let mut options = SwcLoaderJsOptions::default();
+  options.jsc.experimental.plugins = Some(vec![PluginConfig(
+    plugin_path.to_string_lossy().to_string(),
+    json!(null),
+  )]);
+
+  let error = run_swc_loader(options, &tests_path.join("swc-plugin/index.js"))
+    .await
+    .expect_err("should fail to load the plugin");
+  let message = error.to_string();
+  assert!(
+    message.contains(&format!(
+      "Failed to load SWC plugin {}",
+      plugin_path.to_string_lossy()
+    )),
+    "{message}"
+  );
+  assert!(
+    message.contains("compatible with the one used by Rspack"),
+    "{message}"
+  );
+}
+
 #[fixture("tests/fixtures/*")]
 fn swc(fixture_path: PathBuf) {
   test_fixture(&fixture_path, Box::new(|_, _| {}), None);
@@ -0,0 +1,96 @@
+#![feature(let_chains)]
+
+// Port of https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ContextReplacementPlugin.js
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use async_trait::async_trait;
+use rspack_core::{
+  ContextModuleAfterResolveArgs, NormalModuleBeforeResolveArgs, Plugin, PluginContext,
+  PluginContextModuleFactoryAfterResolveOutput, PluginNormalModuleFactoryBeforeResolveOutput,
+};
+use rspack_regex::{regexp_as_str, RspackRegex};
+use sugar_path::SugarPath;
+
+#[derive(Debug)]
+pub struct ContextReplacementPluginOptions {
+  /// Contexts whose request or directory matches are replaced
+  pub resource_reg_exp: RspackRegex,
+  /// New directory of the context, relative to the matched directory or absolute
+  pub new_content_resource: Option<String>,
+  pub new_content_recursive: Option<bool>,
+  pub new_content_reg_exp: Option<RspackRegex>,
+  /// Explicit content of the context, requests of the context mapped to the requests of their
+  /// modules. The directory of the context is not read when it's given.
+  pub new_content_create_context_map: Option<BTreeMap<String, String>>,
+}
+
+#[derive(Debug)]
+pub struct ContextReplacementPlugin {
+  options: ContextReplacementPluginOptions,
+}
+
+impl ContextReplacementPlugin {
+  pub fn new(options: ContextReplacementPluginOptions) -> Self {
+    Self { options }
+  }
+}
+
+#[async_trait]
+impl Plugin for ContextReplacementPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.ContextReplacementPlugin"
+  }
+
+  async fn context_module_before_resolve(
+    &self,
+    _ctx: PluginContext,
+    args: &mut NormalModuleBeforeResolveArgs,
+  ) -> PluginNormalModuleFactoryBeforeResolveOutput {
+    // A relative directory is resolved against the matched directory after resolve
+    if let Some(new_content_resource) = &self.options.new_content_resource
+      && !is_relative(new_content_resource)
+      && self.options.resource_reg_exp.test(&args.request)
+    {
+      args.request = new_content_resource.clone();
+    }
+    Ok(None)
+  }
+
+  async fn context_module_after_resolve(
+    &self,
+    _ctx: PluginContext,
+    args: &mut ContextModuleAfterResolveArgs,
+  ) -> PluginContextModuleFactoryAfterResolveOutput {
+    if !self.options.resource_reg_exp.test(&args.resource) {
+      return Ok(None);
+    }
+    if let Some(new_content_resource) = &self.options.new_content_resource {
+      args.resource = if Path::new(new_content_resource).is_absolute() {
+        new_content_resource.clone()
+      } else {
+        Path::new(&args.resource)
+          .join(new_content_resource)
+          .normalize()
+          .to_string_lossy()
+          .to_string()
+      };
+    }
+    if let Some(recursive) = self.options.new_content_recursive {
+      args.context_options.recursive = recursive;
+    }
+    if let Some(reg_exp) = &self.options.new_content_reg_exp {
+      args.context_options.reg_str = regexp_as_str(reg_exp).to_string();
+      args.context_options.reg_exp = Some(reg_exp.clone());
+    }
+    if let Some(context_map) = &self.options.new_content_create_context_map {
+      args.context_map = Some(context_map.clone());
+    }
+    Ok(None)
+  }
+}
+
+fn is_relative(request: &str) -> bool {
+  request == "." || request == ".." || request.starts_with("./") || request.starts_with("../")
+}
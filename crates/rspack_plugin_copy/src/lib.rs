@@ -1,7 +1,6 @@
 #![feature(let_chains)]
 use std::{
   fmt::Display,
-  fs,
   hash::Hash,
   path::{Path, PathBuf, MAIN_SEPARATOR},
   sync::{Arc, Mutex},
@@ -16,6 +15,7 @@ use rspack_core::{
   CompilationLogger, Filename, Logger, PathData, Plugin,
 };
 use rspack_error::{Diagnostic, DiagnosticError, Error, ErrorExt};
+use rspack_fs::InputFileSystem;
 use rspack_hash::{HashDigest, HashFunction, HashSalt, RspackHash, RspackHashDigest};
 use sugar_path::{AsPath, SugarPath};
 
@@ -223,9 +223,12 @@ impl CopyRspackPlugin {
     // TODO cache
 
     logger.debug(format!("reading '{}'...", absolute_filename.display()));
-    // TODO inputFileSystem
 
-    let source = match tokio::fs::read(absolute_filename.clone()).await {
+    let source = match compilation
+      .options
+      .input_filesystem
+      .read(&absolute_filename)
+    {
       Ok(data) => {
         logger.debug(format!("read '{}'...", absolute_filename.display()));
 
@@ -315,14 +318,14 @@ impl CopyRspackPlugin {
 
     logger.debug(format!("getting stats for '{}'...", abs_from.display()));
 
-    let from_type = if let Ok(meta) = fs::metadata(&abs_from) {
-      if meta.is_dir() {
+    let from_type = if let Ok(meta) = compilation.options.input_filesystem.metadata(&abs_from) {
+      if meta.is_directory {
         logger.debug(format!(
           "determined '{}' is a directory",
           abs_from.display()
         ));
         FromType::Dir
-      } else if meta.is_file() {
+      } else if meta.is_file {
         logger.debug(format!("determined '{}' is a file", abs_from.display()));
         FromType::File
       } else {
@@ -388,7 +391,8 @@ impl CopyRspackPlugin {
 
     logger.log(format!("begin globbing '{glob_query}'..."));
 
-    let glob_entries = glob::glob_with(
+    let glob_entries = glob_files(
+      compilation.options.input_filesystem.as_ref(),
       &glob_query,
       MatchOptions {
         case_sensitive: pattern.glob_options.case_sensitive_match.unwrap_or(true),
@@ -400,9 +404,8 @@ impl CopyRspackPlugin {
     match glob_entries {
       Ok(entries) => {
         let entries: Vec<_> = entries
+          .into_iter()
           .filter_map(|entry| {
-            let entry = entry.ok()?;
-
             let filters = pattern.glob_options.ignore.as_ref();
 
             if let Some(filters) = filters {
@@ -635,6 +638,46 @@ fn escape_glob_chars(s: &str) -> String {
   escaped
 }
 
+/// Match a glob against the files of the input file system.
+///
+/// Directories are walked from the longest leading path of the glob without any special
+/// character, only files are returned.
+fn glob_files(
+  fs: &dyn InputFileSystem,
+  glob_query: &str,
+  options: MatchOptions,
+) -> Result<Vec<PathBuf>, glob::PatternError> {
+  let pattern = GlobPattern::new(glob_query)?;
+  let base = Path::new(glob_query)
+    .components()
+    .take_while(|component| {
+      !component
+        .as_os_str()
+        .to_string_lossy()
+        .contains(['*', '?', '[', ']', '\\'])
+    })
+    .collect::<PathBuf>();
+
+  let mut files = vec![];
+  let mut pending = vec![base];
+  while let Some(path) = pending.pop() {
+    let Ok(metadata) = fs.metadata(&path) else {
+      continue;
+    };
+    if metadata.is_file {
+      if pattern.matches_path_with(&path, options) {
+        files.push(path);
+      }
+    } else if metadata.is_directory
+      && let Ok(names) = fs.read_dir(&path)
+    {
+      pending.extend(names.into_iter().map(|name| path.join(name)));
+    }
+  }
+  files.sort();
+  Ok(files)
+}
+
 fn set_info(target: &mut AssetInfo, info: Info) {
   if let Some(minimized) = info.minimized {
     target.minimized = minimized;
@@ -3,7 +3,7 @@ use rspack_core::{
   DependencyType, ErrorSpan, ModuleDependency,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CssComposeDependency {
   id: DependencyId,
   request: String,
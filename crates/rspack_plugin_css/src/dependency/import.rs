@@ -3,25 +3,81 @@ use rspack_core::{
   DependencyType, ErrorSpan, ModuleDependency, TemplateContext, TemplateReplaceSource,
 };
 
-#[derive(Debug, Clone)]
+/// Conditions of `@import "x.css" layer(base) supports(display: grid) print;`, the imported
+/// module is wrapped in the matching `@layer`, `@supports` and `@media` blocks when rendered.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct CssImportConditions {
+  /// `Some("")` for an anonymous layer, i.e. `@import "x.css" layer;`
+  pub layer: Option<String>,
+  pub supports: Option<String>,
+  pub media: Option<String>,
+}
+
+impl CssImportConditions {
+  pub fn is_empty(&self) -> bool {
+    self.layer.is_none() && self.supports.is_none() && self.media.is_none()
+  }
+
+  /// The block openings from the outermost to the innermost, `@layer` contains `@supports`
+  /// contains `@media`.
+  pub fn render_start(&self) -> String {
+    let mut start = String::new();
+    if let Some(layer) = &self.layer {
+      if layer.is_empty() {
+        start.push_str("@layer {\n");
+      } else {
+        start.push_str(&format!("@layer {layer} {{\n"));
+      }
+    }
+    if let Some(supports) = &self.supports {
+      start.push_str(&format!("@supports ({supports}) {{\n"));
+    }
+    if let Some(media) = &self.media {
+      start.push_str(&format!("@media {media} {{\n"));
+    }
+    start
+  }
+
+  pub fn render_end(&self) -> String {
+    let count = [&self.layer, &self.supports, &self.media]
+      .into_iter()
+      .filter(|c| c.is_some())
+      .count();
+    "}\n".repeat(count)
+  }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CssImportDependency {
   id: DependencyId,
   request: String,
+  conditions: CssImportConditions,
   span: Option<ErrorSpan>,
   start: u32,
   end: u32,
 }
 
 impl CssImportDependency {
-  pub fn new(request: String, span: Option<ErrorSpan>, start: u32, end: u32) -> Self {
+  pub fn new(
+    request: String,
+    conditions: CssImportConditions,
+    span: Option<ErrorSpan>,
+    start: u32,
+    end: u32,
+  ) -> Self {
     Self {
       id: DependencyId::new(),
       request,
+      conditions,
       span,
       start,
       end,
     }
   }
+
+  pub fn conditions(&self) -> &CssImportConditions {
+    &self.conditions
+  }
 }
 
 impl Dependency for CssImportDependency {
@@ -4,4 +4,19 @@ mod url;
 
 pub use compose::*;
 pub use import::*;
+use rspack_core::{
+  register_serializable_dependency, register_serializable_dependency_template,
+  register_serializable_module_dependency,
+};
 pub use url::*;
+
+/// Registers the dependencies created by the css parser, so modules using them can be persisted
+/// by the filesystem cache.
+pub(crate) fn register_serializable_dependencies() {
+  register_serializable_dependency::<CssComposeDependency>();
+  register_serializable_dependency::<CssImportDependency>();
+  register_serializable_dependency_template::<CssImportDependency>();
+  // `url()` is resolved as a code generation dependency
+  register_serializable_module_dependency::<CssUrlDependency>();
+  register_serializable_dependency_template::<CssUrlDependency>();
+}
@@ -8,7 +8,7 @@ use rspack_core::{
 
 use crate::utils::AUTO_PUBLIC_PATH_PLACEHOLDER;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CssUrlDependency {
   id: DependencyId,
   request: String,
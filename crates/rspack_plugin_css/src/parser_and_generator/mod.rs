@@ -4,6 +4,7 @@ use std::collections::HashMap;
 
 use indexmap::IndexMap;
 use once_cell::sync::Lazy;
+use preset_env_base::Versions;
 use regex::Regex;
 use rkyv::{from_bytes, to_bytes, AlignedVec};
 use rspack_core::{
@@ -49,6 +50,8 @@ type CssExportsType = Option<IndexMap<Vec<String>, Vec<(String, Option<String>)>
 #[derive(Debug)]
 pub struct CssParserAndGenerator {
   pub config: CssConfig,
+  /// Browser versions resolved from `config.targets`
+  pub targets: Option<Versions>,
   pub exports: CssExportsType,
 }
 
@@ -105,7 +108,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
     let mut source_map = None;
     let mut diagnostic_vec = vec![];
 
-    if is_enable_css_modules {
+    if is_enable_css_modules || self.targets.is_some() {
       let mut stylesheet = swc_compiler.parse_file(
         &resource_path.to_string_lossy(),
         source_code,
@@ -116,30 +119,37 @@ impl ParserAndGenerator for CssParserAndGenerator {
         },
       )?;
 
-      let result = swc_core::css::modules::compile(
-        &mut stylesheet,
-        ModulesTransformConfig::new(
-          &resource_data
-            .resource_path
-            .relative(&compiler_options.context),
-          &self.config.modules.local_ident_name,
-          &compiler_options.output,
-        ),
-      );
-      let mut exports: IndexMap<Atom, _> = result.renamed.into_iter().collect();
-      exports.sort_keys();
+      if is_enable_css_modules {
+        let result = swc_core::css::modules::compile(
+          &mut stylesheet,
+          ModulesTransformConfig::new(
+            &resource_data
+              .resource_path
+              .relative(&compiler_options.context),
+            &self.config.modules.local_ident_name,
+            &compiler_options.output,
+          ),
+        );
+        let mut exports: IndexMap<Atom, _> = result.renamed.into_iter().collect();
+        exports.sort_keys();
 
-      self.exports = Some(IndexMap::from_iter(
-        exports
-          .iter()
-          .map(|(name, elements)| {
-            let mut names = export_locals_convention(name, &self.config.modules.locals_convention);
-            names.sort_unstable();
-            names.dedup();
-            (names, stringify_css_modules_exports_elements(elements))
-          })
-          .collect::<Vec<_>>(),
-      ));
+        self.exports = Some(IndexMap::from_iter(
+          exports
+            .iter()
+            .map(|(name, elements)| {
+              let mut names =
+                export_locals_convention(name, &self.config.modules.locals_convention);
+              names.sort_unstable();
+              names.dedup();
+              (names, stringify_css_modules_exports_elements(elements))
+            })
+            .collect::<Vec<_>>(),
+        ));
+      }
+
+      if let Some(targets) = &self.targets {
+        swc_compiler.transform_for_targets(&mut stylesheet, targets);
+      }
 
       let (code, map) = swc_compiler.codegen(
         &stylesheet,
@@ -161,6 +171,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
 
     let mut dependencies = analyze_dependencies(
       &new_stylesheet_ast,
+      &source_code,
       code_generation_dependencies,
       &mut diagnostic_vec,
     );
@@ -19,8 +19,10 @@ use rspack_error::{IntoTWithDiagnosticArray, Result};
 use rspack_hash::RspackHash;
 use rspack_plugin_runtime::is_enabled_for_chunk;
 
+use crate::dependency::register_serializable_dependencies;
 use crate::parser_and_generator::CssParserAndGenerator;
 use crate::runtime::CssLoadingRuntimeModule;
+use crate::swc_css_compiler::resolve_targets;
 use crate::utils::AUTO_PUBLIC_PATH_PLACEHOLDER_REGEX;
 use crate::CssPlugin;
 
@@ -42,11 +44,13 @@ impl CssPlugin {
           .code_generation_results
           .get(module_id, Some(&chunk.runtime));
 
-        Ok(
-          code_gen_result
-            .get(&SourceType::Css)
-            .map(|source| (CssModuleDebugInfo { module: *module }, source)),
-        )
+        Ok(code_gen_result.get(&SourceType::Css).map(|source| {
+          (
+            CssModuleDebugInfo { module: *module },
+            Self::get_import_conditions(&compilation.module_graph, module_id),
+            source,
+          )
+        }))
       })
       .collect::<Result<Vec<_>>>()?;
 
@@ -58,11 +62,17 @@ impl CssPlugin {
       .flatten()
       .fold(
         ConcatSource::default,
-        |mut acc, (debug_info, cur_source)| {
+        |mut acc, (debug_info, conditions, cur_source)| {
           let (start, end) = Self::render_module_debug_info(compilation, &debug_info);
           acc.add(start);
+          for conditions in conditions.iter().rev() {
+            acc.add(RawSource::from(conditions.render_start()));
+          }
           acc.add(cur_source.clone());
           acc.add(RawSource::from("\n"));
+          for conditions in &conditions {
+            acc.add(RawSource::from(conditions.render_end()));
+          }
           acc.add(end);
           acc
         },
@@ -123,9 +133,13 @@ impl Plugin for CssPlugin {
     _options: &mut CompilerOptions,
   ) -> Result<()> {
     let config = self.config.clone();
+    let targets = (!config.targets.is_empty())
+      .then(|| resolve_targets(&config.targets))
+      .transpose()?;
     let builder = move || {
       Box::new(CssParserAndGenerator {
         config: config.clone(),
+        targets,
         exports: None,
       }) as Box<dyn ParserAndGenerator>
     };
@@ -139,6 +153,7 @@ impl Plugin for CssPlugin {
     ctx
       .context
       .register_parser_and_generator_builder(ModuleType::CssAuto, Box::new(builder));
+    register_serializable_dependencies();
 
     Ok(())
   }
@@ -185,12 +200,14 @@ impl Plugin for CssPlugin {
             .code_generation_results
             .get_hash(&m.identifier(), Some(&chunk.runtime)),
           compilation.chunk_graph.get_module_id(m.identifier()),
+          Self::get_import_conditions(&compilation.module_graph, &m.identifier()),
         )
       })
-      .for_each(|(current, id)| {
+      .for_each(|(current, id, conditions)| {
         if let Some(current) = current {
           current.hash(&mut hasher);
           id.hash(&mut hasher);
+          conditions.hash(&mut hasher);
         }
       });
 
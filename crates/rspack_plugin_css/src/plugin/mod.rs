@@ -8,10 +8,14 @@ use bitflags::bitflags;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::Filename;
-use rspack_core::{Chunk, ChunkGraph, Compilation, Module, ModuleGraph, PathData, SourceType};
+use rspack_core::{
+  Chunk, ChunkGraph, Compilation, Module, ModuleGraph, ModuleIdentifier, PathData, SourceType,
+};
 use rspack_error::error_bail;
 use rspack_identifier::IdentifierSet;
 
+use crate::dependency::{CssImportConditions, CssImportDependency};
+
 static ESCAPE_LOCAL_IDENT_REGEX: Lazy<Regex> =
   Lazy::new(|| Regex::new(r#"[<>:"/\\|?*\.]"#).expect("Invalid regex"));
 
@@ -101,6 +105,9 @@ impl Default for LocalsConvention {
 #[derive(Debug, Clone)]
 pub struct CssConfig {
   pub modules: ModulesConfig,
+  /// Browserslist queries, css is lowered and prefixed for them. Nothing is transformed when
+  /// it's empty.
+  pub targets: Vec<String>,
 }
 
 impl CssPlugin {
@@ -262,6 +269,55 @@ impl CssPlugin {
     }
     final_modules
   }
+
+  /// Conditions of the `@import`s leading to the module, from the innermost to the outermost.
+  ///
+  /// Conditions are only applied when every `@import` of the module has the same conditions,
+  /// a module imported with different conditions, or imported from js, is rendered as is.
+  pub(crate) fn get_import_conditions(
+    module_graph: &ModuleGraph,
+    identifier: &ModuleIdentifier,
+  ) -> Vec<CssImportConditions> {
+    let mut result = Vec::new();
+    let mut visited = IdentifierSet::default();
+    let mut current = *identifier;
+    while visited.insert(current)
+      && let Some(module) = module_graph.module_by_identifier(&current)
+    {
+      let mut conditions: Option<&CssImportConditions> = None;
+      let mut importers = IdentifierSet::default();
+      for connection in module_graph.get_incoming_connections(module) {
+        let Some(dep) = module_graph
+          .dependency_by_id(&connection.dependency_id)
+          .and_then(|dep| dep.downcast_ref::<CssImportDependency>())
+        else {
+          return result;
+        };
+        if conditions.is_some_and(|conditions| conditions != dep.conditions()) {
+          return result;
+        }
+        conditions = Some(dep.conditions());
+        if let Some(importer) = connection.original_module_identifier {
+          importers.insert(importer);
+        }
+      }
+      let Some(conditions) = conditions else {
+        break;
+      };
+      if !conditions.is_empty() {
+        result.push(conditions.clone());
+      }
+      // the conditions of the importers only apply when they are imported the same way
+      if importers.len() != 1 {
+        break;
+      }
+      current = importers
+        .into_iter()
+        .next()
+        .expect("should have one importer");
+    }
+    result
+  }
 }
 
 #[derive(Debug)]
@@ -1,5 +1,12 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use once_cell::sync::Lazy;
+use preset_env_base::{
+  query::{targets_to_versions, Query, Targets},
+  version::should_enable,
+  BrowserData, Versions,
+};
 use rspack_core::rspack_sources::{self, SourceExt};
 use rspack_error::{error, Result};
 use swc_core::common::{input::SourceFileInput, source_map::SourceMapGenConfig, FileName};
@@ -8,8 +15,14 @@ use swc_core::css::codegen::{
   writer::basic::{BasicCssWriter, BasicCssWriterConfig},
   CodeGenerator, CodegenConfig, Emit,
 };
+use swc_core::css::compat::{
+  compiler::{Compiler, Config},
+  feature::Features,
+};
 use swc_core::css::minifier;
 use swc_core::css::parser::{lexer::Lexer, parser::ParserConfig};
+use swc_core::css::prefixer::{options::Options, prefixer};
+use swc_core::css::visit::VisitMutWith;
 use swc_core::css::{ast::Stylesheet, parser::parser::Parser};
 
 #[derive(Default)]
@@ -29,6 +42,19 @@ impl SwcCssCompiler {
     stylesheet.map_err(|e| error!("Css parsing failed {}", e.message()))
   }
 
+  /// Lower the syntax the targets don't support and add the vendor prefixes they need.
+  pub fn transform_for_targets(&self, ast: &mut Stylesheet, versions: &Versions) {
+    GLOBALS.set(&Globals::default(), || {
+      let process = unsupported_features(versions);
+      if !process.is_empty() {
+        ast.visit_mut_with(&mut Compiler::new(Config { process }));
+      }
+      ast.visit_mut_with(&mut prefixer(Options {
+        env: Some(*versions),
+      }));
+    });
+  }
+
   pub fn codegen(
     &self,
     ast: &Stylesheet,
@@ -124,3 +150,52 @@ impl SourceMapGenConfig for SwcCssSourceMapGenConfig {
     self.emit_columns
   }
 }
+
+/// Resolve browserslist queries to the minimum versions of the targeted browsers.
+pub fn resolve_targets(targets: &[String]) -> Result<Versions> {
+  targets_to_versions(Some(Targets::Query(Query::Multiple(targets.to_vec()))))
+    .map_err(|e| error!("Failed to resolve css targets {targets:?}: {e}"))
+}
+
+/// Browser versions that support the features natively, in the format of
+/// `@babel/compat-data`, a browser is missing if it doesn't support the feature yet.
+/// The versions come from `@mdn/browser-compat-data`.
+static FEATURE_SUPPORT: Lazy<Vec<(Features, Versions)>> = Lazy::new(|| {
+  let data: HashMap<String, BrowserData<Option<String>>> =
+    serde_json::from_str(include_str!("../data/compat.json")).expect("failed to parse json");
+  data
+    .into_iter()
+    .map(|(name, versions)| {
+      let feature = match name.as_str() {
+        "nesting" => Features::NESTING,
+        "custom-media" => Features::CUSTOM_MEDIA,
+        "media-query-ranges" => Features::MEDIA_QUERY_RANGES,
+        "selector-not" => Features::SELECTOR_NOT,
+        "color-hex-alpha" => Features::COLOR_HEX_ALPHA,
+        "color-hwb" => Features::COLOR_HWB,
+        "color-functional-notation" => {
+          Features::COLOR_SPACE_SEPARATED_PARAMETERS
+            | Features::COLOR_ALPHA_PARAMETER
+            | Features::COLOR_LEGACY_RGB_AND_HSL
+        }
+        _ => panic!("unknown css feature `{name}` in compat data"),
+      };
+      let versions = versions.map_value(|version| {
+        version.map(|v| {
+          v.parse()
+            .unwrap_or_else(|err| panic!("failed to parse `{v}` of {name}: {err:?}"))
+        })
+      });
+      (feature, versions)
+    })
+    .collect()
+});
+
+fn unsupported_features(versions: &Versions) -> Features {
+  FEATURE_SUPPORT
+    .iter()
+    .filter(|(_, support)| should_enable(*versions, *support, false))
+    .fold(Features::empty(), |features, (feature, _)| {
+      features | *feature
+    })
+}
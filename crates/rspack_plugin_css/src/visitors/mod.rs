@@ -5,13 +5,13 @@ use rspack_error::miette::{diagnostic, Diagnostic, Severity};
 use rspack_error::DiagnosticExt;
 use swc_core::common::Span;
 use swc_core::css::ast::{
-  AtRule, AtRuleName, Function, ImportHref, ImportPrelude, Stylesheet, Token, TokenAndSpan, Url,
-  UrlValue,
+  AtRule, AtRuleName, Function, ImportHref, ImportLayerName, ImportPrelude, Stylesheet, Token,
+  TokenAndSpan, Url, UrlValue,
 };
 use swc_core::css::visit::{Visit, VisitWith};
 
 use crate::{
-  dependency::{CssImportDependency, CssUrlDependency},
+  dependency::{CssImportConditions, CssImportDependency, CssUrlDependency},
   utils::normalize_url,
 };
 
@@ -19,11 +19,13 @@ static IS_MODULE_REQUEST: Lazy<Regex> = Lazy::new(|| Regex::new(r"^~").expect("T
 
 pub fn analyze_dependencies(
   ss: &Stylesheet,
+  source: &str,
   code_generation_dependencies: &mut Vec<Box<dyn ModuleDependency>>,
   diagnostics: &mut Vec<Box<dyn Diagnostic + Send + Sync>>,
 ) -> Vec<BoxDependency> {
   let mut v = Analyzer {
     deps: Vec::new(),
+    source,
     code_generation_dependencies,
     diagnostics,
     nearest_at_import_span: None,
@@ -38,6 +40,7 @@ pub fn analyze_dependencies(
 #[derive(Debug)]
 struct Analyzer<'a> {
   deps: Vec<BoxDependency>,
+  source: &'a str,
   code_generation_dependencies: &'a mut Vec<Box<dyn ModuleDependency>>,
   diagnostics: &'a mut Vec<Box<dyn Diagnostic + Send + Sync>>,
   nearest_at_import_span: Option<Span>,
@@ -79,6 +82,49 @@ impl Analyzer<'_> {
     self.deps.push(dep.clone());
     self.code_generation_dependencies.push(dep);
   }
+
+  fn source_of(&self, span: Span) -> &str {
+    self
+      .source
+      .get(span.real_lo() as usize..span.real_hi() as usize)
+      .unwrap_or_default()
+      .trim()
+  }
+
+  /// Arguments of a function like `supports(display: grid)` as written in the source.
+  fn function_arguments(&self, f: &Function) -> String {
+    let text = self.source_of(f.span);
+    text
+      .split_once('(')
+      .map(|(_, args)| args.strip_suffix(')').unwrap_or(args).trim().to_string())
+      .unwrap_or_default()
+  }
+
+  fn import_conditions(&self, n: &ImportPrelude) -> CssImportConditions {
+    let layer = n.layer_name.as_ref().map(|box layer| match layer {
+      ImportLayerName::Ident(_) => String::new(),
+      ImportLayerName::Function(f) => self.function_arguments(f),
+    });
+    let (supports, media) = match &n.import_conditions {
+      Some(box conditions) => (
+        conditions
+          .supports
+          .as_ref()
+          .map(|box f| self.function_arguments(f)),
+        conditions
+          .media
+          .as_ref()
+          .map(|box media| self.source_of(media.span).to_string())
+          .filter(|media| !media.is_empty()),
+      ),
+      None => (None, None),
+    };
+    CssImportConditions {
+      layer,
+      supports,
+      media,
+    }
+  }
 }
 
 impl Visit for Analyzer<'_> {
@@ -105,8 +151,10 @@ impl Visit for Analyzer<'_> {
     };
     if let Some(specifier) = specifier {
       let specifier = replace_module_request_prefix(specifier, self.diagnostics);
+      let conditions = self.import_conditions(n);
       self.deps.push(Box::new(CssImportDependency::new(
         specifier,
+        conditions,
         Some(span.into()),
         span.real_lo(),
         span.real_hi(),
@@ -22,7 +22,7 @@ use rspack_core::{
 use rspack_core::{
   Chunk, Filename, Logger, Module, ModuleIdentifier, OutputOptions, RuntimeModule,
 };
-use rspack_error::{miette::IntoDiagnostic, Result};
+use rspack_error::{error, miette::IntoDiagnostic, Result};
 use rspack_hash::RspackHash;
 use rspack_util::identifier::make_paths_absolute;
 use rspack_util::source_map::SourceMapKind;
@@ -108,6 +108,9 @@ pub struct SourceMapDevToolPluginOptions {
   // Include or exclude source maps for modules based on their extension (defaults to .js and .css).
   #[derivative(Debug = "ignore")]
   pub test: Option<TestFn>,
+  // Sources matching this are listed in the 'ignoreList' of the SourceMap, so they are ignored by the debugger.
+  #[derivative(Debug = "ignore")]
+  pub ignore_list: Option<TestFn>,
 }
 
 enum SourceMappingUrlComment {
@@ -138,6 +141,8 @@ pub struct SourceMapDevToolPlugin {
   module: bool,
   #[derivative(Debug = "ignore")]
   test: Option<TestFn>,
+  #[derivative(Debug = "ignore")]
+  ignore_list: Option<TestFn>,
 }
 
 impl SourceMapDevToolPlugin {
@@ -179,6 +184,7 @@ impl SourceMapDevToolPlugin {
       public_path: options.public_path,
       module: options.module,
       test: options.test,
+      ignore_list: options.ignore_list,
     }
   }
 }
@@ -344,7 +350,11 @@ impl Plugin for SourceMapDevToolPlugin {
           source_map
             .to_writer(&mut source_map_buffer)
             .unwrap_or_else(|e| panic!("{}", e.to_string()));
-          Some(source_map_buffer)
+          Some(with_ignore_list(
+            self.ignore_list.as_ref(),
+            &source_map,
+            source_map_buffer,
+          )?)
         }
         None => None,
       };
@@ -473,6 +483,34 @@ impl Plugin for SourceMapDevToolPlugin {
   }
 }
 
+/// Lists the sources matching `ignore_list` in the ignore list of the map. `SourceMap` can't hold
+/// an ignore list, so it's patched into the serialized map. Both the standard `ignoreList` and the
+/// `x_google_ignoreList` read by older versions of Chrome are written.
+fn with_ignore_list(
+  ignore_list: Option<&TestFn>,
+  source_map: &SourceMap,
+  source_map_buffer: Vec<u8>,
+) -> Result<Vec<u8>> {
+  let Some(ignore_list) = ignore_list else {
+    return Ok(source_map_buffer);
+  };
+  let ignored = source_map
+    .sources()
+    .iter()
+    .enumerate()
+    .filter(|(_, source)| ignore_list(source.to_string()))
+    .map(|(index, _)| index)
+    .collect::<Vec<_>>();
+  if ignored.is_empty() {
+    return Ok(source_map_buffer);
+  }
+  let mut map: serde_json::Map<String, serde_json::Value> =
+    serde_json::from_slice(&source_map_buffer).into_diagnostic()?;
+  map.insert("ignoreList".to_string(), json!(ignored));
+  map.insert("x_google_ignoreList".to_string(), json!(ignored));
+  serde_json::to_vec(&map).into_diagnostic()
+}
+
 fn get_before(s: &str, token: &str) -> String {
   match s.rfind(token) {
     Some(idx) => s[..idx].to_string(),
@@ -747,6 +785,7 @@ impl SourceMapDevToolPlugin {
             "absoluteresourcepath" |
             "absresourcepath" => Cow::from(&ctx.absolute_resource_path),
 
+            "loaders" => Cow::from(&ctx.loaders),
             "all-loaders" | "allloaders" => Cow::from(&ctx.all_loaders),
 
             "query" => Cow::from(&ctx.query),
@@ -768,10 +807,13 @@ impl SourceMapDevToolPlugin {
 
 static MODULE_RENDER_CACHE: Lazy<DashMap<BoxSource, BoxSource>> = Lazy::new(DashMap::default);
 
-#[derive(Debug)]
+#[derive(Derivative)]
+#[derivative(Debug)]
 pub struct EvalSourceMapDevToolPlugin {
   columns: bool,
   no_sources: bool,
+  #[derivative(Debug = "ignore")]
+  ignore_list: Option<TestFn>,
 }
 
 impl EvalSourceMapDevToolPlugin {
@@ -779,6 +821,7 @@ impl EvalSourceMapDevToolPlugin {
     Self {
       columns: options.columns,
       no_sources: options.no_sources,
+      ignore_list: options.ignore_list,
     }
   }
 
@@ -802,6 +845,7 @@ impl EvalSourceMapDevToolPlugin {
     map
       .to_writer(&mut map_buffer)
       .unwrap_or_else(|e| panic!("{}", e.to_string()));
+    let map_buffer = with_ignore_list(self.ignore_list.as_ref(), &map, map_buffer)?;
     let base64 = rspack_base64::encode_to_string(&map_buffer);
     let footer =
       format!("\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,{base64}");
@@ -844,6 +888,182 @@ impl Plugin for EvalSourceMapDevToolPlugin {
   }
 }
 
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct EvalDevToolModulePluginOptions {
+  // Namespace prefix to allow multiple webpack roots in the devtools.
+  pub namespace: Option<String>,
+  // Generator string or function to create the URL of modules in the sourceURL comment.
+  #[derivative(Debug = "ignore")]
+  pub module_filename_template: Option<ModuleFilenameTemplate>,
+  // Appended to the code of modules. [url] is replaced with the URL of the module.
+  pub source_url_comment: Option<String>,
+}
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct EvalDevToolModulePlugin {
+  // Creates the filenames of modules the same way as the sources of source maps, only the
+  // namespace of it is set.
+  source_map_dev_tool: SourceMapDevToolPlugin,
+  #[derivative(Debug = "ignore")]
+  module_filename_template: ModuleFilenameTemplate,
+  source_url_comment: String,
+  // Cleared for every compilation, the ids and filenames of modules may change between them.
+  #[derivative(Debug = "ignore")]
+  cache: DashMap<(ModuleIdentifier, BoxSource), BoxSource>,
+  // Filenames created by a function `module_filename_template`, the function is async so they
+  // are created before the modules are rendered.
+  #[derivative(Debug = "ignore")]
+  fn_filenames: DashMap<ModuleIdentifier, String>,
+}
+
+impl EvalDevToolModulePlugin {
+  pub fn new(options: EvalDevToolModulePluginOptions) -> Self {
+    Self {
+      source_map_dev_tool: SourceMapDevToolPlugin::new(SourceMapDevToolPluginOptions {
+        append: None,
+        columns: false,
+        fallback_module_filename_template: None,
+        file_context: None,
+        filename: None,
+        module: false,
+        module_filename_template: None,
+        namespace: options.namespace,
+        no_sources: false,
+        public_path: None,
+        source_root: None,
+        test: None,
+        ignore_list: None,
+      }),
+      module_filename_template: options.module_filename_template.unwrap_or(
+        ModuleFilenameTemplate::String(
+          "webpack://[namespace]/[resourcePath]?[loaders]".to_string(),
+        ),
+      ),
+      source_url_comment: options
+        .source_url_comment
+        .unwrap_or("\n//# sourceURL=[url]".to_string()),
+      cache: Default::default(),
+      fn_filenames: Default::default(),
+    }
+  }
+
+  fn wrap_eval(
+    &self,
+    source: &str,
+    module_identifier: ModuleIdentifier,
+    compilation: &Compilation,
+  ) -> Result<BoxSource> {
+    let module_or_source = ModuleOrSource::Module(module_identifier);
+    let output_options = &compilation.options.output;
+    let filename = match &self.module_filename_template {
+      ModuleFilenameTemplate::String(s) => self
+        .source_map_dev_tool
+        .create_filename_of_string_template(&module_or_source, compilation, s, output_options),
+      ModuleFilenameTemplate::Fn(_) => self
+        .fn_filenames
+        .get(&module_identifier)
+        .map(|filename| filename.value().clone())
+        .ok_or_else(|| {
+          error!("Filename of module {module_identifier} should be created before rendering")
+        })?,
+    };
+    let footer = self
+      .source_url_comment
+      .replace("[url]", &encode_source_url(&filename));
+    Ok(RawSource::from(format!("eval({});", json!(format!("{source}{footer}")))).boxed())
+  }
+}
+
+/// Same as `encodeURI`, except that spaces are replaced by `_` and a leading `/` is removed, like
+/// the sourceURL of webpack.
+fn encode_source_url(url: &str) -> String {
+  let mut encoded = String::with_capacity(url.len());
+  for c in url.chars() {
+    match c {
+      'A'..='Z' | 'a'..='z' | '0'..='9' => encoded.push(c),
+      ';' | ',' | '/' | '?' | ':' | '@' | '&' | '=' | '+' | '$' | '-' | '_' | '.' | '!' | '~'
+      | '*' | '\'' | '(' | ')' | '#' | '^' | '\\' => encoded.push(c),
+      ' ' => encoded.push('_'),
+      _ => {
+        let mut buf = [0; 4];
+        for byte in c.encode_utf8(&mut buf).bytes() {
+          encoded.push_str(&format!("%{byte:02X}"));
+        }
+      }
+    }
+  }
+  match encoded.strip_prefix('/') {
+    Some(stripped) => stripped.to_string(),
+    None => encoded,
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for EvalDevToolModulePlugin {
+  fn name(&self) -> &'static str {
+    "rspack.EvalDevToolModulePlugin"
+  }
+
+  async fn optimize_code_generation(&self, compilation: &mut Compilation) -> Result<Option<()>> {
+    self.cache.clear();
+    self.fn_filenames.clear();
+    let ModuleFilenameTemplate::Fn(f) = &self.module_filename_template else {
+      return Ok(None);
+    };
+    let compilation = &*compilation;
+    let output_options = &compilation.options.output;
+    let module_identifiers = compilation
+      .module_graph
+      .modules()
+      .values()
+      .filter(|module| module.as_external_module().is_none())
+      .map(|module| module.identifier())
+      .collect::<Vec<_>>();
+    let filenames = join_all(module_identifiers.iter().map(|identifier| async move {
+      let module_or_source = ModuleOrSource::Module(*identifier);
+      self
+        .source_map_dev_tool
+        .create_filename_of_fn_template(&module_or_source, compilation, f, output_options)
+        .await
+    }))
+    .await;
+    for (identifier, filename) in module_identifiers.into_iter().zip(filenames) {
+      self.fn_filenames.insert(identifier, filename?);
+    }
+    Ok(None)
+  }
+
+  fn render_module_content<'a>(
+    &'a self,
+    _ctx: PluginContext,
+    mut args: RenderModuleContentArgs<'a>,
+  ) -> PluginRenderModuleContentOutput<'a> {
+    if args.module.as_external_module().is_some() {
+      return Ok(args);
+    }
+    let key = (args.module.identifier(), args.module_source.clone());
+    if let Some(cached) = self.cache.get(&key) {
+      args.module_source = cached.value().clone();
+      return Ok(args);
+    }
+    let source = self.wrap_eval(&key.1.source(), key.0, args.compilation)?;
+    self.cache.insert(key, source.clone());
+    args.module_source = source;
+    Ok(args)
+  }
+
+  fn js_chunk_hash(
+    &self,
+    _ctx: PluginContext,
+    args: &mut JsChunkHashArgs,
+  ) -> PluginJsChunkHashHookOutput {
+    self.name().hash(&mut args.hasher);
+    Ok(())
+  }
+}
+
 pub struct SourceMapDevToolModuleOptionsPluginOptions {
   pub module: bool,
 }
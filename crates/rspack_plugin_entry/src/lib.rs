@@ -50,6 +50,7 @@ impl Plugin for EntryPlugin {
     let dependency: BoxDependency = Box::new(EntryDependency::new(
       self.entry_request.clone(),
       self.context.clone(),
+      self.options.layer.clone(),
     ));
     let dependency_id = *dependency.id();
     compilation.add_entry(dependency, self.options.clone())?;
@@ -9,7 +9,7 @@ use rspack_core::{
   rspack_sources::{RawSource, SourceExt},
   AdditionalChunkRuntimeRequirementsArgs, ApplyContext, AssetInfo, Chunk, ChunkKind,
   CompilationArgs, CompilationAsset, CompilationParams, CompilationRecords, CompilerOptions,
-  DependencyType, ModuleIdentifier, PathData, Plugin,
+  DependencyType, HotRecords, ModuleIdentifier, PathData, Plugin,
   PluginAdditionalChunkRuntimeRequirementsOutput, PluginCompilationHookOutput, PluginContext,
   PluginProcessAssetsOutput, ProcessAssetsArgs, RenderManifestArgs, RuntimeGlobals,
   RuntimeModuleExt, RuntimeSpec, SourceType,
@@ -89,6 +89,13 @@ impl Plugin for HotModuleReplacementPlugin {
     args: ProcessAssetsArgs<'_>,
   ) -> PluginProcessAssetsOutput {
     let compilation = args.compilation;
+    if compilation.persisted_records.is_some() {
+      let hot = HotRecords::new(compilation);
+      if let Some(records) = &mut compilation.persisted_records {
+        records.hot = hot;
+      }
+    }
+
     let Some(CompilationRecords {
       old_chunks,
       all_old_runtime,
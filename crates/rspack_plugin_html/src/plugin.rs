@@ -1,6 +1,5 @@
 use std::{
   collections::hash_map::DefaultHasher,
-  fs,
   hash::{Hash, Hasher},
   path::{Path, PathBuf},
 };
@@ -75,7 +74,12 @@ impl Plugin for HtmlRspackPlugin {
         AsRef::<Path>::as_ref(&compilation.options.context).join(template.as_str()),
       );
 
-      let content = fs::read_to_string(&resolved_template)
+      let content = compilation
+        .options
+        .input_filesystem
+        .read(&resolved_template)
+        .map_err(anyhow::Error::from)
+        .and_then(|content| String::from_utf8(content).map_err(anyhow::Error::from))
         .context(format!(
           "failed to read `{}` from `{}`",
           resolved_template.display(),
@@ -204,7 +208,10 @@ impl Plugin for HtmlRspackPlugin {
       let favicon_file_path = PathBuf::from(config.get_relative_path(compilation, favicon));
 
       let resolved_favicon = AsRef::<Path>::as_ref(&compilation.options.context).join(url.path());
-      let content = fs::read(resolved_favicon)
+      let content = compilation
+        .options
+        .input_filesystem
+        .read(&resolved_favicon)
         .context(format!(
           "failed to read `{}` from `{}`",
           url.path(),
@@ -0,0 +1,93 @@
+// Port of https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/IgnorePlugin.js
+
+use std::fmt;
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use rspack_core::{
+  NormalModuleBeforeResolveArgs, Plugin, PluginContext,
+  PluginNormalModuleFactoryBeforeResolveOutput,
+};
+use rspack_error::Result;
+use rspack_regex::RspackRegex;
+
+/// Called with the request and the context, returns `true` to ignore the request.
+pub type CheckResourceFn =
+  Box<dyn Fn(String, String) -> BoxFuture<'static, Result<bool>> + Sync + Send>;
+
+pub enum IgnoreCondition {
+  /// Ignore requests matching `resource_reg_exp`, in contexts matching `context_reg_exp` if given
+  RegExp {
+    resource_reg_exp: RspackRegex,
+    context_reg_exp: Option<RspackRegex>,
+  },
+  CheckResource(CheckResourceFn),
+}
+
+impl fmt::Debug for IgnoreCondition {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::RegExp {
+        resource_reg_exp,
+        context_reg_exp,
+      } => f
+        .debug_struct("RegExp")
+        .field("resource_reg_exp", resource_reg_exp)
+        .field("context_reg_exp", context_reg_exp)
+        .finish(),
+      Self::CheckResource(_) => f.debug_tuple("CheckResource").finish(),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct IgnorePlugin {
+  condition: IgnoreCondition,
+}
+
+impl IgnorePlugin {
+  pub fn new(condition: IgnoreCondition) -> Self {
+    Self { condition }
+  }
+
+  async fn check_ignore(&self, args: &NormalModuleBeforeResolveArgs) -> Result<Option<bool>> {
+    let ignored = match &self.condition {
+      IgnoreCondition::RegExp {
+        resource_reg_exp,
+        context_reg_exp,
+      } => {
+        resource_reg_exp.test(&args.request)
+          && context_reg_exp
+            .as_ref()
+            .map_or(true, |context_reg_exp| context_reg_exp.test(&args.context))
+      }
+      IgnoreCondition::CheckResource(check_resource) => {
+        check_resource(args.request.clone(), args.context.clone()).await?
+      }
+    };
+    Ok(ignored.then_some(false))
+  }
+}
+
+#[async_trait]
+impl Plugin for IgnorePlugin {
+  fn name(&self) -> &'static str {
+    "rspack.IgnorePlugin"
+  }
+
+  async fn before_resolve(
+    &self,
+    _ctx: PluginContext,
+    args: &mut NormalModuleBeforeResolveArgs,
+  ) -> PluginNormalModuleFactoryBeforeResolveOutput {
+    self.check_ignore(args).await
+  }
+
+  async fn context_module_before_resolve(
+    &self,
+    _ctx: PluginContext,
+    args: &mut NormalModuleBeforeResolveArgs,
+  ) -> PluginNormalModuleFactoryBeforeResolveOutput {
+    self.check_ignore(args).await
+  }
+}
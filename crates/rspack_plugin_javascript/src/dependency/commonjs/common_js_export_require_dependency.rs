@@ -8,7 +8,7 @@ use swc_core::atoms::Atom;
 use super::ExportsBase;
 
 #[allow(unused)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CommonJsExportRequireDependency {
   id: DependencyId,
   range: (u32, u32),
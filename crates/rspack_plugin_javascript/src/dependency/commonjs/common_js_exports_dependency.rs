@@ -7,7 +7,7 @@ use rspack_core::{
 };
 use swc_core::atoms::Atom;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum ExportsBase {
   Exports,
   ModuleExports,
@@ -45,7 +45,7 @@ impl ExportsBase {
   }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CommonJsExportsDependency {
   id: DependencyId,
   range: (u32, u32),
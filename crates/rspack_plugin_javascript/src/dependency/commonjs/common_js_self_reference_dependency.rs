@@ -7,7 +7,7 @@ use swc_core::atoms::Atom;
 
 use super::ExportsBase;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CommonJsSelfReferenceDependency {
   id: DependencyId,
   range: (u32, u32),
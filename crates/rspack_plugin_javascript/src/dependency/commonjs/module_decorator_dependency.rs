@@ -3,7 +3,7 @@ use rspack_core::{
   TemplateContext, TemplateReplaceSource,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ModuleDecoratorDependency {
   decorator: RuntimeGlobals,
 }
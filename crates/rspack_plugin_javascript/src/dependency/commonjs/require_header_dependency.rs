@@ -2,7 +2,7 @@ use rspack_core::{AsContextDependency, AsModuleDependency, Dependency};
 use rspack_core::{DependencyId, DependencyLocation};
 use rspack_core::{DependencyTemplate, RuntimeGlobals, TemplateContext};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct RequireHeaderDependency {
   id: DependencyId,
   loc: DependencyLocation,
@@ -4,7 +4,7 @@ use rspack_core::{
   TemplateContext, TemplateReplaceSource,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct RequireResolveDependency {
   pub start: u32,
   pub end: u32,
@@ -5,7 +5,7 @@ use rspack_core::{
 
 // Mark module `__esModule`.
 // Add `__webpack_require__.r(__webpack_exports__);`.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct HarmonyCompatibilityDependency;
 
 impl DependencyTemplate for HarmonyCompatibilityDependency {
@@ -12,20 +12,20 @@ use swc_core::atoms::Atom;
 
 pub const DEFAULT_EXPORT: &str = "__WEBPACK_DEFAULT_EXPORT__";
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum DeclarationId {
   Id(String),
   Func(DeclarationInfo),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct DeclarationInfo {
   pub range: DependencyLocation,
   pub prefix: String,
   pub suffix: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct HarmonyExportExpressionDependency {
   pub range: DependencyLocation,
   pub range_stmt: DependencyLocation,
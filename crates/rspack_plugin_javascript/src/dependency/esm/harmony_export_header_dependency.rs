@@ -6,7 +6,7 @@ use rspack_core::{
 // Remove `export` label.
 // Before: `export const a = 1`
 // After: `const a = 1`
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct HarmonyExportHeaderDependency {
   pub range: Option<DependencyLocation>,
   pub range_stmt: DependencyLocation,
@@ -22,7 +22,7 @@ use super::{create_resource_identifier_for_esm_dependency, harmony_import_depend
 // case1: `import { a } from 'a'; export { a }`
 // case2: `export { a } from 'a';`
 // case3: `export * from 'a'`
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct HarmonyExportImportedSpecifierDependency {
   pub id: DependencyId,
   pub source_order: i32,
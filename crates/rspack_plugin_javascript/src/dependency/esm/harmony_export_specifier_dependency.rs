@@ -6,7 +6,7 @@ use rspack_core::{
 use swc_core::ecma::atoms::Atom;
 
 // Create _webpack_require__.d(__webpack_exports__, {}) for each export.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct HarmonyExportSpecifierDependency {
   id: DependencyId,
   name: Atom,
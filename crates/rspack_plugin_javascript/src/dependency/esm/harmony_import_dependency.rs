@@ -15,7 +15,7 @@ use swc_core::ecma::atoms::Atom;
 
 use super::create_resource_identifier_for_esm_dependency;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum Specifier {
   Namespace(Atom),
   Default(Atom),
@@ -23,7 +23,7 @@ pub enum Specifier {
 }
 
 // HarmonyImportDependency is merged HarmonyImportSideEffectDependency.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct HarmonyImportSideEffectDependency {
   pub request: Atom,
   pub source_order: i32,
@@ -14,7 +14,7 @@ use super::{
   create_resource_identifier_for_esm_dependency, harmony_import_dependency_apply, Specifier,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct HarmonyImportSpecifierDependency {
   pub id: DependencyId,
   request: Atom,
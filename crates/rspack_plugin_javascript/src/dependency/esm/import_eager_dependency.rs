@@ -6,7 +6,7 @@ use rspack_core::{
 };
 use swc_core::ecma::atoms::Atom;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ImportEagerDependency {
   start: u32,
   end: u32,
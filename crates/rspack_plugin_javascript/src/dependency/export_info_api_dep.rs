@@ -1,7 +1,7 @@
 use rspack_core::{DependencyTemplate, TemplateContext, TemplateReplaceSource, UsageState};
 use swc_core::ecma::atoms::Atom;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ExportInfoApiDependency {
   start: u32,
   end: u32,
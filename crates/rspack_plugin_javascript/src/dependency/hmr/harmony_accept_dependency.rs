@@ -2,7 +2,7 @@ use rspack_core::{
   import_statement, DependencyId, DependencyTemplate, TemplateContext, TemplateReplaceSource,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct HarmonyAcceptDependency {
   start: u32,
   end: u32,
@@ -4,7 +4,7 @@ use rspack_core::{
 };
 use swc_core::ecma::atoms::Atom;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ModuleHotAcceptDependency {
   id: DependencyId,
   request: Atom,
@@ -3,7 +3,7 @@ use rspack_core::{
   ModuleDependency, TemplateContext, TemplateReplaceSource,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct WebpackIsIncludedDependency {
   pub start: u32,
   pub end: u32,
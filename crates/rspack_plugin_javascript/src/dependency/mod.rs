@@ -17,6 +17,55 @@ pub use hmr::*;
 pub use is_included_dependency::*;
 pub use module_argument_dependency::*;
 pub use pure_expression_dependency::*;
+use rspack_core::{
+  register_serializable_dependency, register_serializable_dependency_template, ConstDependency,
+  Dependency, DependencyTemplate, RuntimeRequirementsDependency,
+};
+use serde::{de::DeserializeOwned, Serialize};
 pub use worker::*;
 
 pub use self::url::*;
+
+/// Registers the dependencies created by the javascript parser, so modules using them can be
+/// persisted by the filesystem cache. Context and worker dependencies are not registered yet.
+pub(crate) fn register_serializable_dependencies() {
+  fn dependency<D>()
+  where
+    D: Dependency + DependencyTemplate + Serialize + DeserializeOwned + 'static,
+  {
+    // dependencies are rendered as presentational dependencies as well
+    register_serializable_dependency::<D>();
+    register_serializable_dependency_template::<D>();
+  }
+
+  dependency::<URLDependency>();
+  dependency::<ImportDependency>();
+  dependency::<ImportEagerDependency>();
+  dependency::<HarmonyExportExpressionDependency>();
+  dependency::<HarmonyExportHeaderDependency>();
+  dependency::<HarmonyExportImportedSpecifierDependency>();
+  dependency::<HarmonyExportSpecifierDependency>();
+  dependency::<HarmonyImportSideEffectDependency>();
+  dependency::<HarmonyImportSpecifierDependency>();
+  dependency::<WebpackIsIncludedDependency>();
+  dependency::<PureExpressionDependency>();
+  dependency::<CommonJsExportRequireDependency>();
+  dependency::<CommonJsExportsDependency>();
+  dependency::<CommonJsFullRequireDependency>();
+  dependency::<CommonJsRequireDependency>();
+  dependency::<CommonJsSelfReferenceDependency>();
+  dependency::<RequireHeaderDependency>();
+  dependency::<RequireResolveDependency>();
+  dependency::<ImportMetaHotAcceptDependency>();
+  dependency::<ImportMetaHotDeclineDependency>();
+  dependency::<ModuleHotAcceptDependency>();
+  dependency::<ModuleHotDeclineDependency>();
+
+  register_serializable_dependency_template::<ConstDependency>();
+  register_serializable_dependency_template::<RuntimeRequirementsDependency>();
+  register_serializable_dependency_template::<ExportInfoApiDependency>();
+  register_serializable_dependency_template::<HarmonyAcceptDependency>();
+  register_serializable_dependency_template::<HarmonyCompatibilityDependency>();
+  register_serializable_dependency_template::<ModuleArgumentDependency>();
+  register_serializable_dependency_template::<ModuleDecoratorDependency>();
+}
@@ -1,15 +1,21 @@
+use std::borrow::Cow;
+
 use rspack_core::{DependencyTemplate, RuntimeGlobals, TemplateContext, TemplateReplaceSource};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ModuleArgumentDependency {
   pub start: u32,
   pub end: u32,
-  pub id: Option<&'static str>,
+  pub id: Option<Cow<'static, str>>,
 }
 
 impl ModuleArgumentDependency {
   pub fn new(start: u32, end: u32, id: Option<&'static str>) -> Self {
-    Self { start, end, id }
+    Self {
+      start,
+      end,
+      id: id.map(Cow::Borrowed),
+    }
   }
 }
 
@@ -34,7 +40,7 @@ impl DependencyTemplate for ModuleArgumentDependency {
       .expect("should have mgm")
       .get_module_argument();
 
-    if let Some(id) = self.id {
+    if let Some(id) = &self.id {
       source.replace(
         self.start,
         self.end,
@@ -4,7 +4,7 @@ use rspack_core::{
   TemplateReplaceSource, UsageState, UsedByExports, UsedName,
 };
 use rustc_hash::FxHashSet as HashSet;
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct PureExpressionDependency {
   pub start: u32,
   pub end: u32,
@@ -13,6 +13,10 @@ pub mod runtime;
 pub mod utils;
 pub mod visitors;
 mod webpack_comment;
+pub use crate::parser_plugin::{
+  BoxJavascriptParserPlugin, JavascriptParserPlugin, JavascriptParserPluginBuilder,
+  JavascriptParserPluginRegistry,
+};
 pub use crate::plugin::infer_async_modules_plugin::InferAsyncModulesPlugin;
 pub use crate::plugin::*;
 
@@ -20,17 +20,20 @@ use swc_core::ecma::parser::{EsConfig, Syntax};
 
 use crate::ast::CodegenOptions;
 use crate::inner_graph_plugin::InnerGraphPlugin;
+use crate::parser_plugin::JavascriptParserPluginRegistry;
 use crate::visitors::ScanDependenciesResult;
 use crate::visitors::{run_before_pass, scan_dependencies, swc_visitor::resolver};
 use crate::{SideEffectsFlagPluginVisitor, SyntaxContextInfo};
 
 #[derive(Debug)]
-pub struct JavaScriptParserAndGenerator;
+pub struct JavaScriptParserAndGenerator {
+  parser_plugins: JavascriptParserPluginRegistry,
+}
 
 #[allow(unused)]
 impl JavaScriptParserAndGenerator {
-  pub(crate) fn new() -> Self {
-    Self {}
+  pub(crate) fn new(parser_plugins: JavascriptParserPluginRegistry) -> Self {
+    Self { parser_plugins }
   }
 
   fn source_block(
@@ -197,6 +200,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
         build_info,
         build_meta,
         module_identifier,
+        self.parser_plugins.create(module_type, compiler_options),
       )
     }) {
       Ok(result) => result,
@@ -212,7 +216,14 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       for mut block in blocks.clone() {
         all_dependencies.extend(block.take_dependencies());
       }
-      JsModule::new(&ast, &all_dependencies, module_identifier, compiler_options).analyze()
+      JsModule::new(
+        &ast,
+        &all_dependencies,
+        module_identifier,
+        module_type,
+        compiler_options,
+      )
+      .analyze()
     } else {
       OptimizeAnalyzeResult::default()
     };
@@ -5,6 +5,7 @@ mod compatibility_plugin;
 mod r#const;
 mod drive;
 mod exports_info_api_plugin;
+mod registry;
 mod require_context_dependency_parser_plugin;
 mod r#trait;
 mod url_plugin;
@@ -18,6 +19,7 @@ pub use self::drive::JavaScriptParserPluginDrive;
 pub use self::exports_info_api_plugin::ExportsInfoApiPlugin;
 pub use self::r#const::{is_logic_op, ConstPlugin};
 pub use self::r#trait::{BoxJavascriptParserPlugin, JavascriptParserPlugin};
+pub use self::registry::{JavascriptParserPluginBuilder, JavascriptParserPluginRegistry};
 pub use self::require_context_dependency_parser_plugin::RequireContextDependencyParserPlugin;
 pub use self::url_plugin::URLPlugin;
 pub use self::webpack_included_plugin::WebpackIsIncludedPlugin;
@@ -0,0 +1,86 @@
+use std::fmt::Debug;
+use std::sync::{Arc, RwLock};
+
+use rspack_core::{CompilerOptions, ModuleType};
+use rustc_hash::FxHashMap as HashMap;
+
+use super::BoxJavascriptParserPlugin;
+
+/// Creates the parser plugin of a module, a new plugin is created for every parsed module.
+pub type JavascriptParserPluginBuilder =
+  Arc<dyn Fn(&CompilerOptions) -> BoxJavascriptParserPlugin + Send + Sync>;
+
+/// Parser plugins contributed by other plugins, keyed by the module type they apply to.
+/// They run after the builtin parser plugins.
+///
+/// The registry is shared by all plugins of a compiler through [rspack_core::ApplyContext::hooks],
+/// so a plugin can add parser plugins in its `apply`:
+///
+/// ```ignore
+/// ctx
+///   .context
+///   .hooks::<JavascriptParserPluginRegistry>()
+///   .register(ModuleType::Js, Arc::new(|_| Box::new(MyParserPlugin)));
+/// ```
+#[derive(Default, Clone)]
+pub struct JavascriptParserPluginRegistry {
+  builders: Arc<RwLock<HashMap<ModuleType, Vec<JavascriptParserPluginBuilder>>>>,
+}
+
+impl JavascriptParserPluginRegistry {
+  pub fn register(&self, module_type: ModuleType, builder: JavascriptParserPluginBuilder) {
+    self
+      .builders
+      .write()
+      .expect("should lock parser plugins")
+      .entry(module_type)
+      .or_default()
+      .push(builder);
+  }
+
+  /// Add all parser plugins of `other` to this registry.
+  pub(crate) fn extend(&self, other: &Self) {
+    let other = other.builders.read().expect("should lock parser plugins");
+    let mut builders = self.builders.write().expect("should lock parser plugins");
+    for (module_type, other) in other.iter() {
+      builders
+        .entry(*module_type)
+        .or_default()
+        .extend(other.iter().cloned());
+    }
+  }
+
+  pub fn create(
+    &self,
+    module_type: &ModuleType,
+    compiler_options: &CompilerOptions,
+  ) -> Vec<BoxJavascriptParserPlugin> {
+    self
+      .builders
+      .read()
+      .expect("should lock parser plugins")
+      .get(module_type)
+      .map(|builders| {
+        builders
+          .iter()
+          .map(|builder| builder(compiler_options))
+          .collect()
+      })
+      .unwrap_or_default()
+  }
+}
+
+impl Debug for JavascriptParserPluginRegistry {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_map()
+      .entries(
+        self
+          .builders
+          .read()
+          .expect("should lock parser plugins")
+          .iter()
+          .map(|(module_type, builders)| (module_type, builders.len())),
+      )
+      .finish()
+  }
+}
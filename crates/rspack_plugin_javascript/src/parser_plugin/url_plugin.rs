@@ -1,4 +1,5 @@
 use rspack_core::SpanExt;
+use swc_core::ecma::ast::{Expr, ExprOrSpread};
 
 use super::JavascriptParserPlugin;
 use crate::dependency::URLDependency;
@@ -8,6 +9,30 @@ pub struct URLPlugin {
 }
 
 impl JavascriptParserPlugin for URLPlugin {
+  fn call(
+    &self,
+    parser: &mut crate::visitors::JavascriptParser,
+    expr: &swc_core::ecma::ast::CallExpr,
+  ) -> Option<bool> {
+    // only skip the first argument when the worker plugin takes it as the worker entry,
+    // otherwise `navigator.serviceWorker.register(url)` is walked as a normal call
+    if parser.worker_syntax_list.match_worker_call(expr).is_some()
+      && let Some(ExprOrSpread {
+        spread: None,
+        expr: box Expr::New(new_url_expr),
+      }) = expr.args.first()
+      && rspack_core::needs_refactor::match_new_url(new_url_expr).is_some()
+    {
+      for arg in expr.args.iter().skip(1) {
+        parser.walk_expression(&arg.expr);
+      }
+      // skip `navigator.serviceWorker.register(new URL,)`
+      Some(true)
+    } else {
+      None
+    }
+  }
+
   fn new_expression(
     &self,
     parser: &mut crate::visitors::JavascriptParser,
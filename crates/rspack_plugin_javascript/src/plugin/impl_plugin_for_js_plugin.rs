@@ -14,7 +14,9 @@ use rspack_core::{
 use rspack_error::{IntoTWithDiagnosticArray, Result};
 use rspack_hash::RspackHash;
 
+use crate::dependency::register_serializable_dependencies;
 use crate::parser_and_generator::JavaScriptParserAndGenerator;
+use crate::parser_plugin::JavascriptParserPluginRegistry;
 use crate::JsPlugin;
 
 #[async_trait]
@@ -24,23 +26,34 @@ impl Plugin for JsPlugin {
   }
   fn apply(
     &self,
-    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    mut ctx: PluginContext<&mut rspack_core::ApplyContext>,
     _options: &mut CompilerOptions,
   ) -> Result<()> {
-    let create_parser_and_generator =
-      move || Box::new(JavaScriptParserAndGenerator::new()) as Box<dyn ParserAndGenerator>;
-
-    ctx
+    // Parser plugins of other plugins are registered to the shared registry during `apply`,
+    // it is read when a module is parsed so plugins applied after this one are included as well.
+    let parser_plugins = ctx
       .context
-      .register_parser_and_generator_builder(ModuleType::Js, Box::new(create_parser_and_generator));
+      .hooks::<JavascriptParserPluginRegistry>()
+      .clone();
+    parser_plugins.extend(&self.parser_plugins);
+    let create_parser_and_generator = move || {
+      Box::new(JavaScriptParserAndGenerator::new(parser_plugins.clone()))
+        as Box<dyn ParserAndGenerator>
+    };
+
+    ctx.context.register_parser_and_generator_builder(
+      ModuleType::Js,
+      Box::new(create_parser_and_generator.clone()),
+    );
     ctx.context.register_parser_and_generator_builder(
       ModuleType::JsEsm,
-      Box::new(create_parser_and_generator),
+      Box::new(create_parser_and_generator.clone()),
     );
     ctx.context.register_parser_and_generator_builder(
       ModuleType::JsDynamic,
       Box::new(create_parser_and_generator),
     );
+    register_serializable_dependencies();
 
     Ok(())
   }
@@ -15,22 +15,40 @@ pub use mangle_exports_plugin::*;
 pub use module_concatenation_plugin::*;
 use rspack_core::rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt};
 use rspack_core::{
-  render_init_fragments, ChunkRenderContext, ChunkUkey, Compilation, JsChunkHashArgs,
+  render_init_fragments, ChunkRenderContext, ChunkUkey, Compilation, JsChunkHashArgs, ModuleType,
   PluginJsChunkHashHookOutput, RenderArgs, RenderChunkArgs, RenderStartupArgs, RuntimeGlobals,
 };
 use rspack_error::Result;
 use rspack_hash::RspackHash;
 pub use side_effects_flag_plugin::*;
 
+use crate::parser_plugin::{JavascriptParserPluginBuilder, JavascriptParserPluginRegistry};
 use crate::runtime::{render_chunk_modules, render_iife, render_runtime_modules, stringify_array};
 use crate::utils::is_diff_mode;
 
 #[derive(Debug)]
-pub struct JsPlugin;
+pub struct JsPlugin {
+  parser_plugins: JavascriptParserPluginRegistry,
+}
 
 impl JsPlugin {
   pub fn new() -> Self {
-    Self {}
+    Self {
+      parser_plugins: Default::default(),
+    }
+  }
+
+  /// Add a parser plugin to every module of `module_type`, e.g. to create dependencies
+  /// for custom call sites or magic comments.
+  ///
+  /// Other plugins register theirs through [JavascriptParserPluginRegistry] in `apply`.
+  pub fn with_parser_plugin(
+    self,
+    module_type: ModuleType,
+    builder: JavascriptParserPluginBuilder,
+  ) -> Self {
+    self.parser_plugins.register(module_type, builder);
+    self
   }
 
   pub fn render_require(&self, chunk_ukey: &ChunkUkey, compilation: &Compilation) -> BoxSource {
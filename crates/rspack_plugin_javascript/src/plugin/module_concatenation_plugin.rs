@@ -7,7 +7,7 @@ use rspack_core::concatenated_module::{
   is_harmony_dep_like, ConcatenatedInnerModule, ConcatenatedModule, RootModuleContext,
 };
 use rspack_core::{
-  filter_runtime, merge_runtime, runtime_to_string, Compilation, CompilerContext,
+  filter_runtime, merge_runtime, runtime_to_string, Compilation, CompilerContext, ExportInfoId,
   ExportInfoProvided, ExtendedReferencedExport, LibIdentOptions, Logger, Module, ModuleExt,
   ModuleGraph, ModuleGraphModule, ModuleIdentifier, OptimizeChunksArgs, Plugin, ProvidedExports,
   RuntimeCondition, RuntimeSpec, WrappedModuleIdentifier,
@@ -15,11 +15,29 @@ use rspack_core::{
 use rspack_error::Result;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
-#[allow(unused)]
 fn format_bailout_reason(msg: &str) -> String {
   format!("ModuleConcatenation bailout: {}", msg)
 }
 
+fn set_bailout_reason(module_graph: &mut ModuleGraph, module: &ModuleIdentifier, reason: &str) {
+  module_graph
+    .get_optimization_bailout_mut(module)
+    .push(format_bailout_reason(reason));
+}
+
+fn format_export_names(module_graph: &ModuleGraph, exports: &[ExportInfoId]) -> String {
+  exports
+    .iter()
+    .map(|id| {
+      id.get_export_info(module_graph)
+        .name
+        .as_ref()
+        .map_or_else(|| "other exports".to_string(), |name| name.to_string())
+    })
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
 #[derive(Clone)]
 enum Warning {
   Id(ModuleIdentifier),
@@ -72,10 +90,10 @@ impl ConcatConfiguration {
     self.warnings.insert(module, problem);
   }
 
-  fn get_warnings_sorted(&self) -> HashMap<ModuleIdentifier, Warning> {
+  fn get_warnings_sorted(&self) -> Vec<(ModuleIdentifier, Warning)> {
     let mut sorted_warnings: Vec<_> = self.warnings.clone().into_iter().collect();
     sorted_warnings.sort_by(|a, b| a.0.cmp(&b.0));
-    sorted_warnings.into_iter().collect()
+    sorted_warnings
   }
 
   fn get_modules(&self) -> &HashSet<ModuleIdentifier> {
@@ -137,6 +155,50 @@ impl ModuleConcatenationPlugin {
     set
   }
 
+  fn format_bailout_warning(
+    compilation: &Compilation,
+    module: &ModuleIdentifier,
+    warning: &Warning,
+  ) -> String {
+    let readable_identifier = |id: &ModuleIdentifier| {
+      compilation
+        .module_graph
+        .module_by_identifier(id)
+        .expect("should have module")
+        .readable_identifier(&compilation.options.context)
+        .to_string()
+    };
+    match warning {
+      Warning::Problem(problem) => format!(
+        "Cannot concat with {}: {problem}",
+        readable_identifier(module)
+      ),
+      Warning::Id(id) => {
+        // the last bailout reason of the module that prevents the concatenation
+        let reason = compilation
+          .module_graph
+          .get_optimization_bailout(id)
+          .last()
+          .map(|reason| {
+            format!(
+              ": {}",
+              reason.trim_start_matches(&format_bailout_reason(""))
+            )
+          })
+          .unwrap_or_default();
+        if id == module {
+          format!("Cannot concat with {}{reason}", readable_identifier(module))
+        } else {
+          format!(
+            "Cannot concat with {} because of {}{reason}",
+            readable_identifier(module),
+            readable_identifier(id)
+          )
+        }
+      }
+    }
+  }
+
   #[allow(clippy::too_many_arguments)]
   fn try_to_add(
     compilation: &Compilation,
@@ -511,9 +573,13 @@ impl Plugin for ModuleConcatenationPlugin {
       .map(WrappedModuleIdentifier::from)
       .collect::<Vec<_>>();
     for module_id in module_id_list {
+      // the module graph is kept across rebuilds, drop the reasons of the previous compilation
+      compilation
+        .module_graph
+        .get_optimization_bailout_mut(&module_id)
+        .clear();
       let mut can_be_root = true;
       let mut can_be_inner = true;
-      let m = module_id.module(&compilation.module_graph);
       // If the result is `None`, that means we have some differences with webpack,
       // https://github.com/webpack/webpack/blob/1f99ad6367f2b8a6ef17cce0e058f7a67fb7db18/lib/optimize/ModuleConcatenationPlugin.js#L168-L171
       if compilation
@@ -521,11 +587,20 @@ impl Plugin for ModuleConcatenationPlugin {
         .is_async(&module_id)
         .expect("should have async result")
       {
-        // TODO: bailout
+        set_bailout_reason(&mut compilation.module_graph, &module_id, "Module is async");
         continue;
       }
-      if !m.build_info().expect("should have build info").strict {
-        // TODO: bailout
+      if !module_id
+        .module(&compilation.module_graph)
+        .build_info()
+        .expect("should have build info")
+        .strict
+      {
+        set_bailout_reason(
+          &mut compilation.module_graph,
+          &module_id,
+          "Module is not in strict mode",
+        );
         continue;
       }
       if compilation
@@ -533,7 +608,11 @@ impl Plugin for ModuleConcatenationPlugin {
         .get_number_of_module_chunks(*module_id)
         == 0
       {
-        // TODO: bailout
+        set_bailout_reason(
+          &mut compilation.module_graph,
+          &module_id,
+          "Module is not in any chunk",
+        );
         continue;
       }
       let exports_info = compilation.module_graph.get_exports_info(&module_id);
@@ -553,7 +632,11 @@ impl Plugin for ModuleConcatenationPlugin {
         .copied()
         .collect::<Vec<_>>();
       if !unknown_exports.is_empty() {
-        // TODO: bailout
+        let reason = format!(
+          "Reexports in this module do not have a static target ({})",
+          format_export_names(&compilation.module_graph, &unknown_exports)
+        );
+        set_bailout_reason(&mut compilation.module_graph, &module_id, &reason);
         continue;
       }
       let unknown_provided_exports = relevnat_epxorts
@@ -566,12 +649,20 @@ impl Plugin for ModuleConcatenationPlugin {
         .collect::<Vec<_>>();
 
       if !unknown_provided_exports.is_empty() {
-        // TODO: bailout
+        let reason = format!(
+          "List of module exports is dynamic ({})",
+          format_export_names(&compilation.module_graph, &unknown_provided_exports)
+        );
+        set_bailout_reason(&mut compilation.module_graph, &module_id, &reason);
         can_be_root = false;
       }
 
       if compilation.chunk_graph.is_entry_module(&module_id) {
-        // TODO: bailout
+        set_bailout_reason(
+          &mut compilation.module_graph,
+          &module_id,
+          "Module is an entry point",
+        );
         can_be_inner = false;
       }
       if can_be_root {
@@ -688,7 +779,10 @@ impl Plugin for ModuleConcatenationPlugin {
         concat_configurations.push(current_configuration);
       } else {
         stats_empty_configurations += 1;
-        // TODO: bailout
+        for (module, warning) in current_configuration.get_warnings_sorted() {
+          let reason = Self::format_bailout_warning(compilation, &module, &warning);
+          set_bailout_reason(&mut compilation.module_graph, current_root, &reason);
+        }
       }
     }
     logger.time_end(start);
@@ -757,6 +851,7 @@ impl Plugin for ModuleConcatenationPlugin {
           .get_presentational_dependencies()
           .map(|deps| deps.to_vec()),
         context: Some(compilation.options.context.clone()),
+        layer: box_module.get_layer().cloned(),
         side_effect_connection_state: box_module
           .get_side_effects_connection_state(&compilation.module_graph, &mut HashSet::default()),
         build_meta: box_module.build_meta().cloned(),
@@ -40,6 +40,7 @@ use self::{
   import_meta_scanner::ImportMetaScanner, import_scanner::ImportScanner,
   node_stuff_scanner::NodeStuffScanner, worker_scanner::WorkerScanner,
 };
+use crate::parser_plugin::BoxJavascriptParserPlugin;
 
 pub struct ScanDependenciesResult {
   pub dependencies: Vec<BoxDependency>,
@@ -71,6 +72,7 @@ pub fn scan_dependencies(
   build_info: &mut BuildInfo,
   build_meta: &mut BuildMeta,
   module_identifier: ModuleIdentifier,
+  parser_plugins: Vec<BoxJavascriptParserPlugin>,
 ) -> Result<ScanDependenciesResult, Vec<Box<dyn Diagnostic + Send + Sync>>> {
   let mut warning_diagnostics: Vec<Box<dyn Diagnostic + Send + Sync>> = vec![];
   let mut errors = vec![];
@@ -85,9 +87,10 @@ pub fn scan_dependencies(
   let mut rewrite_usage_span = HashMap::default();
 
   let worker_syntax_list = if module_type.is_js_auto() || module_type.is_js_esm() {
-    let mut worker_syntax_scanner = rspack_core::needs_refactor::WorkerSyntaxScanner::new(
-      rspack_core::needs_refactor::DEFAULT_WORKER_SYNTAX,
-    );
+    let worker_syntax =
+      rspack_core::needs_refactor::get_worker_syntax(compiler_options, module_type);
+    let mut worker_syntax_scanner =
+      rspack_core::needs_refactor::WorkerSyntaxScanner::new(&worker_syntax);
     program.visit_with(&mut worker_syntax_scanner);
     worker_syntax_scanner.result
   } else {
@@ -106,6 +109,7 @@ pub fn scan_dependencies(
     build_info,
     &mut errors,
     &mut warning_diagnostics,
+    parser_plugins,
   );
 
   parser.visit(program.get_inner_program());
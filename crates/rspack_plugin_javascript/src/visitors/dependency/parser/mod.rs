@@ -65,6 +65,7 @@ impl<'parser> JavascriptParser<'parser> {
     build_info: &'parser mut BuildInfo,
     errors: &'parser mut Vec<Box<dyn Diagnostic + Send + Sync>>,
     warning_diagnostics: &'parser mut Vec<Box<dyn Diagnostic + Send + Sync>>,
+    parser_plugins: Vec<parser_plugin::BoxJavascriptParserPlugin>,
   ) -> Self {
     let mut plugins: Vec<parser_plugin::BoxJavascriptParserPlugin> = vec![
       Box::new(parser_plugin::CheckVarDeclaratorIdent),
@@ -99,6 +100,9 @@ impl<'parser> JavascriptParser<'parser> {
       }
     }
 
+    // plugins registered by users run after the builtin ones
+    plugins.extend(parser_plugins);
+
     let plugin_drive = Rc::new(JavaScriptParserPluginDrive::new(plugins));
     let mut db = ScopeInfoDB::new();
     Self {
@@ -121,6 +125,26 @@ impl<'parser> JavascriptParser<'parser> {
     }
   }
 
+  pub fn resource_data(&self) -> &ResourceData {
+    self.resource_data
+  }
+
+  pub fn add_dependency(&mut self, dependency: BoxDependency) {
+    self.dependencies.push(dependency);
+  }
+
+  pub fn add_presentational_dependency(&mut self, dependency: Box<dyn DependencyTemplate>) {
+    self.presentational_dependencies.push(dependency);
+  }
+
+  pub fn add_warning(&mut self, warning: Box<dyn Diagnostic + Send + Sync>) {
+    self.warning_diagnostics.push(warning);
+  }
+
+  pub fn add_error(&mut self, error: Box<dyn Diagnostic + Send + Sync>) {
+    self.errors.push(error);
+  }
+
   pub fn get_mut_variable_info(&mut self, name: &str) -> Option<&mut VariableInfo> {
     let Some(id) = self.definitions_db.get(&self.definitions, name) else {
       return None;
@@ -1,14 +1,14 @@
 use std::hash::Hash;
 
 use rspack_core::{
-  AsyncDependenciesBlock, BoxDependency, BoxDependencyTemplate, ConstDependency,
+  AsyncDependenciesBlock, BoxDependency, BoxDependencyTemplate, ChunkLoading, ConstDependency,
   DependencyLocation, EntryOptions, ErrorSpan, GroupOptions, ModuleIdentifier, OutputOptions,
   SpanExt,
 };
 use rspack_hash::RspackHash;
 use rustc_hash::FxHashSet;
-use swc_core::common::Spanned;
-use swc_core::ecma::ast::{Expr, ExprOrSpread, NewExpr};
+use swc_core::common::{Span, Spanned};
+use swc_core::ecma::ast::{CallExpr, Expr, ExprOrSpread, NewExpr};
 use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
 
 use crate::dependency::WorkerDependency;
@@ -28,6 +28,7 @@ pub struct WorkerScanner<'a> {
 }
 
 // new Worker(new URL("./foo.worker.js", import.meta.url));
+// navigator.serviceWorker.register(new URL("./sw.js", import.meta.url));
 impl<'a> WorkerScanner<'a> {
   pub fn new(
     module_identifier: &'a ModuleIdentifier,
@@ -49,9 +50,10 @@ impl<'a> WorkerScanner<'a> {
 
   fn add_dependencies(
     &mut self,
-    new_expr: &NewExpr,
+    span: Span,
     parsed_path: ParsedNewWorkerPath,
     parsed_options: Option<ParsedNewWorkerOptions>,
+    worklet: bool,
   ) {
     let mut hasher = RspackHash::from(self.output_options);
     self.module_identifier.hash(&mut hasher);
@@ -64,7 +66,7 @@ impl<'a> WorkerScanner<'a> {
     let range = parsed_options.as_ref().map(|options| options.range);
     let name = parsed_options.and_then(|options| options.name);
     let output_module = self.output_options.module;
-    let span = ErrorSpan::from(new_expr.span);
+    let span = ErrorSpan::from(span);
     let dep = Box::new(WorkerDependency::new(
       parsed_path.range.0,
       parsed_path.range.1,
@@ -79,12 +81,19 @@ impl<'a> WorkerScanner<'a> {
     block.set_group_options(GroupOptions::Entrypoint(Box::new(EntryOptions {
       name,
       runtime: Some(runtime),
-      chunk_loading: Some(self.output_options.worker_chunk_loading.clone()),
-      async_chunks: None,
+      depend_on: None,
+      // worklets are loaded as a single script, without a way to load other chunks
+      chunk_loading: Some(if worklet {
+        ChunkLoading::Disable
+      } else {
+        self.output_options.worker_chunk_loading.clone()
+      }),
+      async_chunks: worklet.then_some(false),
       public_path: None,
       base_uri: None,
       filename: None,
       library: None,
+      layer: None,
     })));
     block.add_dependency(dep);
     self.blocks.push(block);
@@ -123,23 +132,43 @@ impl<'a> WorkerScanner<'a> {
   ) -> Option<(ParsedNewWorkerPath, Option<ParsedNewWorkerOptions>)> {
     if self.syntax_list.match_new_worker(new_expr)
       && let Some(args) = &new_expr.args
-      && let Some(expr_or_spread) = args.first()
-      && let ExprOrSpread {
-        spread: None,
-        expr: box Expr::New(new_url_expr),
-      } = expr_or_spread
-      && let Some((start, end, request)) = rspack_core::needs_refactor::match_new_url(new_url_expr)
     {
-      let path = ParsedNewWorkerPath {
-        range: (start, end),
-        value: request,
-      };
-      let options = args.get(1).map(parse_new_worker_options);
-      Some((path, options))
+      parse_worker_args(args)
     } else {
       None
     }
   }
+
+  /// The parsed arguments of the call, and whether it adds a worklet
+  fn parse_worker_call(
+    &self,
+    call_expr: &CallExpr,
+  ) -> Option<(ParsedNewWorkerPath, Option<ParsedNewWorkerOptions>, bool)> {
+    let syntax = self.syntax_list.match_worker_call(call_expr)?;
+    let (path, options) = parse_worker_args(&call_expr.args)?;
+    Some((path, options, syntax.is_worklet()))
+  }
+}
+
+fn parse_worker_args(
+  args: &[ExprOrSpread],
+) -> Option<(ParsedNewWorkerPath, Option<ParsedNewWorkerOptions>)> {
+  if let Some(expr_or_spread) = args.first()
+    && let ExprOrSpread {
+      spread: None,
+      expr: box Expr::New(new_url_expr),
+    } = expr_or_spread
+    && let Some((start, end, request)) = rspack_core::needs_refactor::match_new_url(new_url_expr)
+  {
+    let path = ParsedNewWorkerPath {
+      range: (start, end),
+      value: request,
+    };
+    let options = args.get(1).map(parse_new_worker_options);
+    Some((path, options))
+  } else {
+    None
+  }
 }
 
 impl Visit for WorkerScanner<'_> {
@@ -148,11 +177,19 @@ impl Visit for WorkerScanner<'_> {
 
   fn visit_new_expr(&mut self, new_expr: &NewExpr) {
     if let Some((parsed_path, parsed_options)) = self.parse_new_worker(new_expr) {
-      self.add_dependencies(new_expr, parsed_path, parsed_options);
+      self.add_dependencies(new_expr.span, parsed_path, parsed_options, false);
     } else {
       new_expr.visit_children_with(self);
     }
   }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if let Some((parsed_path, parsed_options, worklet)) = self.parse_worker_call(call_expr) {
+      self.add_dependencies(call_expr.span, parsed_path, parsed_options, worklet);
+    } else {
+      call_expr.visit_children_with(self);
+    }
+  }
 }
 
 #[derive(Debug)]
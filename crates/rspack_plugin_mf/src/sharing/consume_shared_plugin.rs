@@ -14,6 +14,7 @@ use rspack_core::{
   RuntimeGlobals, ThisCompilationArgs,
 };
 use rspack_error::{error, Diagnostic};
+use rspack_fs::InputFileSystem;
 use rustc_hash::FxHashMap;
 
 use super::{
@@ -96,21 +97,31 @@ fn resolve_matched_configs(
   }
 }
 
-async fn get_description_file(mut dir: &Path) -> Option<(PathBuf, serde_json::Value)> {
-  let description_filename = "package.json";
-  loop {
-    let description_file = dir.join(description_filename);
-    if let Ok(data) = tokio::fs::read(&description_file).await
-      && let Ok(data) = serde_json::from_slice::<serde_json::Value>(&data)
-    {
-      return Some((description_file, data));
-    }
-    if let Some(parent) = dir.parent() {
-      dir = parent;
-    } else {
-      return None;
+async fn get_description_file(
+  fs: Arc<dyn InputFileSystem>,
+  dir: &Path,
+) -> Option<(PathBuf, serde_json::Value)> {
+  let dir = dir.to_path_buf();
+  tokio::task::spawn_blocking(move || {
+    let description_filename = "package.json";
+    let mut dir = dir.as_path();
+    loop {
+      let description_file = dir.join(description_filename);
+      if let Ok(data) = fs.read(&description_file)
+        && let Ok(data) = serde_json::from_slice::<serde_json::Value>(&data)
+      {
+        return Some((description_file, data));
+      }
+      if let Some(parent) = dir.parent() {
+        dir = parent;
+      } else {
+        return None;
+      }
     }
-  }
+  })
+  .await
+  .ok()
+  .flatten()
 }
 
 fn get_required_version_from_description_file(
@@ -145,6 +156,7 @@ pub struct ConsumeSharedPlugin {
   options: ConsumeSharedPluginOptions,
   resolver: Mutex<Option<Arc<Resolver>>>,
   compiler_context: Mutex<Option<Context>>,
+  input_filesystem: Mutex<Option<Arc<dyn InputFileSystem>>>,
   matched_consumes: Mutex<Option<Arc<MatchedConsumes>>>,
 }
 
@@ -154,6 +166,7 @@ impl ConsumeSharedPlugin {
       options,
       resolver: Default::default(),
       compiler_context: Default::default(),
+      input_filesystem: Default::default(),
       matched_consumes: Default::default(),
     }
   }
@@ -168,6 +181,16 @@ impl ConsumeSharedPlugin {
     lock.clone().expect("init_context first")
   }
 
+  fn init_input_filesystem(&self, compilation: &Compilation) {
+    let mut lock = self.input_filesystem.lock().expect("should lock");
+    *lock = Some(compilation.options.input_filesystem.clone());
+  }
+
+  fn get_input_filesystem(&self) -> Arc<dyn InputFileSystem> {
+    let lock = self.input_filesystem.lock().expect("should lock");
+    lock.clone().expect("init_input_filesystem first")
+  }
+
   fn init_resolver(&self, compilation: &Compilation) {
     let mut lock = self.resolver.lock().expect("should lock");
     *lock = Some(
@@ -226,7 +249,8 @@ impl ConsumeSharedPlugin {
         return None;
       };
       if let Some(package_name) = package_name
-        && let Some((description_path, data)) = get_description_file(context.as_ref()).await
+        && let Some((description_path, data)) =
+          get_description_file(self.get_input_filesystem(), context.as_ref()).await
       {
         if let Some(name) = data.get("name").and_then(|n| n.as_str())
           && name == package_name
@@ -329,6 +353,7 @@ impl Plugin for ConsumeSharedPlugin {
       params.normal_module_factory.clone(),
     );
     self.init_context(args.this_compilation);
+    self.init_input_filesystem(args.this_compilation);
     self.init_resolver(args.this_compilation);
     self.init_matched_consumes(args.this_compilation, self.get_resolver());
     Ok(())
@@ -0,0 +1,151 @@
+// Port of https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/NormalModuleReplacementPlugin.js
+
+use std::{fmt, path::Path};
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use rspack_core::{
+  parse_resource, NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs, Plugin,
+  PluginContext, PluginNormalModuleFactoryAfterResolveOutput,
+  PluginNormalModuleFactoryBeforeResolveOutput, ResourceData, ResourceParsedData,
+};
+use rspack_error::Result;
+use rspack_regex::RspackRegex;
+use sugar_path::SugarPath;
+
+/// `resolveData` of webpack, passed to [NewResourceFn].
+#[derive(Debug, Clone)]
+pub struct ResolveData {
+  pub request: String,
+  pub context: String,
+  /// Only present after resolve, when the resolved resource is matched
+  pub create_data: Option<CreateData>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateData {
+  pub resource: String,
+}
+
+/// Called with the matched data, returns it with the replacement applied.
+pub type NewResourceFn =
+  Box<dyn Fn(ResolveData) -> BoxFuture<'static, Result<ResolveData>> + Sync + Send>;
+
+pub enum NewResource {
+  /// Request replacing the matched request, or resource replacing the matched resource.
+  /// A relative resource is resolved against the directory of the matched resource.
+  String(String),
+  Fn(NewResourceFn),
+}
+
+impl fmt::Debug for NewResource {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::String(request) => f.debug_tuple("String").field(request).finish(),
+      Self::Fn(_) => f.debug_tuple("Fn").finish(),
+    }
+  }
+}
+
+/// Replaces requests matching `resource_reg_exp` before they are resolved, and resources
+/// matching it after they are resolved.
+#[derive(Debug)]
+pub struct NormalModuleReplacementPlugin {
+  resource_reg_exp: RspackRegex,
+  new_resource: NewResource,
+}
+
+impl NormalModuleReplacementPlugin {
+  pub fn new(resource_reg_exp: RspackRegex, new_resource: NewResource) -> Self {
+    Self {
+      resource_reg_exp,
+      new_resource,
+    }
+  }
+}
+
+#[async_trait]
+impl Plugin for NormalModuleReplacementPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.NormalModuleReplacementPlugin"
+  }
+
+  async fn before_resolve(
+    &self,
+    _ctx: PluginContext,
+    args: &mut NormalModuleBeforeResolveArgs,
+  ) -> PluginNormalModuleFactoryBeforeResolveOutput {
+    if !self.resource_reg_exp.test(&args.request) {
+      return Ok(None);
+    }
+    match &self.new_resource {
+      NewResource::String(request) => args.request = request.clone(),
+      NewResource::Fn(f) => {
+        let data = f(ResolveData {
+          request: args.request.clone(),
+          context: args.context.clone(),
+          create_data: None,
+        })
+        .await?;
+        args.request = data.request;
+        args.context = data.context;
+      }
+    }
+    Ok(None)
+  }
+
+  async fn after_resolve(
+    &self,
+    _ctx: PluginContext,
+    args: &mut NormalModuleAfterResolveArgs<'_>,
+  ) -> PluginNormalModuleFactoryAfterResolveOutput {
+    if !self.resource_reg_exp.test(&args.resource_data.resource) {
+      return Ok(None);
+    }
+    match &self.new_resource {
+      NewResource::String(resource) => {
+        let resource = if Path::new(resource).is_absolute() {
+          resource.clone()
+        } else {
+          let dir = args
+            .resource_data
+            .resource_path
+            .parent()
+            .unwrap_or_else(|| Path::new(""));
+          dir.join(resource).normalize().to_string_lossy().to_string()
+        };
+        replace_resource(args.resource_data, resource);
+      }
+      NewResource::Fn(f) => {
+        let data = f(ResolveData {
+          request: args.request.to_string(),
+          context: args.context.to_string(),
+          create_data: Some(CreateData {
+            resource: args.resource_data.resource.clone(),
+          }),
+        })
+        .await?;
+        if let Some(create_data) = data.create_data {
+          if create_data.resource != args.resource_data.resource {
+            replace_resource(args.resource_data, create_data.resource);
+          }
+        }
+      }
+    }
+    Ok(None)
+  }
+}
+
+fn replace_resource(resource_data: &mut ResourceData, resource: String) {
+  let Some(ResourceParsedData {
+    path,
+    query,
+    fragment,
+  }) = parse_resource(&resource)
+  else {
+    return;
+  };
+  *resource_data = ResourceData::new(resource, path)
+    .query_optional(query)
+    .fragment_optional(fragment);
+}
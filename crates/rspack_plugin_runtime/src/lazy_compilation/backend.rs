@@ -0,0 +1,166 @@
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::{Arc, RwLock};
+
+use rspack_error::{error, Result};
+
+const PREFIX: &str = "/lazy-compilation-using-";
+
+/// Decides whether a lazily compiled module is built for real.
+///
+/// Modules are keyed by the absolute resource path of the real module.
+pub trait LazyCompilationBackend: Debug + Send + Sync {
+  /// The url requested by the client to activate a module, followed by the encoded key.
+  fn client_url(&self) -> &str;
+  fn is_active(&self, key: &str) -> bool;
+  /// Activate a module without a request from the client.
+  fn activate(&self, key: &str);
+}
+
+pub type OnActivate = Box<dyn Fn(&str) + Send + Sync>;
+
+/// Serves activation requests over http on the loopback interface.
+///
+/// `on_activate` is called with the key of every module activated by the client, the embedder
+/// should rebuild with the key as a changed file so the proxy is replaced by the real module.
+/// The server keeps running until the process exits.
+#[derive(Debug)]
+pub struct LoopbackBackend {
+  address: SocketAddr,
+  client_url: String,
+  active: Arc<RwLock<HashSet<String>>>,
+}
+
+impl LoopbackBackend {
+  /// Listen on `127.0.0.1:port`, a random port is picked when `port` is zero.
+  pub fn new(port: u16, on_activate: OnActivate) -> Result<Self> {
+    let listener = TcpListener::bind(("127.0.0.1", port))
+      .map_err(|e| error!("Failed to start lazy compilation backend: {e}"))?;
+    let address = listener
+      .local_addr()
+      .map_err(|e| error!("Failed to start lazy compilation backend: {e}"))?;
+    let active: Arc<RwLock<HashSet<String>>> = Default::default();
+
+    let server_active = active.clone();
+    std::thread::spawn(move || {
+      for stream in listener.incoming().flatten() {
+        let Some(key) = handle_request(stream) else {
+          continue;
+        };
+        // release the lock before calling back, the rebuild triggered by `on_activate` reads it
+        let inserted = server_active
+          .write()
+          .expect("should lock active modules")
+          .insert(key.clone());
+        if inserted {
+          on_activate(&key);
+        }
+      }
+    });
+
+    Ok(Self {
+      address,
+      client_url: format!("http://{address}{PREFIX}"),
+      active,
+    })
+  }
+
+  pub fn address(&self) -> SocketAddr {
+    self.address
+  }
+}
+
+impl LazyCompilationBackend for LoopbackBackend {
+  fn client_url(&self) -> &str {
+    &self.client_url
+  }
+
+  fn is_active(&self, key: &str) -> bool {
+    self
+      .active
+      .read()
+      .expect("should lock active modules")
+      .contains(key)
+  }
+
+  fn activate(&self, key: &str) {
+    self
+      .active
+      .write()
+      .expect("should lock active modules")
+      .insert(key.to_string());
+  }
+}
+
+/// Activates modules through rebuilds of the embedder.
+///
+/// The client requests `client_url` followed by the encoded key, the embedder (e.g. the dev
+/// server) is expected to answer it and rebuild with the key as a changed file, which activates
+/// it in [super::LazyCompilationPlugin].
+#[derive(Debug)]
+pub struct RebuildBackend {
+  client_url: String,
+  active: RwLock<HashSet<String>>,
+}
+
+impl RebuildBackend {
+  pub fn new(client_url: String) -> Self {
+    Self {
+      client_url,
+      active: Default::default(),
+    }
+  }
+}
+
+impl LazyCompilationBackend for RebuildBackend {
+  fn client_url(&self) -> &str {
+    &self.client_url
+  }
+
+  fn is_active(&self, key: &str) -> bool {
+    self
+      .active
+      .read()
+      .expect("should lock active modules")
+      .contains(key)
+  }
+
+  fn activate(&self, key: &str) {
+    self
+      .active
+      .write()
+      .expect("should lock active modules")
+      .insert(key.to_string());
+  }
+}
+
+/// Reply to a `GET <PREFIX><key>` request and return the decoded key.
+fn handle_request(mut stream: TcpStream) -> Option<String> {
+  let mut reader = BufReader::new(&stream);
+  let mut request_line = String::new();
+  reader.read_line(&mut request_line).ok()?;
+  // drain the headers, closing a socket with unread data resets the connection
+  let mut header = String::new();
+  while reader.read_line(&mut header).is_ok_and(|n| n > 2) {
+    header.clear();
+  }
+
+  let key = request_line
+    .split(' ')
+    .nth(1)
+    .and_then(|path| path.strip_prefix(PREFIX))
+    .and_then(|key| urlencoding::decode(key).ok())
+    .map(|key| key.into_owned());
+  let status = if key.is_some() {
+    "200 OK"
+  } else {
+    "404 Not Found"
+  };
+  let _ = write!(
+    stream,
+    "HTTP/1.1 {status}\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+  );
+  key
+}
@@ -0,0 +1,55 @@
+use rspack_core::{
+  AsContextDependency, AsDependencyTemplate, Dependency, DependencyCategory, DependencyId,
+  DependencyType, ModuleDependency,
+};
+
+/// Dependency from an active [super::LazyCompilationProxyModule] to the real module.
+#[derive(Debug, Clone)]
+pub struct LazyCompilationDependency {
+  id: DependencyId,
+  request: String,
+}
+
+impl LazyCompilationDependency {
+  pub fn new(request: String) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+    }
+  }
+}
+
+impl Dependency for LazyCompilationDependency {
+  fn dependency_debug_name(&self) -> &'static str {
+    "LazyCompilationDependency"
+  }
+
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::LazyImport
+  }
+}
+
+impl ModuleDependency for LazyCompilationDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+}
+
+impl AsContextDependency for LazyCompilationDependency {}
+impl AsDependencyTemplate for LazyCompilationDependency {}
@@ -0,0 +1,104 @@
+mod backend;
+mod dependency;
+mod module;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+pub use backend::{LazyCompilationBackend, LoopbackBackend, OnActivate, RebuildBackend};
+pub use dependency::LazyCompilationDependency;
+pub use module::LazyCompilationProxyModule;
+use rspack_core::{
+  BoxModule, CompilationArgs, CompilationParams, DependencyType, NormalModuleCreateData, Plugin,
+  PluginCompilationHookOutput, PluginContext, PluginNormalModuleFactoryModuleHookOutput,
+};
+use rspack_identifier::Identifiable;
+use rspack_regex::RspackRegex;
+
+#[derive(Debug)]
+pub struct LazyCompilationOptions {
+  pub backend: Arc<dyn LazyCompilationBackend>,
+  /// Compile entries lazily
+  pub entries: bool,
+  /// Compile dynamic imports lazily
+  pub imports: bool,
+  /// Only compile resources matching this regex lazily
+  pub test: Option<RspackRegex>,
+}
+
+/// Replaces dynamic imports and entries with proxies, the real modules are only built after
+/// the client requests them from the backend.
+///
+/// Files changed in a rebuild, i.e. [rspack_core::Compilation::lazy_visit_modules], are
+/// activated as well.
+#[derive(Debug)]
+pub struct LazyCompilationPlugin {
+  options: LazyCompilationOptions,
+}
+
+impl LazyCompilationPlugin {
+  pub fn new(options: LazyCompilationOptions) -> Self {
+    Self { options }
+  }
+
+  fn should_proxy(&self, args: &NormalModuleCreateData<'_>) -> bool {
+    let enabled = match args.dependency_type {
+      DependencyType::DynamicImport => self.options.imports,
+      DependencyType::Entry => self.options.entries,
+      _ => false,
+    };
+    let resource = &args.resource_resolve_data.resource;
+    // the dev client delivers the activated modules, so it can't be lazy itself
+    enabled
+      && !resource.contains("rspack-dev-client")
+      && self
+        .options
+        .test
+        .as_ref()
+        .map_or(true, |test| test.test(resource))
+  }
+}
+
+#[async_trait]
+impl Plugin for LazyCompilationPlugin {
+  fn name(&self) -> &'static str {
+    "LazyCompilationPlugin"
+  }
+
+  async fn compilation(
+    &self,
+    args: CompilationArgs<'_>,
+    params: &CompilationParams,
+  ) -> PluginCompilationHookOutput {
+    args.compilation.set_dependency_factory(
+      DependencyType::LazyImport,
+      params.normal_module_factory.clone(),
+    );
+    for key in &args.compilation.lazy_visit_modules {
+      self.options.backend.activate(key);
+    }
+    Ok(())
+  }
+
+  async fn normal_module_factory_module(
+    &self,
+    _ctx: PluginContext,
+    module: BoxModule,
+    args: &mut NormalModuleCreateData<'_>,
+  ) -> PluginNormalModuleFactoryModuleHookOutput {
+    if !self.should_proxy(args) {
+      return Ok(module);
+    }
+    Ok(Box::new(LazyCompilationProxyModule::new(
+      module.identifier(),
+      args
+        .resource_resolve_data
+        .resource_path
+        .to_string_lossy()
+        .to_string(),
+      args.resolve_data_request.to_string(),
+      args.context.clone(),
+      self.options.backend.clone(),
+    )))
+  }
+}
@@ -0,0 +1,246 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use rspack_core::{
+  impl_build_info_meta, impl_source_map_config, module_id, module_namespace_promise,
+  rspack_sources::{RawSource, Source, SourceExt},
+  AsyncDependenciesBlock, AsyncDependenciesBlockId, BuildContext, BuildInfo, BuildMeta,
+  BuildResult, Compilation, ConcatenationScope, DependenciesBlock, DependencyId, Module,
+  ModuleType, RuntimeGlobals, RuntimeSpec, SourceType, TemplateContext,
+};
+use rspack_core::{CodeGenerationResult, Context, ModuleIdentifier};
+use rspack_error::{impl_empty_diagnosable_trait, Result};
+use rspack_hash::RspackHash;
+use rspack_identifier::Identifiable;
+use rspack_util::source_map::SourceMapKind;
+
+use super::{LazyCompilationBackend, LazyCompilationDependency};
+
+/// Placeholder of a lazily compiled module.
+///
+/// Until the backend activates its key, the proxy only asks the backend to activate it and
+/// exports a pending promise. Once active, it depends on the real module through an async
+/// block and resolves the previous promise through hot module replacement.
+#[impl_source_map_config]
+#[derive(Debug)]
+pub struct LazyCompilationProxyModule {
+  dependencies: Vec<DependencyId>,
+  blocks: Vec<AsyncDependenciesBlockId>,
+  pub module_identifier: ModuleIdentifier,
+  original_identifier: ModuleIdentifier,
+  /// Key of the module in the backend, the absolute resource path of the real module
+  key: String,
+  request: String,
+  context: Context,
+  backend: Arc<dyn LazyCompilationBackend>,
+  active: bool,
+  build_info: Option<BuildInfo>,
+  build_meta: Option<BuildMeta>,
+}
+
+impl LazyCompilationProxyModule {
+  pub fn new(
+    original_identifier: ModuleIdentifier,
+    key: String,
+    request: String,
+    context: Context,
+    backend: Arc<dyn LazyCompilationBackend>,
+  ) -> Self {
+    Self {
+      dependencies: Vec::new(),
+      blocks: Vec::new(),
+      module_identifier: ModuleIdentifier::from(format!(
+        "lazy-compilation-proxy|{original_identifier}"
+      )),
+      original_identifier,
+      key,
+      request,
+      context,
+      backend,
+      active: false,
+      build_info: None,
+      build_meta: None,
+      source_map_kind: SourceMapKind::None,
+    }
+  }
+}
+
+impl DependenciesBlock for LazyCompilationProxyModule {
+  fn add_block_id(&mut self, block: AsyncDependenciesBlockId) {
+    self.blocks.push(block)
+  }
+
+  fn get_blocks(&self) -> &[AsyncDependenciesBlockId] {
+    &self.blocks
+  }
+
+  fn add_dependency_id(&mut self, dependency: DependencyId) {
+    self.dependencies.push(dependency)
+  }
+
+  fn get_dependencies(&self) -> &[DependencyId] {
+    &self.dependencies
+  }
+}
+
+#[async_trait]
+impl Module for LazyCompilationProxyModule {
+  impl_build_info_meta!();
+
+  fn module_type(&self) -> &ModuleType {
+    &ModuleType::Js
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    &[SourceType::JavaScript]
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+  fn get_diagnostics(&self) -> Vec<rspack_error::Diagnostic> {
+    vec![]
+  }
+  fn readable_identifier(&self, context: &Context) -> Cow<str> {
+    Cow::Owned(format!(
+      "lazy-compilation-proxy {}",
+      context.shorten(&self.original_identifier)
+    ))
+  }
+
+  fn size(&self, _source_type: &SourceType) -> f64 {
+    200.0
+  }
+
+  fn get_context(&self) -> Option<Box<Context>> {
+    Some(Box::new(self.context.clone()))
+  }
+
+  async fn build(
+    &mut self,
+    build_context: BuildContext<'_>,
+    _: Option<&Compilation>,
+  ) -> Result<BuildResult> {
+    self.active = self.backend.is_active(&self.key);
+
+    let mut hasher = RspackHash::from(&build_context.compiler_options.output);
+    self.update_hash(&mut hasher);
+    let hash = hasher.digest(&build_context.compiler_options.output.hash_digest);
+
+    let mut blocks = vec![];
+    if self.active {
+      let mut block = AsyncDependenciesBlock::new(self.module_identifier, None);
+      block.add_dependency(Box::new(LazyCompilationDependency::new(
+        self.request.clone(),
+      )));
+      blocks.push(block);
+    }
+
+    let mut build_info = BuildInfo {
+      hash: Some(hash),
+      ..Default::default()
+    };
+    // the real module is requested as a changed file when activated,
+    // so the proxy needs to be rebuilt for it as well
+    build_info
+      .file_dependencies
+      .insert(std::path::PathBuf::from(&self.key));
+
+    Ok(BuildResult {
+      build_info,
+      build_meta: Default::default(),
+      blocks,
+      ..Default::default()
+    })
+  }
+
+  fn code_generation(
+    &self,
+    compilation: &Compilation,
+    runtime: Option<&RuntimeSpec>,
+    _: Option<ConcatenationScope>,
+  ) -> Result<CodeGenerationResult> {
+    let mut cgr = CodeGenerationResult::default();
+    cgr.runtime_requirements.insert(RuntimeGlobals::MODULE);
+
+    let source = if let Some(block_id) = self.blocks.first() {
+      let block = block_id.expect_get(compilation);
+      let dep_id = block
+        .get_dependencies()
+        .first()
+        .expect("active LazyCompilationProxyModule should have a dependency");
+      let mut init_fragments = vec![];
+      let mut template_context = TemplateContext {
+        compilation,
+        module: self,
+        runtime_requirements: &mut cgr.runtime_requirements,
+        init_fragments: &mut init_fragments,
+        runtime,
+        concatenation_scope: None,
+      };
+      let promise = module_namespace_promise(
+        &mut template_context,
+        dep_id,
+        Some(block_id),
+        &self.request,
+        "import()",
+        false,
+      );
+      format!(
+        r#"module.exports = {promise};
+if (module.hot) {{
+	module.hot.accept();
+	module.hot.accept({}, function () {{ module.hot.invalidate(); }});
+	module.hot.dispose(function (data) {{ delete data.resolveSelf; }});
+	if (module.hot.data && module.hot.data.resolveSelf) module.hot.data.resolveSelf(module.exports);
+}}"#,
+        module_id(compilation, dep_id, &self.request, false)
+      )
+    } else {
+      include_str!("../runtime/lazy_compilation.js")
+        .replace(
+          "$KEY$",
+          &serde_json::to_string(&self.key).expect("should be able to json stringify"),
+        )
+        .replace(
+          "$CLIENT_URL$",
+          &serde_json::to_string(self.backend.client_url())
+            .expect("should be able to json stringify"),
+        )
+    };
+
+    cgr.add(SourceType::JavaScript, RawSource::from(source).boxed());
+    cgr.set_hash(
+      &compilation.options.output.hash_function,
+      &compilation.options.output.hash_digest,
+      &compilation.options.output.hash_salt,
+    );
+    Ok(cgr)
+  }
+}
+
+impl Identifiable for LazyCompilationProxyModule {
+  fn identifier(&self) -> ModuleIdentifier {
+    self.module_identifier
+  }
+}
+
+impl_empty_diagnosable_trait!(LazyCompilationProxyModule);
+
+impl Hash for LazyCompilationProxyModule {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    "__rspack_internal__LazyCompilationProxyModule".hash(state);
+    self.identifier().hash(state);
+    self.active.hash(state);
+  }
+}
+
+impl PartialEq for LazyCompilationProxyModule {
+  fn eq(&self, other: &Self) -> bool {
+    self.identifier() == other.identifier()
+  }
+}
+
+impl Eq for LazyCompilationProxyModule {}
@@ -1,8 +1,12 @@
 #![feature(get_mut_unchecked)]
+#![feature(let_chains)]
 mod helpers;
 pub use helpers::*;
 mod lazy_compilation;
-pub use lazy_compilation::LazyCompilationPlugin;
+pub use lazy_compilation::{
+  LazyCompilationBackend, LazyCompilationOptions, LazyCompilationPlugin, LoopbackBackend,
+  OnActivate, RebuildBackend,
+};
 mod common_js_chunk_format;
 pub use common_js_chunk_format::CommonJsChunkFormatPlugin;
 mod runtime_plugin;
@@ -86,10 +86,6 @@ impl Plugin for ModuleChunkFormatPlugin {
     let compilation = args.compilation;
     let chunk = args.chunk();
     let base_chunk_output_name = get_chunk_output_name(chunk, compilation);
-    if matches!(chunk.kind, ChunkKind::HotUpdate) {
-      unreachable!("HMR is not implemented for module chunk format yet");
-    }
-
     let mut sources = ConcatSource::default();
     sources.add(RawSource::from(format!(
       "export const ids = ['{}'];\n",
@@ -109,6 +105,11 @@ impl Plugin for ModuleChunkFormatPlugin {
       sources.add(RawSource::from(";\n"));
     }
 
+    // Hot update chunks are imported by the HMR runtime, which only reads `modules` and `runtime`
+    if matches!(chunk.kind, ChunkKind::HotUpdate) {
+      return Ok(Some(sources.boxed()));
+    }
+
     if chunk.has_entry_module(&compilation.chunk_graph) {
       let runtime_chunk_output_name = get_runtime_chunk_output_name(args)?;
       sources.add(RawSource::from(format!(
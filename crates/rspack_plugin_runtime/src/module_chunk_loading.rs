@@ -36,6 +36,17 @@ impl Plugin for ModuleChunkLoadingPlugin {
           has_chunk_loading = true;
           runtime_requirements_mut.insert(RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME);
         }
+        RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS if is_enabled_for_chunk => {
+          has_chunk_loading = true;
+          runtime_requirements_mut.insert(RuntimeGlobals::GET_CHUNK_UPDATE_SCRIPT_FILENAME);
+          runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+          runtime_requirements_mut.insert(RuntimeGlobals::HMR_MODULE_DATA);
+        }
+        RuntimeGlobals::HMR_DOWNLOAD_MANIFEST if is_enabled_for_chunk => {
+          has_chunk_loading = true;
+          runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+          runtime_requirements_mut.insert(RuntimeGlobals::GET_UPDATE_MANIFEST_FILENAME);
+        }
         RuntimeGlobals::EXTERNAL_INSTALL_CHUNK if is_enabled_for_chunk => {
           has_chunk_loading = true;
           compilation
@@ -1,19 +1,23 @@
-// var client = __webpack_require__("./hot/lazy-compilation-web.js?lazy-compilation-using-")
-// var data = "$MODULE_ID$";
-module.exports = __webpack_require__.l(
-	"./lazy-compilation-web/$CHUNK_ID$",
-	function () {}
-); //.then(__webpack_require__.t.bind(__webpack_require__, "$MODULE_ID$", 23));
+var data = $KEY$;
+var resolveSelf, onError;
+module.exports = new Promise(function (resolve, reject) {
+	resolveSelf = resolve;
+	onError = reject;
+});
 if (module.hot) {
 	module.hot.accept();
-	// module.hot.accept("$MODULE_ID$", function() { module.hot.invalidate(); });
-	// module.hot.dispose(function(data) {
-	//     delete data.resolveSelf;
-	//     //dispose(data);
-	// });
-	// if (module.hot.data && module.hot.data.resolveSelf) module.hot.data.resolveSelf(module.exports);
+	if (module.hot.data && module.hot.data.resolveSelf)
+		module.hot.data.resolveSelf(module.exports);
+	module.hot.dispose(function (data) {
+		data.resolveSelf = resolveSelf;
+	});
 }
-function onError() {
-	/* ignore */
-}
-// var dispose = client.keepAlive({ data: data, active: true, module: module, onError: onError });
+fetch($CLIENT_URL$ + encodeURIComponent(data))
+	.then(function (res) {
+		if (!res.ok) {
+			throw new Error(
+				"Failed to activate lazy compiled module " + data + ": " + res.status
+			);
+		}
+	})
+	.catch(onError);
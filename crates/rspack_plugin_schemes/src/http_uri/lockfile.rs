@@ -0,0 +1,74 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use rspack_error::{error, Result};
+use rspack_fs::{InputFileSystem, IntermediateFileSystem};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha512};
+
+use super::write_file;
+
+const LOCKFILE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockfileEntry {
+  /// Subresource integrity of the content, e.g. `sha512-...`
+  pub integrity: String,
+  pub content_type: String,
+}
+
+/// Fetched urls with the integrity of their content, committed along with the project so
+/// builds are reproducible.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Lockfile {
+  version: u32,
+  #[serde(flatten)]
+  entries: BTreeMap<String, LockfileEntry>,
+}
+
+impl Lockfile {
+  /// Read the lockfile at `path`, an empty lockfile is returned when it doesn't exist.
+  pub fn read(fs: &dyn InputFileSystem, path: &Path) -> Result<Self> {
+    let content = match fs.read(path) {
+      Ok(content) => content,
+      Err(rspack_fs::Error::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+        return Ok(Self {
+          version: LOCKFILE_VERSION,
+          entries: Default::default(),
+        })
+      }
+      Err(e) => return Err(error!("Failed to read lockfile {}: {e}", path.display())),
+    };
+    let lockfile: Self = serde_json::from_slice(&content)
+      .map_err(|e| error!("Failed to parse lockfile {}: {e}", path.display()))?;
+    if lockfile.version != LOCKFILE_VERSION {
+      return Err(error!(
+        "Unsupported lockfile version {} in {}",
+        lockfile.version,
+        path.display()
+      ));
+    }
+    Ok(lockfile)
+  }
+
+  pub fn write(&self, fs: &dyn IntermediateFileSystem, path: &Path) -> Result<()> {
+    let mut content = serde_json::to_string_pretty(self)
+      .map_err(|e| error!("Failed to serialize lockfile: {e}"))?;
+    content.push('\n');
+    write_file(fs, path, content.as_bytes())
+  }
+
+  pub fn get(&self, url: &str) -> Option<&LockfileEntry> {
+    self.entries.get(url)
+  }
+
+  pub fn insert(&mut self, url: String, entry: LockfileEntry) {
+    self.entries.insert(url, entry);
+  }
+}
+
+pub fn compute_integrity(content: &[u8]) -> String {
+  let digest = Sha512::digest(content);
+  format!("sha512-{}", rspack_base64::encode_to_string(digest))
+}
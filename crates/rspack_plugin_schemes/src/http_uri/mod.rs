@@ -0,0 +1,391 @@
+mod lockfile;
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use rspack_core::{
+  CompilationArgs, CompilationParams, CompilerOptions, Content, Plugin,
+  PluginCompilationHookOutput, PluginContext, PluginNormalModuleFactoryResolveForSchemeOutput,
+  PluginReadResourceOutput, ResourceData,
+};
+use rspack_error::{error, Result};
+use rspack_fs::{InputFileSystem, IntermediateFileSystem};
+use rspack_regex::RspackRegex;
+
+pub use self::lockfile::LockfileEntry;
+use self::lockfile::{compute_integrity, Lockfile};
+
+#[derive(Debug)]
+pub struct HttpResponse {
+  pub status: u16,
+  /// Header names are lower cased
+  pub headers: HashMap<String, String>,
+  pub body: Vec<u8>,
+}
+
+/// Performs the requests of [HttpUriPlugin], redirects should be followed by the client.
+#[async_trait]
+pub trait HttpClient: Debug + Send + Sync {
+  async fn get(&self, url: &str) -> Result<HttpResponse>;
+}
+
+#[derive(Debug, Clone)]
+pub enum AllowedUri {
+  Prefix(String),
+  Regex(RspackRegex),
+}
+
+impl AllowedUri {
+  fn is_allowed(&self, url: &str) -> bool {
+    match self {
+      Self::Prefix(prefix) => url.starts_with(prefix),
+      Self::Regex(regex) => regex.test(url),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct HttpUriOptions {
+  /// Only urls matching one of these are fetched
+  pub allowed_uris: Vec<AllowedUri>,
+  pub lockfile_location: PathBuf,
+  /// Directory storing fetched content by its integrity, content is fetched in every
+  /// build when it's `None`
+  pub cache_location: Option<PathBuf>,
+  /// Build from the lockfile and the cache only, nothing is fetched or written
+  pub frozen: bool,
+  /// Refetch urls already in the lockfile and update their entries
+  pub upgrade: bool,
+  pub client: Arc<dyn HttpClient>,
+}
+
+#[derive(Debug, Clone)]
+struct FileSystems {
+  input: Arc<dyn InputFileSystem>,
+  intermediate: Arc<dyn IntermediateFileSystem>,
+}
+
+/// Resolves and reads `http://` and `https://` requests.
+///
+/// The integrity of every fetched url is recorded in the lockfile, content that doesn't match
+/// its entry fails the build unless `upgrade` is enabled. The lockfile and the cache are read
+/// from the input file system of the compiler and written to its intermediate file system.
+#[derive(Debug)]
+pub struct HttpUriPlugin {
+  options: HttpUriOptions,
+  lockfile: Mutex<Option<Lockfile>>,
+  file_systems: Mutex<Option<FileSystems>>,
+}
+
+impl HttpUriPlugin {
+  pub fn new(options: HttpUriOptions) -> Self {
+    Self {
+      options,
+      lockfile: Mutex::new(None),
+      file_systems: Mutex::new(None),
+    }
+  }
+
+  fn init_file_systems(&self, options: &CompilerOptions) {
+    let mut lock = self.file_systems.lock().expect("should lock");
+    *lock = Some(FileSystems {
+      input: options.input_filesystem.clone(),
+      intermediate: options.intermediate_filesystem.clone(),
+    });
+  }
+
+  fn get_file_systems(&self) -> FileSystems {
+    let lock = self.file_systems.lock().expect("should lock");
+    lock.clone().expect("init_file_systems first")
+  }
+
+  fn with_lockfile<T>(&self, f: impl FnOnce(&mut Lockfile) -> Result<T>) -> Result<T> {
+    let mut lockfile = self.lockfile.lock().expect("should lock lockfile");
+    if lockfile.is_none() {
+      *lockfile = Some(Lockfile::read(
+        &*self.get_file_systems().input,
+        &self.options.lockfile_location,
+      )?);
+    }
+    f(lockfile.as_mut().expect("should have lockfile"))
+  }
+
+  fn cache_path(&self, integrity: &str) -> Option<PathBuf> {
+    let cache_location = self.options.cache_location.as_ref()?;
+    let digest = rspack_base64::decode_to_vec(integrity.trim_start_matches("sha512-")).ok()?;
+    let name: String = digest.iter().map(|byte| format!("{byte:02x}")).collect();
+    Some(cache_location.join(name))
+  }
+
+  fn read_cache(&self, entry: &LockfileEntry) -> Option<Vec<u8>> {
+    let content = self
+      .get_file_systems()
+      .input
+      .read(&self.cache_path(&entry.integrity)?)
+      .ok()?;
+    // the cache directory might have been tampered with
+    (compute_integrity(&content) == entry.integrity).then_some(content)
+  }
+
+  fn write_cache(&self, integrity: &str, content: &[u8]) -> Result<()> {
+    let Some(path) = self.cache_path(integrity) else {
+      return Ok(());
+    };
+    write_file(&*self.get_file_systems().intermediate, &path, content)
+  }
+
+  async fn fetch(&self, url: &str) -> Result<(Vec<u8>, LockfileEntry)> {
+    let response = self.options.client.get(url).await?;
+    if !(200..300).contains(&response.status) {
+      return Err(error!(
+        "Failed to fetch {url}: server responded with status {}",
+        response.status
+      ));
+    }
+    let content_type = response
+      .headers
+      .get("content-type")
+      .cloned()
+      .unwrap_or_default();
+    let integrity = compute_integrity(&response.body);
+    Ok((
+      response.body,
+      LockfileEntry {
+        integrity,
+        content_type,
+      },
+    ))
+  }
+
+  async fn read(&self, url: &str) -> Result<Vec<u8>> {
+    let locked = self.with_lockfile(|lockfile| Ok(lockfile.get(url).cloned()))?;
+
+    if let Some(entry) = &locked
+      && !self.options.upgrade
+      && let Some(content) = self.read_cache(entry)
+    {
+      return Ok(content);
+    }
+
+    if self.options.frozen {
+      return Err(match locked {
+        Some(_) => error!("{url} is not cached and can't be fetched as the lockfile is frozen"),
+        None => error!("{url} has no lockfile entry and the lockfile is frozen"),
+      });
+    }
+
+    let (content, entry) = self.fetch(url).await?;
+    if let Some(locked) = &locked
+      && !self.options.upgrade
+      && locked.integrity != entry.integrity
+    {
+      return Err(error!(
+        "Content of {url} doesn't match the integrity in the lockfile, expected {} but received {}. Enable upgrade to update the lockfile",
+        locked.integrity,
+        entry.integrity
+      ));
+    }
+
+    self.write_cache(&entry.integrity, &content)?;
+    if locked.as_ref() != Some(&entry) {
+      self.with_lockfile(|lockfile| {
+        lockfile.insert(url.to_string(), entry);
+        lockfile.write(
+          &*self.get_file_systems().intermediate,
+          &self.options.lockfile_location,
+        )
+      })?;
+    }
+    Ok(content)
+  }
+}
+
+#[async_trait]
+impl Plugin for HttpUriPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.HttpUriPlugin"
+  }
+
+  async fn compilation(
+    &self,
+    args: CompilationArgs<'_>,
+    _params: &CompilationParams,
+  ) -> PluginCompilationHookOutput {
+    self.init_file_systems(&args.compilation.options);
+    Ok(())
+  }
+
+  async fn normal_module_factory_resolve_for_scheme(
+    &self,
+    _ctx: PluginContext,
+    resource_data: ResourceData,
+  ) -> PluginNormalModuleFactoryResolveForSchemeOutput {
+    if !resource_data.get_scheme().is_http() {
+      return Ok((resource_data, false));
+    }
+    let url = &resource_data.resource;
+    if !self
+      .options
+      .allowed_uris
+      .iter()
+      .any(|allowed| allowed.is_allowed(url))
+    {
+      return Err(error!(
+        "{url} doesn't match the allowedUris policy. These URIs are allowed:\n{}",
+        self
+          .options
+          .allowed_uris
+          .iter()
+          .map(|allowed| match allowed {
+            AllowedUri::Prefix(prefix) => format!(" - {prefix}"),
+            AllowedUri::Regex(regex) => format!(" - {regex:?}"),
+          })
+          .collect::<Vec<_>>()
+          .join("\n")
+      ));
+    }
+    Ok((resource_data, true))
+  }
+
+  async fn read_resource(&self, resource_data: &ResourceData) -> PluginReadResourceOutput {
+    if !resource_data.get_scheme().is_http() {
+      return Ok(None);
+    }
+    let content = self.read(&resource_data.resource).await?;
+    Ok(Some(Content::Buffer(content)))
+  }
+}
+
+fn write_file(fs: &dyn IntermediateFileSystem, path: &Path, content: &[u8]) -> Result<()> {
+  if let Some(dir) = path.parent() {
+    fs.create_dir_all(dir)
+      .map_err(|e| error!("Failed to create {}: {e}", dir.display()))?;
+  }
+  fs.write(path, content)
+    .map_err(|e| error!("Failed to write {}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod test {
+  use std::sync::atomic::{AtomicUsize, Ordering};
+
+  use rspack_fs::MemoryFileSystem;
+
+  use super::*;
+
+  #[derive(Debug, Default)]
+  struct StandInServer {
+    files: Mutex<HashMap<String, Vec<u8>>>,
+    requests: AtomicUsize,
+  }
+
+  impl StandInServer {
+    fn serve(&self, url: &str, content: &str) {
+      self
+        .files
+        .lock()
+        .expect("should lock")
+        .insert(url.to_string(), content.as_bytes().to_vec());
+    }
+  }
+
+  #[async_trait]
+  impl HttpClient for StandInServer {
+    async fn get(&self, url: &str) -> Result<HttpResponse> {
+      self.requests.fetch_add(1, Ordering::SeqCst);
+      let body = self.files.lock().expect("should lock").get(url).cloned();
+      Ok(HttpResponse {
+        status: if body.is_some() { 200 } else { 404 },
+        headers: HashMap::from([(
+          "content-type".to_string(),
+          "application/javascript".to_string(),
+        )]),
+        body: body.unwrap_or_default(),
+      })
+    }
+  }
+
+  fn plugin(
+    fs: Arc<MemoryFileSystem>,
+    server: Arc<StandInServer>,
+    frozen: bool,
+    upgrade: bool,
+  ) -> HttpUriPlugin {
+    let dir = Path::new("/project");
+    let plugin = HttpUriPlugin::new(HttpUriOptions {
+      allowed_uris: vec![AllowedUri::Prefix("https://example.com/".to_string())],
+      lockfile_location: dir.join("rspack.lock"),
+      cache_location: Some(dir.join("rspack.lock.data")),
+      frozen,
+      upgrade,
+      client: server,
+    });
+    *plugin.file_systems.lock().expect("should lock") = Some(FileSystems {
+      input: fs.clone(),
+      intermediate: fs,
+    });
+    plugin
+  }
+
+  fn resource(url: &str) -> ResourceData {
+    ResourceData::new(url.to_string(), PathBuf::new())
+  }
+
+  async fn read(plugin: &HttpUriPlugin, url: &str) -> Result<Vec<u8>> {
+    match plugin.read_resource(&resource(url)).await? {
+      Some(Content::Buffer(content)) => Ok(content),
+      _ => unreachable!(),
+    }
+  }
+
+  #[tokio::test]
+  async fn should_build_from_lockfile() {
+    let fs = Arc::new(MemoryFileSystem::new());
+    let url = "https://example.com/a.js";
+    let server = Arc::new(StandInServer::default());
+    server.serve(url, "export default 1");
+
+    let fresh = plugin(fs.clone(), server.clone(), false, false);
+    assert_eq!(
+      read(&fresh, url).await.expect("should fetch"),
+      b"export default 1"
+    );
+    assert_eq!(server.requests.load(Ordering::SeqCst), 1);
+
+    // served from the cache while the content changes upstream
+    server.serve(url, "export default 2");
+    let frozen = plugin(fs.clone(), server.clone(), true, false);
+    assert_eq!(
+      read(&frozen, url).await.expect("should read cache"),
+      b"export default 1"
+    );
+    assert_eq!(server.requests.load(Ordering::SeqCst), 1);
+    assert!(read(&frozen, "https://example.com/b.js").await.is_err());
+
+    assert!(fs.read(Path::new("/project/rspack.lock")).is_ok());
+    fs.remove("/project/rspack.lock.data");
+    let locked = plugin(fs.clone(), server.clone(), false, false);
+    assert!(read(&locked, url).await.is_err());
+    let upgrade = plugin(fs.clone(), server.clone(), false, true);
+    assert_eq!(
+      read(&upgrade, url).await.expect("should upgrade"),
+      b"export default 2"
+    );
+
+    let (_, stop) = upgrade
+      .normal_module_factory_resolve_for_scheme(PluginContext::new(), resource(url))
+      .await
+      .expect("should be allowed");
+    assert!(stop);
+    assert!(upgrade
+      .normal_module_factory_resolve_for_scheme(
+        PluginContext::new(),
+        resource("https://evil.com/a.js")
+      )
+      .await
+      .is_err());
+  }
+}
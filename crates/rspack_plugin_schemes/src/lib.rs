@@ -2,6 +2,10 @@
 
 mod data_uri;
 mod file_uri;
+mod http_uri;
 
 pub use data_uri::DataUriPlugin;
 pub use file_uri::FileUriPlugin;
+pub use http_uri::{
+  AllowedUri, HttpClient, HttpResponse, HttpUriOptions, HttpUriPlugin, LockfileEntry,
+};
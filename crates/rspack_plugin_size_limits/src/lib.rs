@@ -0,0 +1,197 @@
+// Port of https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/performance/SizeLimitsPlugin.js
+
+use std::fmt;
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+use rspack_core::{Compilation, Plugin};
+use rspack_error::{Diagnostic, Result};
+
+/// Called with the name of an asset, returns `false` to leave the asset out of the size limits.
+pub type AssetFilterFn = Box<dyn Fn(String) -> BoxFuture<'static, Result<bool>> + Sync + Send>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PerformanceHints {
+  Warning,
+  Error,
+}
+
+pub struct SizeLimitsPluginOptions {
+  pub hints: PerformanceHints,
+  /// Max size of an asset in bytes
+  pub max_asset_size: f64,
+  /// Max size of the initial assets of an entrypoint in bytes
+  pub max_entrypoint_size: f64,
+  /// Source maps are left out when it's not given
+  pub asset_filter: Option<AssetFilterFn>,
+}
+
+impl fmt::Debug for SizeLimitsPluginOptions {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("SizeLimitsPluginOptions")
+      .field("hints", &self.hints)
+      .field("max_asset_size", &self.max_asset_size)
+      .field("max_entrypoint_size", &self.max_entrypoint_size)
+      .field("asset_filter", &self.asset_filter.as_ref().map(|_| "Fn"))
+      .finish()
+  }
+}
+
+/// Reports assets and entrypoints exceeding the size limits.
+#[derive(Debug)]
+pub struct SizeLimitsPlugin {
+  options: SizeLimitsPluginOptions,
+}
+
+impl SizeLimitsPlugin {
+  pub fn new(options: SizeLimitsPluginOptions) -> Self {
+    Self { options }
+  }
+
+  async fn filter_asset(&self, name: &str) -> Result<bool> {
+    match &self.options.asset_filter {
+      Some(asset_filter) => asset_filter(name.to_string()).await,
+      None => Ok(!name.ends_with(".map")),
+    }
+  }
+
+  fn create_diagnostic(&self, title: &str, message: String) -> Diagnostic {
+    match self.options.hints {
+      PerformanceHints::Warning => Diagnostic::warn(title.to_string(), message),
+      PerformanceHints::Error => Diagnostic::error(title.to_string(), message),
+    }
+  }
+}
+
+/// Same as `SizeFormatHelpers.formatSize` of webpack, e.g. `244 KiB`.
+fn format_size(size: f64) -> String {
+  const ABBREVIATIONS: [&str; 4] = ["bytes", "KiB", "MiB", "GiB"];
+  if size <= 0.0 {
+    return "0 bytes".to_string();
+  }
+  let index = ((size.ln() / 1024f64.ln()).floor() as usize).min(ABBREVIATIONS.len() - 1);
+  let value = size / 1024f64.powi(index as i32);
+  // three significant digits without trailing zeros
+  let precision = 2usize.saturating_sub(value.log10().floor() as usize);
+  let value = format!("{value:.precision$}");
+  let value = if value.contains('.') {
+    value.trim_end_matches('0').trim_end_matches('.')
+  } else {
+    value.as_str()
+  };
+  format!("{value} {}", ABBREVIATIONS[index])
+}
+
+#[async_trait]
+impl Plugin for SizeLimitsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.SizeLimitsPlugin"
+  }
+
+  async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
+    let mut asset_sizes = Vec::new();
+    for (name, asset) in compilation.assets() {
+      if asset.info.development || asset.info.hot_module_replacement {
+        continue;
+      }
+      let Some(source) = asset.get_source() else {
+        continue;
+      };
+      if !self.filter_asset(name).await? {
+        continue;
+      }
+      asset_sizes.push((name.clone(), source.size() as f64));
+    }
+    asset_sizes.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let size_of = |file: &str| {
+      asset_sizes
+        .iter()
+        .find(|(name, _)| name == file)
+        .map(|(_, size)| *size)
+    };
+
+    let assets_over_size_limit = asset_sizes
+      .iter()
+      .filter(|(_, size)| *size > self.options.max_asset_size)
+      .collect::<Vec<_>>();
+
+    let mut entrypoints_over_size_limit = Vec::new();
+    for (name, ukey) in compilation.entrypoints() {
+      let entrypoint = compilation.chunk_group_by_ukey.expect_get(ukey);
+      let mut files = entrypoint.get_files(&compilation.chunk_by_ukey);
+      files.sort();
+      files.dedup();
+      let files = files
+        .into_iter()
+        .filter_map(|file| size_of(&file).map(|size| (file, size)))
+        .collect::<Vec<_>>();
+      let size = files.iter().map(|(_, size)| size).sum::<f64>();
+      if size > self.options.max_entrypoint_size {
+        entrypoints_over_size_limit.push((name, size, files));
+      }
+    }
+
+    let mut diagnostics = Vec::new();
+    if !assets_over_size_limit.is_empty() {
+      let assets = assets_over_size_limit
+        .iter()
+        .map(|(name, size)| format!("\n  {name} ({})", format_size(*size)))
+        .collect::<String>();
+      diagnostics.push(self.create_diagnostic(
+        "AssetsOverSizeLimitWarning",
+        format!(
+          "asset size limit: The following asset(s) exceed the recommended size limit ({}).\nThis can impact web performance.\nAssets: {assets}",
+          format_size(self.options.max_asset_size)
+        ),
+      ));
+    }
+    if !entrypoints_over_size_limit.is_empty() {
+      let entrypoints = entrypoints_over_size_limit
+        .iter()
+        .map(|(name, size, files)| {
+          let files = files
+            .iter()
+            .map(|(file, _)| format!("\n      {file}"))
+            .collect::<String>();
+          format!("\n  {name} ({}){files}", format_size(*size))
+        })
+        .collect::<String>();
+      diagnostics.push(self.create_diagnostic(
+        "EntrypointsOverSizeLimitWarning",
+        format!(
+          "entrypoint size limit: The following entrypoint(s) combined asset size exceeds the recommended limit ({}). This can impact web performance.\nEntrypoints:{entrypoints}\n",
+          format_size(self.options.max_entrypoint_size)
+        ),
+      ));
+
+      let has_async_chunks = compilation
+        .chunk_by_ukey
+        .values()
+        .any(|chunk| !chunk.can_be_initial(&compilation.chunk_group_by_ukey));
+      if !has_async_chunks {
+        diagnostics.push(self.create_diagnostic(
+          "NoAsyncChunksWarning",
+          "rspack performance recommendations: \nYou can limit the size of your bundles by using import() to lazy load some parts of your application.".to_string(),
+        ));
+      }
+    }
+
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::format_size;
+
+  #[test]
+  fn format_size_should_work() {
+    assert_eq!(format_size(0.0), "0 bytes");
+    assert_eq!(format_size(100.0), "100 bytes");
+    assert_eq!(format_size(250000.0), "244 KiB");
+    assert_eq!(format_size(1536.0), "1.5 KiB");
+    assert_eq!(format_size(3.2 * 1024.0 * 1024.0), "3.2 MiB");
+  }
+}
@@ -8,11 +8,16 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 pub type ChunkFilter = Arc<dyn Fn(&Chunk, &ChunkGroupByUkey) -> bool + Send + Sync>;
 pub type ModuleTypeFilter = Arc<dyn Fn(&dyn Module) -> bool + Send + Sync>;
+pub type ModuleLayerFilter = Arc<dyn Fn(&dyn Module) -> bool + Send + Sync>;
 
 pub fn create_default_module_type_filter() -> ModuleTypeFilter {
   Arc::new(|_| true)
 }
 
+pub fn create_default_module_layer_filter() -> ModuleLayerFilter {
+  Arc::new(|_| true)
+}
+
 pub fn create_async_chunk_filter() -> ChunkFilter {
   Arc::new(|chunk, chunk_group_db| !chunk.can_be_initial(chunk_group_db))
 }
@@ -112,6 +117,37 @@ impl SplitChunkSizes {
   pub fn add_by(&mut self, other: &Self) {
     self.combine_with(other, &|a, b| a + b)
   }
+
+  pub fn has_non_zero(&self) -> bool {
+    self.values().any(|size| *size > 0.0)
+  }
+
+  /// Port of `getViolatingMinSizes` in SplitChunksPlugin.js of webpack
+  pub fn violating_min_sizes(&self, min_size: &Self) -> Vec<SourceType> {
+    min_size
+      .iter()
+      .filter(|(ty, min)| {
+        self
+          .get(ty)
+          .map_or(false, |size| *size != 0.0 && size < min)
+      })
+      .map(|(ty, _)| *ty)
+      .collect()
+  }
+
+  /// Port of `checkMinSize` in SplitChunksPlugin.js of webpack
+  pub fn check_min_size(&self, min_size: &Self) -> bool {
+    self.violating_min_sizes(min_size).is_empty()
+  }
+
+  /// Port of `checkMinSizeReduction` in SplitChunksPlugin.js of webpack
+  pub fn check_min_size_reduction(&self, min_size_reduction: &Self, chunk_count: usize) -> bool {
+    min_size_reduction.iter().all(|(ty, min)| {
+      self.get(ty).map_or(true, |size| {
+        *size == 0.0 || size * chunk_count as f64 >= *min
+      })
+    })
+  }
 }
 
 impl Deref for SplitChunkSizes {
@@ -3,7 +3,7 @@ use rspack_core::Filename;
 
 use super::cache_group_test::CacheGroupTest;
 use super::chunk_name::ChunkNameGetter;
-use crate::common::{ChunkFilter, ModuleTypeFilter, SplitChunkSizes};
+use crate::common::{ChunkFilter, ModuleLayerFilter, ModuleTypeFilter, SplitChunkSizes};
 
 #[derive(Derivative)]
 #[derivative(Debug)]
@@ -25,11 +25,27 @@ pub struct CacheGroup {
   pub test: CacheGroupTest,
   #[derivative(Debug = "ignore")]
   pub r#type: ModuleTypeFilter,
+  /// Only modules in matched layers are selected
+  #[derivative(Debug = "ignore")]
+  pub layer: ModuleLayerFilter,
   /// `name` is used to create chunk
   #[derivative(Debug = "ignore")]
   pub name: ChunkNameGetter,
   pub priority: f64,
+  /// Always split the modules, `min_size`, `min_chunks`, `max_*_requests` and `max_*_size` of
+  /// `splitChunks` are not inherited
+  pub enforce: bool,
   pub min_size: SplitChunkSizes,
+  /// Minimum size reduction of the original chunks, which is the size of the new chunk times the
+  /// number of chunks it's split from
+  pub min_size_reduction: SplitChunkSizes,
+  /// If only one chunk is split from, modules are kept in it rather than leaving it smaller than this
+  pub min_remaining_size: SplitChunkSizes,
+  /// `ModuleGroup`s bigger than this are split as if `enforce` is set
+  pub enforce_size_threshold: SplitChunkSizes,
+  /// Chunks of a module are only combined when the exports of the module are used in the same way
+  /// in the runtimes of the chunks
+  pub used_exports: bool,
   pub reuse_existing_chunk: bool,
   /// number of referenced chunks
   pub min_chunks: u32,
@@ -82,48 +82,50 @@ impl SplitChunksPlugin {
     best_reusable_chunk.map(|c| c.ukey)
   }
 
-  pub(crate) fn get_corresponding_chunk(
+  /// Return the chunk named `module_group.chunk_name` or a reusable chunk if there is one.
+  /// New chunks are created by [Self::create_chunk] after all the checks of the `ModuleGroup`
+  /// pass, so that a skipped `ModuleGroup` leaves no empty chunk behind.
+  pub(crate) fn get_existing_chunk(
     &self,
     compilation: &mut Compilation,
     module_group: &mut ModuleGroup,
-    is_reuse_existing_chunk: &mut bool,
     is_reuse_existing_chunk_with_all_modules: &mut bool,
-  ) -> ChunkUkey {
+  ) -> Option<ChunkUkey> {
     if let Some(chunk_name) = &module_group.chunk_name {
-      if let Some(chunk) = compilation.named_chunks.get(chunk_name) {
-        *is_reuse_existing_chunk = true;
-        *chunk
-      } else {
-        let new_chunk_ukey = Compilation::add_named_chunk(
-          chunk_name.clone(),
-          &mut compilation.chunk_by_ukey,
-          &mut compilation.named_chunks,
-        );
-        let new_chunk = compilation.chunk_by_ukey.expect_get_mut(&new_chunk_ukey);
-        new_chunk
-          .chunk_reasons
-          .push("Create by split chunks".to_string());
-        compilation.chunk_graph.add_chunk(new_chunk.ukey);
-        new_chunk.ukey
-      }
+      compilation.named_chunks.get(chunk_name).copied()
     } else if let Some(reusable_chunk) =
       self.find_the_best_reusable_chunk(compilation, module_group)
       && module_group.cache_group_reuse_existing_chunk
     {
-      *is_reuse_existing_chunk = true;
       *is_reuse_existing_chunk_with_all_modules = true;
-      reusable_chunk
+      Some(reusable_chunk)
     } else {
-      let new_chunk_ukey = Compilation::add_chunk(&mut compilation.chunk_by_ukey);
-      let new_chunk = compilation.chunk_by_ukey.expect_get_mut(&new_chunk_ukey);
-      new_chunk
-        .chunk_reasons
-        .push("Create by split chunks".to_string());
-      compilation.chunk_graph.add_chunk(new_chunk.ukey);
-      new_chunk.ukey
+      None
     }
   }
 
+  pub(crate) fn create_chunk(
+    &self,
+    compilation: &mut Compilation,
+    module_group: &ModuleGroup,
+  ) -> ChunkUkey {
+    let new_chunk_ukey = if let Some(chunk_name) = &module_group.chunk_name {
+      Compilation::add_named_chunk(
+        chunk_name.clone(),
+        &mut compilation.chunk_by_ukey,
+        &mut compilation.named_chunks,
+      )
+    } else {
+      Compilation::add_chunk(&mut compilation.chunk_by_ukey)
+    };
+    let new_chunk = compilation.chunk_by_ukey.expect_get_mut(&new_chunk_ukey);
+    new_chunk
+      .chunk_reasons
+      .push("Create by split chunks".to_string());
+    compilation.chunk_graph.add_chunk(new_chunk.ukey);
+    new_chunk.ukey
+  }
+
   /// This de-duplicated each module fro other chunks, make sure there's only one copy of each module.
   #[tracing::instrument(skip_all)]
   pub(crate) fn move_modules_to_new_chunk_and_remove_from_old_chunks(
@@ -1,8 +1,8 @@
 use rayon::prelude::*;
-use rspack_core::{Compilation, SourceType};
+use rspack_core::{ChunkUkey, Compilation, SourceType};
 
 use super::ModuleGroupMap;
-use crate::{module_group::ModuleGroup, CacheGroup, SplitChunksPlugin};
+use crate::{module_group::ModuleGroup, CacheGroup, SplitChunkSizes, SplitChunksPlugin};
 
 impl SplitChunksPlugin {
   /// Return `true` if the `ModuleGroup` become empty.
@@ -39,7 +39,16 @@ impl SplitChunksPlugin {
     })
     .collect::<Box<[_]>>();
 
-    // Remove modules having violating SourceType
+    Self::remove_modules_with_source_types(compilation, module_group, &violating_source_types);
+
+    module_group.modules.is_empty()
+  }
+
+  pub(crate) fn remove_modules_with_source_types(
+    compilation: &Compilation,
+    module_group: &mut ModuleGroup,
+    source_types: &[SourceType],
+  ) {
     let violating_modules = module_group
       .modules
       .par_iter()
@@ -48,7 +57,7 @@ impl SplitChunksPlugin {
           .module_graph
           .module_by_identifier(module_id)
           .expect("Should have a module");
-        let having_violating_source_type = violating_source_types
+        let having_violating_source_type = source_types
           .iter()
           .any(|ty: &SourceType| module.source_types().contains(ty));
         if having_violating_source_type {
@@ -64,11 +73,34 @@ impl SplitChunksPlugin {
     violating_modules
       .into_iter()
       .for_each(|violating_module| module_group.remove_module(violating_module));
+  }
 
-    module_group.modules.is_empty()
+  /// Affected by `splitChunks.minRemainingSize`/`splitChunks.cacheGroups.{cacheGroup}.minRemainingSize`
+  ///
+  /// Return `SourceType`s whose size of modules left in `chunk` would be smaller than `min_remaining_size`
+  /// after moving the modules of the `ModuleGroup` out of it.
+  pub(crate) fn get_min_remaining_size_violating_source_types(
+    compilation: &Compilation,
+    module_group: &ModuleGroup,
+    cache_group: &CacheGroup,
+    chunk: &ChunkUkey,
+  ) -> Vec<SourceType> {
+    let mut remaining_sizes = SplitChunkSizes::empty();
+    compilation
+      .chunk_graph
+      .get_chunk_modules(chunk, &compilation.module_graph)
+      .into_iter()
+      .filter(|module| !module_group.modules.contains(&module.identifier()))
+      .for_each(|module| {
+        module.source_types().iter().for_each(|ty| {
+          *remaining_sizes.entry(*ty).or_default() += module.size(ty);
+        })
+      });
+    remaining_sizes.violating_min_sizes(&cache_group.min_remaining_size)
   }
 
   /// Affected by `splitChunks.minSize`/`splitChunks.cacheGroups.{cacheGroup}.minSize`
+  /// Affected by `splitChunks.minSizeReduction`/`splitChunks.cacheGroups.{cacheGroup}.minSizeReduction`
   #[tracing::instrument(skip_all)]
   pub(crate) fn ensure_min_size_fit(
     &self,
@@ -80,9 +112,9 @@ impl SplitChunksPlugin {
       .filter_map(|(module_group_key, module_group)| {
         let cache_group = module_group.get_cache_group(&self.cache_groups);
         // Fast path
-        if cache_group.min_size.is_empty() {
+        if cache_group.min_size.is_empty() && cache_group.min_size_reduction.is_empty() {
           tracing::debug!(
-            "ModuleGroup({}) skips `minSize` checking. Reason: min_size of CacheGroup({}) is empty",
+            "ModuleGroup({}) skips `minSize` checking. Reason: min_size and min_size_reduction of CacheGroup({}) are empty",
             module_group_key,
             cache_group.key,
           );
@@ -94,7 +126,10 @@ impl SplitChunksPlugin {
           compilation,
           module_group,
           cache_group,
-        ) {
+        ) || !module_group
+          .sizes
+          .check_min_size_reduction(&cache_group.min_size_reduction, module_group.chunks.len())
+        {
           Some(module_group_key.clone())
         } else {
           None
@@ -104,7 +139,7 @@ impl SplitChunksPlugin {
 
     invalidated_module_groups.into_iter().for_each(|key| {
       tracing::debug!(
-        "ModuleGroup({}) is removed. Reason: empty modules cause by `minSize` checking",
+        "ModuleGroup({}) is removed. Reason: empty modules or too small size reduction cause by `minSize` or `minSizeReduction` checking",
         key,
       );
       module_group_map.remove(&key);
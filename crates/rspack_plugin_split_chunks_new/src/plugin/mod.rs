@@ -64,39 +64,34 @@ impl SplitChunksPlugin {
       process_span.in_scope(|| {
         let cache_group = module_group.get_cache_group(&self.cache_groups);
 
-      let mut is_reuse_existing_chunk = false;
       let mut is_reuse_existing_chunk_with_all_modules = false;
-      let new_chunk = self.get_corresponding_chunk(
+      let existing_chunk = self.get_existing_chunk(
         compilation,
         &mut module_group,
-        &mut is_reuse_existing_chunk,
         &mut is_reuse_existing_chunk_with_all_modules,
       );
+      let is_reuse_existing_chunk = existing_chunk.is_some();
+      tracing::trace!("{module_group_key}, get existing Chunk {existing_chunk:?} with is_reuse_existing_chunk_with_all_modules: {is_reuse_existing_chunk_with_all_modules:?}");
 
-      let new_chunk_mut = new_chunk.as_mut(&mut compilation.chunk_by_ukey);
-      tracing::trace!("{module_group_key}, get Chunk {} with is_reuse_existing_chunk: {is_reuse_existing_chunk:?} and {is_reuse_existing_chunk_with_all_modules:?}", new_chunk_mut.chunk_reasons.join("~"));
-
-      new_chunk_mut
-        .chunk_reasons
-        .push(["(cache group: ", cache_group.key.as_str(), ")"].join(""));
-
-      if let Some(filename) = &cache_group.filename {
-        new_chunk_mut.filename_template = Some(filename.clone());
-      }
-
-      new_chunk_mut
-        .id_name_hints
-        .insert(cache_group.id_hint.clone());
-
-      if is_reuse_existing_chunk {
-        // The chunk is not new but created in code splitting. We need remove `new_chunk` since we would remove
+      if let Some(existing_chunk) = existing_chunk {
+        // The chunk is not new but created in code splitting. We need remove `existing_chunk` since we would remove
         // modules in this `Chunk/ModuleGroup` from other chunks. Other chunks is stored in `ModuleGroup.chunks`.
-        module_group.chunks.remove(&new_chunk);
+        module_group.chunks.remove(&existing_chunk);
       }
 
+      // `ModuleGroup`s of enforced `CacheGroup`s or bigger than `enforce_size_threshold` skip
+      // `max_*_requests` and `min_remaining_size` checking
+      let enforced = cache_group.enforce
+        || (cache_group.enforce_size_threshold.has_non_zero()
+          && module_group
+            .sizes
+            .check_min_size(&cache_group.enforce_size_threshold));
+
       let mut used_chunks = Cow::Borrowed(&module_group.chunks);
 
-      self.ensure_max_request_fit(compilation, cache_group, &mut used_chunks);
+      if !enforced {
+        self.ensure_max_request_fit(compilation, cache_group, &mut used_chunks);
+      }
 
       if used_chunks.len() != module_group.chunks.len() {
         // There are some chunks removed by `ensure_max_request_fit`
@@ -113,6 +108,54 @@ impl SplitChunksPlugin {
         }
       }
 
+      if !enforced
+        && used_chunks.len() == 1
+        && cache_group.min_remaining_size.has_non_zero()
+      {
+        let remaining_chunk = used_chunks.iter().next().copied().expect("should have one chunk");
+        let violating_source_types = Self::get_min_remaining_size_violating_source_types(
+          compilation,
+          &module_group,
+          cache_group,
+          &remaining_chunk,
+        );
+        if !violating_source_types.is_empty() {
+          // Keep modules of violating `SourceType`s in the chunk and process the rest again
+          let old_modules_len = module_group.modules.len();
+          Self::remove_modules_with_source_types(
+            compilation,
+            &mut module_group,
+            &violating_source_types,
+          );
+          if !module_group.modules.is_empty() && module_group.modules.len() != old_modules_len {
+            if is_reuse_existing_chunk_with_all_modules
+              && let Some(existing_chunk) = existing_chunk
+            {
+              module_group.chunks.insert(existing_chunk);
+            }
+            module_group_map.insert(module_group_key.clone(), module_group);
+          }
+          tracing::trace!("ModuleGroup({module_group_key}) is skipped. Reason: the remaining chunk violates min_remaining_size of SourceTypes {violating_source_types:?}");
+          return;
+        }
+      }
+
+      let new_chunk =
+        existing_chunk.unwrap_or_else(|| self.create_chunk(compilation, &module_group));
+      let new_chunk_mut = new_chunk.as_mut(&mut compilation.chunk_by_ukey);
+
+      new_chunk_mut
+        .chunk_reasons
+        .push(["(cache group: ", cache_group.key.as_str(), ")"].join(""));
+
+      if let Some(filename) = &cache_group.filename {
+        new_chunk_mut.filename_template = Some(filename.clone());
+      }
+
+      new_chunk_mut
+        .id_name_hints
+        .insert(cache_group.id_hint.clone());
+
       if !cache_group.max_initial_size.is_empty() || !cache_group.max_async_size.is_empty() {
         max_size_setting_map.insert(
           new_chunk,
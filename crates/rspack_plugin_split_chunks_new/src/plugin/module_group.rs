@@ -2,7 +2,10 @@ use std::hash::{Hash, Hasher};
 
 use dashmap::DashMap;
 use rayon::prelude::*;
-use rspack_core::{Chunk, ChunkGraph, ChunkUkey, Compilation, Module, ModuleGraph};
+use rspack_core::{
+  Chunk, ChunkByUkey, ChunkGraph, ChunkUkey, Compilation, Module, ModuleGraph, ModuleIdentifier,
+  UsageState,
+};
 use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 
 use super::ModuleGroupMap;
@@ -60,39 +63,19 @@ impl SplitChunksPlugin {
 
     let module_group_map: DashMap<String, ModuleGroup> = DashMap::default();
 
-    // chunk_sets_in_graph: key: module, value: multiple chunks contains the module
-    // single_chunk_sets: chunkset of module that belongs to only one chunk
-    // chunk_sets_by_count: use chunkset len as key
-    let (chunk_sets_in_graph, chunk_sets_by_count) =
-      { Self::prepare_combination_maps(&compilation.module_graph, &compilation.chunk_graph) };
-
-    let combinations_cache = DashMap::<ChunksKey, Vec<FxHashSet<ChunkUkey>>>::default();
-
-    let get_combination = |chunks_key: ChunksKey| {
-      if let Some(combs) = combinations_cache.get(&chunks_key) {
-        return combs.clone();
-      }
-      let chunks_set = chunk_sets_in_graph
-        .get(&chunks_key)
-        .expect("This should never happen, please file an issue");
-      let mut result = vec![chunks_set.clone()];
-
-      for (count, array_of_set) in &chunk_sets_by_count {
-        if *count < chunks_set.len() {
-          for set in array_of_set {
-            if set.is_subset(chunks_set) {
-              result.push(set.clone());
-            }
-          }
-        }
-      }
-
-      combinations_cache.insert(chunks_key, result);
-      combinations_cache
-        .get(&chunks_key)
-        .expect("This should never happen, please file an issue")
-        .clone()
-    };
+    let combinator = Combinator::by_chunks(&compilation.module_graph, &compilation.chunk_graph);
+    // Chunks only need to be grouped by used exports for `CacheGroup`s with `used_exports` enabled
+    let used_exports_combinator = self
+      .cache_groups
+      .iter()
+      .any(|cache_group| cache_group.used_exports)
+      .then(|| {
+        Combinator::by_used_exports(
+          &compilation.module_graph,
+          &compilation.chunk_graph,
+          chunk_db,
+        )
+      });
 
     compilation.module_graph.modules().values().par_bridge().for_each(|module| {
       let module = &**module;
@@ -123,14 +106,16 @@ impl SplitChunksPlugin {
           CacheGroupTest::Enabled => true,
         };
         let is_match_the_type: bool = (cache_group.r#type)(module);
-        let is_match = is_match_the_test && is_match_the_type;
+        let is_match_the_layer: bool = (cache_group.layer)(module);
+        let is_match = is_match_the_test && is_match_the_type && is_match_the_layer;
         if !is_match {
           tracing::trace!(
-                  "Module({:?}) is ignored by CacheGroup({:?}). Reason: !(is_match_the_test({:?}) && is_match_the_type({:?}))",
+                  "Module({:?}) is ignored by CacheGroup({:?}). Reason: !(is_match_the_test({:?}) && is_match_the_type({:?}) && is_match_the_layer({:?}))",
                   module.identifier(),
                   cache_group.key,
                   is_match_the_test,
-                  is_match_the_type
+                  is_match_the_type,
+                  is_match_the_layer
                 );
         }
 
@@ -146,7 +131,12 @@ impl SplitChunksPlugin {
         .filter(|(index, _)| temp[*index].1);
 
       for (cache_group_index, (idx, cache_group)) in filtered.enumerate() {
-        let combs = get_combination(chunks_key);
+        let combs = match &used_exports_combinator {
+          Some(used_exports_combinator) if cache_group.used_exports => {
+            used_exports_combinator.get_combinations_by_used_exports(&module.identifier())
+          }
+          _ => combinator.get_combinations(chunks_key),
+        };
 
         for chunk_combination in combs {
           // Filter by `splitChunks.cacheGroups.{cacheGroup}.minChunks`
@@ -329,6 +319,18 @@ impl SplitChunksPlugin {
           return Some(key.clone());
         }
 
+        // Validate `min_size_reduction` again
+        if !other_module_group.sizes.check_min_size_reduction(
+          &cache_group.min_size_reduction,
+          other_module_group.chunks.len(),
+        ) {
+          tracing::trace!(
+            "{key} is deleted for violating min_size_reduction {:#?}",
+            cache_group.min_size_reduction,
+          );
+          return Some(key.clone());
+        }
+
         None
       })
       .collect::<Vec<_>>();
@@ -348,34 +350,111 @@ impl SplitChunksPlugin {
     }
     hasher.finish()
   }
+}
+
+/// Combinations of chunks that a module could be split from, which are the chunk set containing the
+/// module and its subsets shared by other modules.
+#[derive(Default)]
+struct Combinator {
+  chunk_sets_in_graph: FxHashMap<ChunksKey, FxHashSet<ChunkUkey>>,
+  chunk_sets_by_count: FxHashMap<usize, Vec<FxHashSet<ChunkUkey>>>,
+  /// Chunks of each module grouped by how the exports of the module are used in the runtime of the chunks
+  grouped_by_used_exports: FxHashMap<ModuleIdentifier, Vec<ChunksKey>>,
+  combinations_cache: DashMap<ChunksKey, Vec<FxHashSet<ChunkUkey>>>,
+}
+
+impl Combinator {
+  fn by_chunks(module_graph: &ModuleGraph, chunk_graph: &ChunkGraph) -> Self {
+    let mut combinator = Self::default();
+    for module in module_graph.modules().keys() {
+      combinator.add_chunk_set(chunk_graph.get_module_chunks(*module).clone());
+    }
+    combinator.group_chunk_sets_by_count();
+    combinator
+  }
 
-  #[allow(clippy::type_complexity)]
-  fn prepare_combination_maps(
+  /// Port of `getExportsChunkSetsInGraph` in SplitChunksPlugin.js of webpack
+  fn by_used_exports(
     module_graph: &ModuleGraph,
     chunk_graph: &ChunkGraph,
-  ) -> (
-    FxHashMap<ChunksKey, FxHashSet<ChunkUkey>>,
-    FxHashMap<usize, Vec<FxHashSet<ChunkUkey>>>,
-  ) {
-    let mut chunk_sets_in_graph = FxHashMap::default();
-
+    chunk_db: &ChunkByUkey,
+  ) -> Self {
+    let mut combinator = Self::default();
     for module in module_graph.modules().keys() {
-      let chunks = chunk_graph.get_module_chunks(*module);
-      let chunk_key = Self::get_key(chunks.iter());
+      let exports_info = module_graph.get_exports_info(module);
+      let mut grouped_chunks: FxHashMap<Vec<UsageState>, FxHashSet<ChunkUkey>> = Default::default();
+      for chunk in chunk_graph.get_module_chunks(*module) {
+        let runtime = &chunk_db.expect_get(chunk).runtime;
+        grouped_chunks
+          .entry(exports_info.get_usage_key(Some(runtime), module_graph))
+          .or_default()
+          .insert(*chunk);
+      }
+      let chunks_keys = grouped_chunks
+        .into_values()
+        .map(|chunks| combinator.add_chunk_set(chunks))
+        .collect();
+      combinator
+        .grouped_by_used_exports
+        .insert(*module, chunks_keys);
+    }
+    combinator.group_chunk_sets_by_count();
+    combinator
+  }
 
-      chunk_sets_in_graph.insert(chunk_key, chunks.clone());
+  fn add_chunk_set(&mut self, chunks: FxHashSet<ChunkUkey>) -> ChunksKey {
+    let chunks_key = SplitChunksPlugin::get_key(chunks.iter());
+    self.chunk_sets_in_graph.insert(chunks_key, chunks);
+    chunks_key
+  }
+
+  fn group_chunk_sets_by_count(&mut self) {
+    for chunks in self.chunk_sets_in_graph.values() {
+      self
+        .chunk_sets_by_count
+        .entry(chunks.len())
+        .or_default()
+        .push(chunks.clone());
     }
+  }
 
-    let mut chunk_sets_by_count = FxHashMap::<usize, Vec<FxHashSet<ChunkUkey>>>::default();
+  fn get_combinations(&self, chunks_key: ChunksKey) -> Vec<FxHashSet<ChunkUkey>> {
+    if let Some(combs) = self.combinations_cache.get(&chunks_key) {
+      return combs.clone();
+    }
+    let chunks_set = self
+      .chunk_sets_in_graph
+      .get(&chunks_key)
+      .expect("This should never happen, please file an issue");
+    let mut result = vec![chunks_set.clone()];
 
-    for chunks in chunk_sets_in_graph.values() {
-      let count = chunks.len();
-      chunk_sets_by_count
-        .entry(count)
-        .and_modify(|set| set.push(chunks.clone()))
-        .or_insert(vec![chunks.clone()]);
+    for (count, array_of_set) in &self.chunk_sets_by_count {
+      if *count < chunks_set.len() {
+        for set in array_of_set {
+          if set.is_subset(chunks_set) {
+            result.push(set.clone());
+          }
+        }
+      }
     }
 
-    (chunk_sets_in_graph, chunk_sets_by_count)
+    self.combinations_cache.insert(chunks_key, result.clone());
+    result
+  }
+
+  fn get_combinations_by_used_exports(
+    &self,
+    module: &ModuleIdentifier,
+  ) -> Vec<FxHashSet<ChunkUkey>> {
+    self
+      .grouped_by_used_exports
+      .get(module)
+      .map(|chunks_keys| {
+        chunks_keys
+          .iter()
+          .flat_map(|chunks_key| self.get_combinations(*chunks_key))
+          .collect()
+      })
+      .unwrap_or_default()
   }
 }
@@ -11,7 +11,9 @@ pub struct WasmImportDependency {
   id: DependencyId,
   name: Atom,
   request: String,
-  // only_direct_import: bool,
+  /// Why the import can only be provided by another wasm module, e.g. `Memory`,
+  /// `None` if it can be provided by JavaScript
+  only_direct_import: Option<String>,
   /// the WASM AST node
   pub desc: WasmNode,
 
@@ -19,19 +21,27 @@ pub struct WasmImportDependency {
 }
 
 impl WasmImportDependency {
-  pub fn new(request: String, name: String, desc: WasmNode) -> Self {
+  pub fn new(
+    request: String,
+    name: String,
+    desc: WasmNode,
+    only_direct_import: Option<String>,
+  ) -> Self {
     Self {
       id: DependencyId::new(),
       name: name.into(),
       request,
       desc,
-      // only_direct_import,
+      only_direct_import,
       span: None,
     }
   }
   pub fn name(&self) -> &str {
     &self.name
   }
+  pub fn only_direct_import(&self) -> Option<&str> {
+    self.only_direct_import.as_deref()
+  }
 }
 
 impl Dependency for WasmImportDependency {
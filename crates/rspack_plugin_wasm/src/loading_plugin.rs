@@ -1,15 +1,151 @@
 use rspack_core::{
-  BoxPlugin, Plugin, PluginContext, PluginExt, PluginRuntimeRequirementsInTreeOutput,
+  BoxPlugin, Identifiable, Plugin, PluginContext, PluginExt, PluginRuntimeRequirementsInTreeOutput,
   RuntimeGlobals, RuntimeModuleExt, RuntimeRequirementsInTreeArgs, WasmLoadingType,
 };
+use rspack_error::Diagnostic;
 
-use crate::AsyncWasmLoadingRuntimeModule;
+use crate::{get_all_wasm_modules, AsyncWasmLoadingRuntimeModule, WasmChunkLoadingRuntimeModule};
 
-pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> BoxPlugin {
+pub fn enable_wasm_loading_plugin(
+  wasm_loading_type: WasmLoadingType,
+  plugins: &mut Vec<BoxPlugin>,
+) {
   match wasm_loading_type {
-    WasmLoadingType::Fetch => FetchCompileAsyncWasmPlugin.boxed(),
-    WasmLoadingType::AsyncNode => ReadFileCompileAsyncWasmPlugin::new(false).boxed(),
-    WasmLoadingType::AsyncNodeModule => ReadFileCompileAsyncWasmPlugin::new(true).boxed(),
+    WasmLoadingType::Fetch => {
+      plugins.push(FetchCompileWasmPlugin.boxed());
+      plugins.push(FetchCompileAsyncWasmPlugin.boxed());
+    }
+    WasmLoadingType::AsyncNode => {
+      plugins.push(ReadFileCompileWasmPlugin.boxed());
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(false).boxed());
+    }
+    // `webassembly/sync` modules can't be loaded in esm output, they are reported as errors
+    WasmLoadingType::AsyncNodeModule => {
+      plugins.push(UnsupportedSyncWasmLoadingPlugin.boxed());
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(true).boxed());
+    }
+  }
+}
+
+/// Whether the async chunks of the chunk have `webassembly/sync` modules to load.
+fn has_wasm_modules(args: &RuntimeRequirementsInTreeArgs) -> bool {
+  !get_all_wasm_modules(args.compilation, args.chunk).is_empty()
+}
+
+#[derive(Debug)]
+pub struct FetchCompileWasmPlugin;
+
+#[async_trait::async_trait]
+impl Plugin for FetchCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "FetchCompileWasmPlugin"
+  }
+
+  async fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut RuntimeRequirementsInTreeArgs,
+  ) -> PluginRuntimeRequirementsInTreeOutput {
+    if args
+      .runtime_requirements
+      .contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+      && has_wasm_modules(args)
+    {
+      args
+        .runtime_requirements_mut
+        .insert(RuntimeGlobals::MODULE_CACHE | RuntimeGlobals::PUBLIC_PATH);
+      args
+        .compilation
+        .add_runtime_module(
+          args.chunk,
+          WasmChunkLoadingRuntimeModule::new(
+            format!("fetch({} + $PATH)", RuntimeGlobals::PUBLIC_PATH),
+            true,
+            *args.chunk,
+          )
+          .boxed(),
+        )
+        .await?;
+    }
+
+    Ok(())
+  }
+}
+
+#[derive(Debug)]
+pub struct ReadFileCompileWasmPlugin;
+
+#[async_trait::async_trait]
+impl Plugin for ReadFileCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "ReadFileCompileWasmPlugin"
+  }
+
+  async fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut RuntimeRequirementsInTreeArgs,
+  ) -> PluginRuntimeRequirementsInTreeOutput {
+    if args
+      .runtime_requirements
+      .contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+      && has_wasm_modules(args)
+    {
+      args
+        .runtime_requirements_mut
+        .insert(RuntimeGlobals::MODULE_CACHE);
+      args
+        .compilation
+        .add_runtime_module(
+          args.chunk,
+          WasmChunkLoadingRuntimeModule::new(
+            include_str!("runtime/read_file_compile_async_wasm.js").to_string(),
+            false,
+            *args.chunk,
+          )
+          .boxed(),
+        )
+        .await?;
+    }
+
+    Ok(())
+  }
+}
+
+/// Reports the `webassembly/sync` modules of the async chunks, which the wasm loading type has
+/// no loader for.
+#[derive(Debug)]
+pub struct UnsupportedSyncWasmLoadingPlugin;
+
+#[async_trait::async_trait]
+impl Plugin for UnsupportedSyncWasmLoadingPlugin {
+  fn name(&self) -> &'static str {
+    "UnsupportedSyncWasmLoadingPlugin"
+  }
+
+  async fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut RuntimeRequirementsInTreeArgs,
+  ) -> PluginRuntimeRequirementsInTreeOutput {
+    if !args
+      .runtime_requirements
+      .contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    {
+      return Ok(());
+    }
+    let diagnostics = get_all_wasm_modules(args.compilation, args.chunk)
+      .into_iter()
+      .map(|(module, _)| {
+        Diagnostic::error(
+          "Unsupported WebAssembly Loading Error".into(),
+          "WebAssembly modules of type \"webassembly/sync\" can't be loaded with wasmLoading \"async-node-module\".\nUse \"webassembly/async\" (experiments.asyncWebAssembly) instead.".into(),
+        )
+        .with_module_identifier(Some(module.identifier()))
+      })
+      .collect();
+    args.compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
   }
 }
 
@@ -1,18 +1,20 @@
 use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
 use std::hash::{Hash, Hasher};
 
 use indexmap::IndexMap;
 use rspack_core::rspack_sources::{BoxSource, RawSource, Source, SourceExt};
 use rspack_core::DependencyType::WasmImport;
 use rspack_core::{
-  AssetInfo, BoxDependency, BuildMetaExportsType, Compilation, Filename, GenerateContext, Module,
-  ModuleDependency, ModuleIdentifier, NormalModule, ParseContext, ParseResult, ParserAndGenerator,
-  PathData, RuntimeGlobals, SourceType, StaticExportsDependency, UsedName,
+  AssetInfo, BoxDependency, BuildMetaExportsType, CodeGenerationDataFilename, Compilation,
+  Filename, GenerateContext, Module, ModuleDependency, ModuleIdentifier, NormalModule,
+  ParseContext, ParseResult, ParserAndGenerator, PathData, RuntimeGlobals, SourceType,
+  StaticExportsDependency, UsedName,
 };
 use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_identifier::Identifier;
 use swc_core::atoms::Atom;
-use wasmparser::{Import, Parser, Payload};
+use wasmparser::{ExternalKind, FuncType, Import, Parser, Payload, Type, TypeRef, ValType};
 
 use crate::dependency::WasmImportDependency;
 use crate::ModuleIdToFileName;
@@ -62,6 +64,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
                     module.into(),
                     name.into(),
                     ty,
+                    None,
                   )));
                 }
                 Err(err) => diagnostic.push(Diagnostic::error(
@@ -306,3 +309,245 @@ fn hash_for_source(source: &BoxSource) -> String {
   source.hash(&mut hasher);
   format!("{:016x}", hasher.finish())
 }
+
+/// Parser and generator of `webassembly/sync` modules, which are instantiated while loading the
+/// chunk containing them, so their exports can be used synchronously.
+#[derive(Debug)]
+pub struct SyncWasmParserAndGenerator {
+  pub(crate) module_id_to_filename: ModuleIdToFileName,
+}
+
+impl ParserAndGenerator for SyncWasmParserAndGenerator {
+  fn source_types(&self) -> &[SourceType] {
+    WASM_SOURCE_TYPE
+  }
+
+  fn parse(&mut self, parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+    parse_context.build_info.strict = true;
+    parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
+
+    let source = parse_context.source;
+
+    let mut func_types: Vec<FuncType> = vec![];
+    // type indices of all functions, imported functions come first
+    let mut func_type_indices: Vec<u32> = vec![];
+    let mut exports = Vec::with_capacity(1);
+    let mut js_incompatible_exports = BTreeMap::new();
+    let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
+    let mut diagnostic = Vec::with_capacity(1);
+
+    for payload in Parser::new(0).parse_all(&source.buffer()) {
+      match payload {
+        Ok(payload) => match payload {
+          Payload::TypeSection(s) => {
+            for ty in s {
+              match ty {
+                Ok(Type::Func(func_type)) => func_types.push(func_type),
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Type Parse Error".into(),
+                  err.to_string(),
+                )),
+              }
+            }
+          }
+          Payload::FunctionSection(s) => {
+            for type_index in s {
+              match type_index {
+                Ok(type_index) => func_type_indices.push(type_index),
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Function Parse Error".into(),
+                  err.to_string(),
+                )),
+              }
+            }
+          }
+          Payload::ImportSection(s) => {
+            for import in s {
+              match import {
+                Ok(Import { module, name, ty }) => {
+                  let only_direct_import = match ty {
+                    TypeRef::Memory(_) => Some("Memory".to_string()),
+                    TypeRef::Table(_) => Some("Table".to_string()),
+                    // globals are read while instantiating, before the JavaScript modules are executed
+                    TypeRef::Global(_) => Some("Global".to_string()),
+                    TypeRef::Tag(_) => Some("Tag".to_string()),
+                    TypeRef::Func(type_index) => {
+                      func_type_indices.push(type_index);
+                      func_types
+                        .get(type_index as usize)
+                        .and_then(get_js_incompatible_type)
+                        .map(|ty| format!("Non-JS-compatible Func Signature ({ty})"))
+                    }
+                  };
+                  dependencies.push(Box::new(WasmImportDependency::new(
+                    module.into(),
+                    name.into(),
+                    ty,
+                    only_direct_import,
+                  )));
+                }
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Import Parse Error".into(),
+                  err.to_string(),
+                )),
+              }
+            }
+          }
+          Payload::ExportSection(s) => {
+            for export in s {
+              match export {
+                Ok(export) => {
+                  if export.kind == ExternalKind::Func
+                    && let Some(ty) = func_type_indices
+                      .get(export.index as usize)
+                      .and_then(|type_index| func_types.get(*type_index as usize))
+                      .and_then(get_js_incompatible_type)
+                  {
+                    js_incompatible_exports.insert(export.name.to_string(), ty);
+                  }
+                  exports.push(export.name.to_string());
+                }
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Export Parse Error".into(),
+                  err.to_string(),
+                )),
+              };
+            }
+          }
+          _ => {}
+        },
+        Err(err) => {
+          diagnostic.push(Diagnostic::error(
+            "Wasm Parse Error".into(),
+            err.to_string(),
+          ));
+        }
+      }
+    }
+
+    if !js_incompatible_exports.is_empty() {
+      parse_context.build_meta.js_incompatible_exports = Some(js_incompatible_exports);
+    }
+
+    dependencies.push(Box::new(StaticExportsDependency::new(
+      exports.iter().cloned().map(Atom::from).collect::<Vec<_>>(),
+      false,
+    )));
+
+    Ok(
+      ParseResult {
+        dependencies,
+        blocks: vec![],
+        presentational_dependencies: vec![],
+        source,
+        analyze_result: Default::default(),
+      }
+      .with_diagnostic(diagnostic),
+    )
+  }
+
+  fn size(&self, module: &dyn Module, source_type: &SourceType) -> f64 {
+    match source_type {
+      SourceType::JavaScript => 95.0 + module.get_dependencies().len() as f64 * 5.0,
+      SourceType::Wasm => module.original_source().map_or(0, |source| source.size()) as f64,
+      _ => 0.0,
+    }
+  }
+
+  #[allow(clippy::unwrap_in_result)]
+  fn generate(
+    &self,
+    source: &BoxSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<BoxSource> {
+    let compilation = generate_context.compilation;
+    let wasm_filename_template = &compilation.options.output.webassembly_module_filename;
+    let hash = hash_for_source(source);
+    let normal_module = module
+      .as_normal_module()
+      .expect("module should be a NormalModule in SyncWasmParserAndGenerator::generate");
+    let wasm_path_with_info =
+      render_wasm_name(compilation, normal_module, wasm_filename_template, &hash);
+
+    self
+      .module_id_to_filename
+      .insert(module.identifier(), wasm_path_with_info.clone());
+
+    match generate_context.requested_source_type {
+      SourceType::JavaScript => {
+        // the wasm loading runtime of the chunk fetches the wasm file from here
+        generate_context
+          .data
+          .insert(CodeGenerationDataFilename::new(
+            wasm_path_with_info.0,
+            compilation.options.output.public_path.clone(),
+          ));
+
+        let runtime_requirements = &mut generate_context.runtime_requirements;
+        runtime_requirements.insert(RuntimeGlobals::MODULE);
+        runtime_requirements.insert(RuntimeGlobals::MODULE_ID);
+        runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+        runtime_requirements.insert(RuntimeGlobals::WASM_INSTANCES);
+
+        let module_graph = &compilation.module_graph;
+        let chunk_graph = &compilation.chunk_graph;
+
+        let mut dep_modules = IndexMap::<ModuleIdentifier, String>::new();
+        module
+          .get_dependencies()
+          .iter()
+          .filter_map(|id| module_graph.dependency_by_id(id))
+          .filter(|dep| dep.dependency_type() == &WasmImport)
+          .filter_map(|dep| module_graph.module_graph_module_by_dependency_id(dep.id()))
+          .for_each(|mgm| {
+            if !dep_modules.contains_key(&mgm.module_identifier) {
+              let import_var = format!("WEBPACK_IMPORTED_MODULE_{}", dep_modules.len());
+              dep_modules.insert(
+                mgm.module_identifier,
+                render_import_stmt(&import_var, mgm.id(chunk_graph)),
+              );
+            }
+          });
+        let imports_code = dep_modules.into_values().collect::<Vec<_>>().join("");
+
+        Ok(
+          RawSource::from(format!(
+            r#""use strict";
+// Instantiate WebAssembly module
+var wasmExports = {}[module.id];
+// export exports from WebAssembly module
+module.exports = wasmExports;
+// exec imports from WebAssembly module (for esm order)
+{imports_code}"#,
+            RuntimeGlobals::WASM_INSTANCES
+          ))
+          .boxed(),
+        )
+      }
+      _ => Ok(source.clone()),
+    }
+  }
+}
+
+/// The first type of the function signature that can't be passed between JavaScript and wasm,
+/// e.g. `i64 as parameter`.
+fn get_js_incompatible_type(func_type: &FuncType) -> Option<String> {
+  let is_incompatible = |ty: &&ValType| matches!(ty, ValType::I64 | ValType::V128);
+  if let Some(ty) = func_type.params().iter().find(is_incompatible) {
+    return Some(format!("{} as parameter", val_type_name(ty)));
+  }
+  func_type
+    .results()
+    .iter()
+    .find(is_incompatible)
+    .map(|ty| format!("{} as result", val_type_name(ty)))
+}
+
+fn val_type_name(ty: &ValType) -> &'static str {
+  match ty {
+    ValType::I64 => "i64",
+    ValType::V128 => "v128",
+    _ => "unknown",
+  }
+}
@@ -1,11 +1,16 @@
+use std::collections::BTreeMap;
+
 use rspack_core::rspack_sources::{BoxSource, RawSource, SourceExt};
 use rspack_core::{
-  get_filename_without_hash_length, impl_runtime_module, ChunkUkey, Compilation, PathData,
-  RuntimeModule, RuntimeModuleStage,
+  get_filename_without_hash_length, impl_runtime_module, BoxModule, ChunkUkey,
+  CodeGenerationDataFilename, Compilation, Dependency, ModuleDependency, ModuleType, PathData,
+  RuntimeGlobals, RuntimeModule, RuntimeModuleStage, RuntimeSpec, UsedName,
 };
 use rspack_identifier::Identifier;
 use rspack_util::source_map::SourceMapKind;
 
+use crate::dependency::WasmImportDependency;
+
 #[impl_runtime_module]
 #[derive(Debug, Eq)]
 pub struct AsyncWasmLoadingRuntimeModule {
@@ -103,3 +108,320 @@ fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
     "#
   )
 }
+
+/// Loads and instantiates the `webassembly/sync` modules of a chunk in the ensure chunk handlers,
+/// before the modules of the chunk are executed.
+#[impl_runtime_module]
+#[derive(Debug, Eq)]
+pub struct WasmChunkLoadingRuntimeModule {
+  generate_load_binary_code: String,
+  id: Identifier,
+  supports_streaming: bool,
+  chunk: ChunkUkey,
+}
+
+impl WasmChunkLoadingRuntimeModule {
+  pub fn new(
+    generate_load_binary_code: String,
+    supports_streaming: bool,
+    chunk: ChunkUkey,
+  ) -> Self {
+    Self {
+      generate_load_binary_code,
+      id: Identifier::from("webpack/runtime/wasm_chunk_loading"),
+      supports_streaming,
+      chunk,
+      source_map_kind: SourceMapKind::None,
+      custom_source: None,
+    }
+  }
+}
+
+impl RuntimeModule for WasmChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let chunk = compilation.chunk_by_ukey.expect_get(&self.chunk);
+    let wasm_modules = get_all_wasm_modules(compilation, &self.chunk);
+
+    let mut declarations = vec![];
+    let mut import_objects = vec![];
+    let mut module_paths = BTreeMap::new();
+    for (module, _) in &wasm_modules {
+      let module_id = module_id_to_json(compilation, module);
+      import_objects.push(generate_import_object(
+        compilation,
+        module,
+        &module_id,
+        &chunk.runtime,
+        &mut declarations,
+      ));
+      if let Some(filename) = compilation
+        .code_generation_results
+        .get(&module.identifier(), Some(&chunk.runtime))
+        .data
+        .get::<CodeGenerationDataFilename>()
+      {
+        module_paths.insert(module_id, filename.filename().to_string());
+      }
+    }
+
+    let mut chunk_module_id_map = BTreeMap::<String, Vec<String>>::new();
+    for (module, chunk_id) in &wasm_modules {
+      chunk_module_id_map
+        .entry(chunk_id.clone())
+        .or_default()
+        .push(
+          compilation
+            .chunk_graph
+            .get_module_id(module.identifier())
+            .clone()
+            .unwrap_or_default(),
+        );
+    }
+
+    let module_paths = module_paths
+      .into_iter()
+      .map(|(module_id, path)| {
+        format!(
+          "{module_id}: {}",
+          serde_json::to_string(&path).expect("should be valid json")
+        )
+      })
+      .collect::<Vec<_>>()
+      .join(",\n");
+
+    RawSource::from(format!(
+      r#"
+// object to store loaded and loading wasm modules
+var installedWasmModules = {{}};
+
+function promiseResolve() {{ return Promise.resolve(); }}
+
+{declarations}
+var wasmImportObjects = {{
+{import_objects}
+}};
+
+var wasmModuleMap = {module_map};
+
+var wasmModulePaths = {{
+{module_paths}
+}};
+
+// object with all WebAssembly.instance exports
+{wasm_instances} = {{}};
+
+// Fetch + compile chunk loading for webassembly
+{ensure_chunk_handlers}.wasm = function(chunkId, promises) {{
+  var wasmModules = wasmModuleMap[chunkId] || [];
+  wasmModules.forEach(function(wasmModuleId, idx) {{
+    var installedWasmModuleData = installedWasmModules[wasmModuleId];
+    // a Promise means "currently loading" or "already loaded".
+    if(installedWasmModuleData)
+      promises.push(installedWasmModuleData);
+    else {{
+      var importObject = wasmImportObjects[wasmModuleId]();
+      var req = {req};
+      var promise;
+      {instantiate_code}
+      promises.push(installedWasmModules[wasmModuleId] = promise.then(function(res) {{
+        return {wasm_instances}[wasmModuleId] = (res.instance || res).exports;
+      }}));
+    }}
+  }});
+}};
+"#,
+      declarations = declarations.join("\n"),
+      import_objects = import_objects.join(",\n"),
+      module_map = serde_json::to_string(&chunk_module_id_map).expect("should be valid json"),
+      wasm_instances = RuntimeGlobals::WASM_INSTANCES,
+      ensure_chunk_handlers = RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+      req = self
+        .generate_load_binary_code
+        .replace("$PATH", "wasmModulePaths[wasmModuleId]"),
+      instantiate_code = get_instantiate_code(self.supports_streaming),
+    ))
+    .boxed()
+  }
+}
+
+/// `webassembly/sync` modules of the async chunks of the chunk, with the ids of their chunks.
+pub(crate) fn get_all_wasm_modules<'a>(
+  compilation: &'a Compilation,
+  chunk: &ChunkUkey,
+) -> Vec<(&'a BoxModule, String)> {
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk);
+  let mut wasm_modules = vec![];
+  for async_chunk in chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey) {
+    let async_chunk = compilation.chunk_by_ukey.expect_get(&async_chunk);
+    let Some(chunk_id) = &async_chunk.id else {
+      continue;
+    };
+    let mut modules = compilation
+      .chunk_graph
+      .get_chunk_modules(&async_chunk.ukey, &compilation.module_graph);
+    modules.sort_unstable_by_key(|m| m.identifier());
+    for module in modules {
+      if *module.module_type() == ModuleType::WasmSync {
+        wasm_modules.push((module, chunk_id.clone()));
+      }
+    }
+  }
+  wasm_modules
+}
+
+fn module_id_to_json(compilation: &Compilation, module: &BoxModule) -> String {
+  serde_json::to_string(
+    compilation
+      .chunk_graph
+      .get_module_id(module.identifier())
+      .as_deref()
+      .unwrap_or_default(),
+  )
+  .expect("should be valid json")
+}
+
+/// The import object of a wasm module, functions imported from JavaScript modules are wrapped,
+/// because the JavaScript modules are executed after the wasm module is instantiated.
+fn generate_import_object(
+  compilation: &Compilation,
+  module: &BoxModule,
+  module_id: &str,
+  runtime: &RuntimeSpec,
+  declarations: &mut Vec<String>,
+) -> String {
+  let module_graph = &compilation.module_graph;
+  // instance variable and module id of the wasm modules imported directly
+  let mut wait_for_instances: Vec<(String, String)> = vec![];
+  let mut properties = BTreeMap::<String, Vec<String>>::new();
+
+  for dep in module
+    .get_dependencies()
+    .iter()
+    .filter_map(|id| module_graph.dependency_by_id(id))
+    .filter_map(|dep| dep.as_any().downcast_ref::<WasmImportDependency>())
+  {
+    let Some(imported_module) = module_graph.get_module(dep.id()) else {
+      continue;
+    };
+    let used_name = match module_graph
+      .get_exports_info(&imported_module.identifier())
+      .id
+      .get_used_name(
+        module_graph,
+        Some(runtime),
+        UsedName::Str(dep.name().into()),
+      ) {
+      Some(UsedName::Str(used_name)) => used_name.to_string(),
+      _ => dep.name().to_string(),
+    };
+    let used_name = serde_json::to_string(&used_name).expect("should be valid json");
+    let imported_module_id = module_id_to_json(compilation, imported_module);
+
+    let value = if dep.only_direct_import().is_some() {
+      let instance_var = match wait_for_instances
+        .iter()
+        .find(|(_, id)| *id == imported_module_id)
+      {
+        Some((instance_var, _)) => instance_var.clone(),
+        None => {
+          let instance_var = format!("m{}", wait_for_instances.len());
+          wait_for_instances.push((instance_var.clone(), imported_module_id));
+          instance_var
+        }
+      };
+      format!("{instance_var}[{used_name}]")
+    } else {
+      let module_exports = format!(
+        "{}[{imported_module_id}].exports",
+        RuntimeGlobals::MODULE_CACHE
+      );
+      let cache = format!("wasmImportedFuncCache{}", declarations.len());
+      declarations.push(format!("var {cache};"));
+      let wasm_export = if imported_module.module_type().is_wasm_like() {
+        format!(
+          "{}[{imported_module_id}] ? {module_exports}[{used_name}] : ",
+          RuntimeGlobals::MODULE_CACHE
+        )
+      } else {
+        String::new()
+      };
+      format!(
+        "{wasm_export}function() {{\n  if({cache} === undefined) {cache} = {module_exports};\n  return {cache}[{used_name}].apply(null, arguments);\n}}"
+      )
+    };
+    properties
+      .entry(serde_json::to_string(&dep.request()).expect("should be valid json"))
+      .or_default()
+      .push(format!(
+        "{}: {value}",
+        serde_json::to_string(dep.name()).expect("should be valid json")
+      ));
+  }
+
+  let import_object = format!(
+    "return {{\n{}\n}};",
+    properties
+      .into_iter()
+      .map(|(request, properties)| format!("{request}: {{\n{}\n}}", properties.join(",\n")))
+      .collect::<Vec<_>>()
+      .join(",\n")
+  );
+
+  match wait_for_instances.as_slice() {
+    [] => format!("{module_id}: function() {{\n{import_object}\n}}"),
+    [(instance_var, imported_module_id)] => format!(
+      "{module_id}: function() {{\nreturn promiseResolve().then(function() {{ return installedWasmModules[{imported_module_id}]; }}).then(function({instance_var}) {{\n{import_object}\n}});\n}}"
+    ),
+    _ => {
+      let promises = wait_for_instances
+        .iter()
+        .map(|(_, id)| format!("installedWasmModules[{id}]"))
+        .collect::<Vec<_>>()
+        .join(", ");
+      let variables = wait_for_instances
+        .iter()
+        .enumerate()
+        .map(|(i, (instance_var, _))| format!("{instance_var} = array[{i}]"))
+        .collect::<Vec<_>>()
+        .join(", ");
+      format!(
+        "{module_id}: function() {{\nreturn promiseResolve().then(function() {{ return Promise.all([{promises}]); }}).then(function(array) {{\nvar {variables};\n{import_object}\n}});\n}}"
+      )
+    }
+  }
+}
+
+fn get_instantiate_code(supports_streaming: bool) -> &'static str {
+  if supports_streaming {
+    r#"if(importObject && typeof importObject.then === 'function' && typeof WebAssembly.compileStreaming === 'function') {
+        promise = Promise.all([WebAssembly.compileStreaming(req), importObject]).then(function(items) {
+          return WebAssembly.instantiate(items[0], items[1]);
+        });
+      } else if(typeof WebAssembly.instantiateStreaming === 'function') {
+        promise = WebAssembly.instantiateStreaming(req, importObject);
+      } else {
+        var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+        promise = bytesPromise.then(function(bytes) {
+          return WebAssembly.instantiate(bytes, importObject);
+        });
+      }"#
+  } else {
+    r#"var bytesPromise = req.then(function(x) { return x.arrayBuffer(); });
+      if(importObject && typeof importObject.then === 'function') {
+        promise = Promise.all([
+          bytesPromise.then(function(bytes) { return WebAssembly.compile(bytes); }),
+          importObject
+        ]).then(function(items) {
+          return WebAssembly.instantiate(items[0], items[1]);
+        });
+      } else {
+        promise = bytesPromise.then(function(bytes) {
+          return WebAssembly.instantiate(bytes, importObject);
+        });
+      }"#
+  }
+}
@@ -2,13 +2,15 @@ use std::fmt::Debug;
 
 use rayon::prelude::*;
 use rspack_core::{
-  ApplyContext, CompilationArgs, CompilationParams, CompilerOptions, DependencyType, ModuleType,
-  ParserAndGenerator, Plugin, PluginCompilationHookOutput, PluginContext,
-  PluginRenderManifestHookOutput, RenderManifestArgs, RenderManifestEntry, SourceType,
+  ApplyContext, Compilation, CompilationArgs, CompilationParams, CompilerOptions, Dependency,
+  DependencyType, ExtendedReferencedExport, ModuleDependency, ModuleType, ParserAndGenerator,
+  Plugin, PluginCompilationHookOutput, PluginContext, PluginRenderManifestHookOutput,
+  RenderManifestArgs, RenderManifestEntry, SourceType,
 };
-use rspack_error::{IntoTWithDiagnosticArray, Result};
+use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result};
 
-use crate::{AsyncWasmParserAndGenerator, ModuleIdToFileName};
+use crate::dependency::WasmImportDependency;
+use crate::{AsyncWasmParserAndGenerator, ModuleIdToFileName, SyncWasmParserAndGenerator};
 
 pub struct EnableWasmLoadingPlugin;
 
@@ -78,38 +80,230 @@ impl Plugin for AsyncWasmPlugin {
     _ctx: PluginContext,
     args: RenderManifestArgs<'_>,
   ) -> PluginRenderManifestHookOutput {
-    let compilation = args.compilation;
-    let chunk = args.chunk();
+    render_wasm_manifest(
+      &args,
+      ModuleType::WasmAsync,
+      &self.module_id_to_filename_without_ext,
+    )
+  }
+}
+
+/// Emits the wasm files of the modules of `module_type` in the chunk.
+fn render_wasm_manifest(
+  args: &RenderManifestArgs<'_>,
+  module_type: ModuleType,
+  module_id_to_filename: &ModuleIdToFileName,
+) -> PluginRenderManifestHookOutput {
+  let compilation = args.compilation;
+  let chunk = args.chunk();
+  let module_graph = &compilation.module_graph;
+
+  let ordered_modules = compilation
+    .chunk_graph
+    .get_chunk_modules(&args.chunk_ukey, module_graph);
+
+  let files = ordered_modules
+    .par_iter()
+    .filter(|m| *m.module_type() == module_type)
+    .map(|m| {
+      let code_gen_result = compilation
+        .code_generation_results
+        .get(&m.identifier(), Some(&chunk.runtime));
+
+      let result = code_gen_result.get(&SourceType::Wasm).map(|source| {
+        let (output_path, asset_info) = module_id_to_filename
+          .get(&m.identifier())
+          .map(|s| s.clone())
+          .expect("should have wasm_filename");
+        RenderManifestEntry::new(source.clone(), output_path, asset_info, false, false)
+      });
+
+      Ok(result)
+    })
+    .collect::<Result<Vec<Option<RenderManifestEntry>>>>()?
+    .into_iter()
+    .flatten()
+    .collect::<Vec<RenderManifestEntry>>();
+
+  Ok(files.with_empty_diagnostic())
+}
+
+/// Port of webpack's `WebAssemblyModulesPlugin`, handles `webassembly/sync` modules.
+#[derive(Debug, Default)]
+pub struct SyncWasmPlugin {
+  pub module_id_to_filename_without_ext: ModuleIdToFileName,
+}
+
+impl SyncWasmPlugin {
+  pub fn new() -> SyncWasmPlugin {
+    Self {
+      module_id_to_filename_without_ext: Default::default(),
+    }
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for SyncWasmPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.WebAssemblyModulesPlugin"
+  }
+
+  async fn compilation(
+    &self,
+    args: CompilationArgs<'_>,
+    params: &CompilationParams,
+  ) -> PluginCompilationHookOutput {
+    args.compilation.set_dependency_factory(
+      DependencyType::WasmImport,
+      params.normal_module_factory.clone(),
+    );
+    args.compilation.set_dependency_factory(
+      DependencyType::WasmExportImported,
+      params.normal_module_factory.clone(),
+    );
+    Ok(())
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    let module_id_to_filename_without_ext = self.module_id_to_filename_without_ext.clone();
+
+    let builder = move || {
+      Box::new({
+        SyncWasmParserAndGenerator {
+          module_id_to_filename: module_id_to_filename_without_ext.clone(),
+        }
+      }) as Box<dyn ParserAndGenerator>
+    };
+
+    ctx
+      .context
+      .register_parser_and_generator_builder(ModuleType::WasmSync, Box::new(builder));
+
+    Ok(())
+  }
+
+  async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
     let module_graph = &compilation.module_graph;
+    let mut diagnostics = vec![];
 
-    let ordered_modules = compilation
-      .chunk_graph
-      .get_chunk_modules(&args.chunk_ukey, module_graph);
-
-    let files = ordered_modules
-      .par_iter()
-      .filter(|m| *m.module_type() == ModuleType::WasmAsync)
-      .map(|m| {
-        let code_gen_result = compilation
-          .code_generation_results
-          .get(&m.identifier(), Some(&chunk.runtime));
-
-        let result = code_gen_result.get(&SourceType::Wasm).map(|source| {
-          let (output_path, asset_info) = self
-            .module_id_to_filename_without_ext
-            .get(&m.identifier())
-            .map(|s| s.clone())
-            .expect("should have wasm_filename");
-          RenderManifestEntry::new(source.clone(), output_path, asset_info, false, false)
-        });
-
-        Ok(result)
-      })
-      .collect::<Result<Vec<Option<RenderManifestEntry>>>>()?
-      .into_iter()
-      .flatten()
-      .collect::<Vec<RenderManifestEntry>>();
-
-    Ok(files.with_empty_diagnostic())
+    for module in module_graph.modules().values() {
+      if *module.module_type() != ModuleType::WasmSync {
+        continue;
+      }
+
+      // imports which can't be provided by JavaScript modules
+      for dep in module
+        .get_dependencies()
+        .iter()
+        .filter_map(|id| module_graph.dependency_by_id(id))
+        .filter_map(|dep| dep.as_any().downcast_ref::<WasmImportDependency>())
+      {
+        if let Some(only_direct_import) = dep.only_direct_import()
+          && let Some(imported_module) = module_graph.get_module(dep.id())
+          && !imported_module.module_type().is_wasm_like()
+        {
+          diagnostics.push(
+            Diagnostic::error(
+              "Unsupported WebAssembly Feature Error".into(),
+              format!(
+                "Import \"{}\" from \"{}\" with {only_direct_import} can only be used for direct wasm to wasm dependencies",
+                dep.name(),
+                dep.request()
+              ),
+            )
+            .with_module_identifier(Some(module.identifier())),
+          );
+        }
+      }
+
+      // exports which can't be called from JavaScript modules
+      let Some(js_incompatible_exports) = module
+        .build_meta()
+        .and_then(|build_meta| build_meta.js_incompatible_exports.as_ref())
+      else {
+        continue;
+      };
+      for connection in module_graph.get_incoming_connections(module) {
+        let Some(origin_module) = connection
+          .original_module_identifier
+          .and_then(|identifier| module_graph.module_by_identifier(&identifier))
+        else {
+          continue;
+        };
+        if origin_module.module_type().is_wasm_like() {
+          continue;
+        }
+        let Some(dep) = module_graph
+          .dependency_by_id(&connection.dependency_id)
+          .and_then(|dep| dep.as_module_dependency())
+        else {
+          continue;
+        };
+        for referenced_export in dep.get_referenced_exports(module_graph, None) {
+          let names = match &referenced_export {
+            ExtendedReferencedExport::Array(names) => names,
+            ExtendedReferencedExport::Export(export) => &export.name,
+          };
+          if let Some(name) = names.first()
+            && let Some(ty) = js_incompatible_exports.get(&**name)
+          {
+            diagnostics.push(
+              Diagnostic::error(
+                "Unsupported WebAssembly Feature Error".into(),
+                format!(
+                  "Export \"{name}\" with {ty} can only be used for direct wasm to wasm dependencies\nIt's used from {}",
+                  origin_module.readable_identifier(&compilation.options.context)
+                ),
+              )
+              .with_module_identifier(Some(module.identifier())),
+            );
+          }
+        }
+      }
+    }
+
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+
+  async fn optimize_tree(&self, compilation: &mut Compilation) -> Result<()> {
+    let mut diagnostics = vec![];
+    for chunk in compilation.chunk_by_ukey.values() {
+      if !chunk.can_be_initial(&compilation.chunk_group_by_ukey) {
+        continue;
+      }
+      for module in compilation
+        .chunk_graph
+        .get_chunk_modules(&chunk.ukey, &compilation.module_graph)
+      {
+        if *module.module_type() == ModuleType::WasmSync {
+          diagnostics.push(
+            Diagnostic::error(
+              "WebAssembly In Initial Chunk Error".into(),
+              "WebAssembly module is included in initial chunk.\nThis is not allowed, because WebAssembly download and compilation must happen asynchronous.\nAdd an async split point (i. e. import()) somewhere between your entrypoint and the WebAssembly module.".into(),
+            )
+            .with_module_identifier(Some(module.identifier())),
+          );
+        }
+      }
+    }
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+
+  async fn render_manifest(
+    &self,
+    _ctx: PluginContext,
+    args: RenderManifestArgs<'_>,
+  ) -> PluginRenderManifestHookOutput {
+    render_wasm_manifest(
+      &args,
+      ModuleType::WasmSync,
+      &self.module_id_to_filename_without_ext,
+    )
   }
 }
@@ -3,9 +3,11 @@ use std::{
   convert::TryFrom,
   path::{Path, PathBuf},
   str::FromStr,
+  sync::Arc,
 };
 
 use rspack_core::{BoxPlugin, CompilerOptions, ModuleType, PluginExt};
+use rspack_fs::NativeFileSystem;
 use rspack_plugin_devtool::{
   Append, SourceMapDevToolModuleOptionsPlugin, SourceMapDevToolModuleOptionsPluginOptions,
 };
@@ -355,6 +357,8 @@ impl TestConfig {
 
     let options = CompilerOptions {
       bail: false,
+      records_input_path: None,
+      records_output_path: None,
       context: root.clone(),
       output: c::OutputOptions {
         clean: self.output.clean,
@@ -427,6 +431,8 @@ impl TestConfig {
         provide: self.builtins.provide,
         tree_shaking: self.builtins.tree_shaking.into(),
       },
+      input_filesystem: Arc::new(NativeFileSystem),
+      intermediate_filesystem: Arc::new(NativeFileSystem),
       module: c::ModuleOptions {
         rules,
         ..Default::default()
@@ -462,12 +468,14 @@ impl TestConfig {
             rspack_core::EntryOptions {
               name: Some(name.clone()),
               runtime: Some("runtime".to_string()),
+              depend_on: None,
               chunk_loading: None,
               async_chunks: Some(true),
               public_path: None,
               base_uri: None,
               filename: None,
               library: None,
+              layer: None,
             },
           )
           .boxed(),
@@ -491,6 +499,11 @@ impl TestConfig {
           ),
           exports_only: self.builtins.css.modules.exports_only,
         },
+        targets: self
+          .builtins
+          .preset_env
+          .map(|preset_env| preset_env.targets)
+          .unwrap_or_default(),
       })
       .boxed(),
     );
@@ -534,6 +547,7 @@ impl TestConfig {
             file_context: None,
             source_root: None,
             test: None,
+            ignore_list: None,
           },
         )
         .boxed(),
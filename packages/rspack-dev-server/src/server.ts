@@ -227,20 +227,26 @@ export class RspackDevServer extends WebpackDevServer {
 			if (compiler.options.experiments.lazyCompilation) {
 				middlewares.push({
 					// @ts-expect-error
-					middleware: (req, res) => {
-						if (req.url.indexOf("/lazy-compilation-web/") > -1) {
-							const path = req.url.replace("/lazy-compilation-web/", "");
-							if (fs.existsSync(path)) {
-								compiler.rebuild(new Set([path]), new Set(), error => {
-									if (error) {
-										throw error;
-									}
-									res.write("");
-									res.end();
-									console.log("lazy compiler success");
-								});
-							}
+					middleware: (req, res, next) => {
+						if (req.url.indexOf("/lazy-compilation-web/") === -1) {
+							return next();
+						}
+						// the client encodes the resource path of the module to activate
+						const path = decodeURIComponent(
+							req.url.replace("/lazy-compilation-web/", "")
+						);
+						if (!fs.existsSync(path)) {
+							res.statusCode = 404;
+							return res.end();
 						}
+						compiler.rebuild(new Set([path]), new Set(), error => {
+							if (error) {
+								throw error;
+							}
+							res.write("");
+							res.end();
+							console.log("lazy compiler success");
+						});
 					}
 				});
 			}
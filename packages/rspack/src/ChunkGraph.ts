@@ -2,7 +2,8 @@ import {
 	__chunk_graph_inner_get_chunk_entry_dependent_chunks_iterable,
 	__chunk_graph_inner_get_chunk_entry_modules,
 	__chunk_graph_inner_get_chunk_modules,
-	__chunk_graph_inner_get_chunk_modules_iterable_by_source_type
+	__chunk_graph_inner_get_chunk_modules_iterable_by_source_type,
+	__chunk_graph_inner_get_module_chunks
 } from "@rspack/binding";
 import { Chunk } from "./Chunk";
 import { Compilation } from "./Compilation";
@@ -59,4 +60,17 @@ export class ChunkGraph {
 			).map(m => Module.__from_binding(m))
 		);
 	}
+
+	getModuleChunks(module: Module): Chunk[] {
+		return __chunk_graph_inner_get_module_chunks(
+			module.identifier(),
+			this.compilation.__internal_getInner()
+		).map(c =>
+			Chunk.__from_binding(c, this.compilation.__internal_getInner())
+		);
+	}
+
+	getModuleChunksIterable(module: Module): Iterable<Chunk> {
+		return new Set(this.getModuleChunks(module));
+	}
 }
@@ -59,6 +59,7 @@ import { memoizeValue } from "./util/memoize";
 import { Chunk } from "./Chunk";
 import { CodeGenerationResult } from "./Module";
 import { ChunkGraph } from "./ChunkGraph";
+import { ModuleGraph } from "./ModuleGraph";
 
 export type AssetInfo = Partial<JsAssetInfo> & Record<string, any>;
 export type Assets = Record<string, Source>;
@@ -145,6 +146,7 @@ export class Compilation {
 	children: Compilation[] = [];
 	contextModuleFactory?: ContextModuleFactory;
 	chunkGraph: ChunkGraph;
+	moduleGraph: ModuleGraph;
 	fileSystemInfo = {
 		createSnapshot() {
 			// fake implement to support html-webpack-plugin
@@ -199,6 +201,7 @@ export class Compilation {
 		this.outputOptions = compiler.options.output;
 		this.logging = new Map();
 		this.chunkGraph = new ChunkGraph(this);
+		this.moduleGraph = new ModuleGraph(this);
 		this.#inner = inner;
 		// Cache the current NormalModuleHooks
 	}
@@ -344,6 +347,19 @@ export class Compilation {
 			!context.forToString
 		);
 		options.source = optionOrLocalFallback(options.source, false);
+		// not turned on by `all` yet, so the default output stays the same
+		options.providedExports = optionsOrFallback(
+			options.providedExports,
+			false
+		);
+		options.usedExports = optionsOrFallback(options.usedExports, false);
+		options.optimizationBailout = optionsOrFallback(
+			options.optimizationBailout,
+			false
+		);
+		options.depth = optionsOrFallback(options.depth, false);
+		options.cacheStatus = optionsOrFallback(options.cacheStatus, false);
+		options.chunkOrigins = optionsOrFallback(options.chunkOrigins, false);
 		options.logging = optionOrLocalFallback(
 			options.logging,
 			context.forToString ? "info" : true
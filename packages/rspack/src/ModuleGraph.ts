@@ -0,0 +1,107 @@
+import {
+	JsExportInfo,
+	JsModuleGraphConnection,
+	__module_graph_inner_get_exports_info,
+	__module_graph_inner_get_incoming_connections,
+	__module_graph_inner_get_module,
+	__module_graph_inner_get_outgoing_connections
+} from "@rspack/binding";
+import { Compilation } from "./Compilation";
+import { Module } from "./Module";
+
+export class ModuleGraphConnection {
+	#inner: JsModuleGraphConnection;
+	#moduleGraph: ModuleGraph;
+
+	constructor(inner: JsModuleGraphConnection, moduleGraph: ModuleGraph) {
+		this.#inner = inner;
+		this.#moduleGraph = moduleGraph;
+	}
+
+	/**
+	 * The module that contains the dependency, `null` for entries.
+	 */
+	get originModule(): Module | null {
+		const identifier = this.#inner.originModuleIdentifier;
+		return identifier
+			? this.#moduleGraph.getModuleByIdentifier(identifier)
+			: null;
+	}
+
+	get module(): Module | null {
+		return this.#moduleGraph.getModuleByIdentifier(
+			this.#inner.moduleIdentifier
+		);
+	}
+
+	get dependencyType(): string {
+		return this.#inner.dependencyType;
+	}
+
+	get userRequest(): string | undefined {
+		return this.#inner.userRequest;
+	}
+
+	get active(): boolean {
+		return this.#inner.active;
+	}
+
+	get conditional(): boolean {
+		return this.#inner.conditional;
+	}
+}
+
+export type ExportInfo = Readonly<JsExportInfo>;
+
+export class ModuleGraph {
+	constructor(private compilation: Compilation) {}
+
+	getModuleByIdentifier(identifier: string): Module | null {
+		const module = __module_graph_inner_get_module(
+			identifier,
+			this.compilation.__internal_getInner()
+		);
+		return module ? Module.__from_binding(module) : null;
+	}
+
+	/**
+	 * Connections to the modules imported by the module.
+	 */
+	getOutgoingConnections(module: Module): ModuleGraphConnection[] {
+		return __module_graph_inner_get_outgoing_connections(
+			module.identifier(),
+			this.compilation.__internal_getInner()
+		).map(c => new ModuleGraphConnection(c, this));
+	}
+
+	/**
+	 * Connections from the modules importing the module.
+	 */
+	getIncomingConnections(module: Module): ModuleGraphConnection[] {
+		return __module_graph_inner_get_incoming_connections(
+			module.identifier(),
+			this.compilation.__internal_getInner()
+		).map(c => new ModuleGraphConnection(c, this));
+	}
+
+	getExportsInfo(module: Module): ExportInfo[] {
+		return __module_graph_inner_get_exports_info(
+			module.identifier(),
+			this.compilation.__internal_getInner()
+		);
+	}
+
+	getProvidedExports(module: Module): string[] {
+		return this.getExportsInfo(module)
+			.filter(info => info.provided === true)
+			.map(info => info.name);
+	}
+
+	getUsedExports(module: Module): string[] {
+		return this.getExportsInfo(module)
+			.filter(
+				info => info.used === "used" || info.used === "onlyPropertiesUsed"
+			)
+			.map(info => info.name);
+	}
+}
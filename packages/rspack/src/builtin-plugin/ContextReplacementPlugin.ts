@@ -0,0 +1,43 @@
+import { RawContextReplacementPluginOptions } from "@rspack/binding";
+import { BuiltinPluginName, create } from "./base";
+
+export const ContextReplacementPlugin = create(
+	BuiltinPluginName.ContextReplacementPlugin,
+	(
+		resourceRegExp: RegExp,
+		newContentResource?: any,
+		newContentRecursive?: any,
+		newContentRegExp?: any
+	): RawContextReplacementPluginOptions => {
+		const rawOptions: RawContextReplacementPluginOptions = {
+			resourceRegExp
+		};
+		if (typeof newContentResource === "function") {
+			throw new Error(
+				"ContextReplacementPlugin doesn't support a callback as newContentResource yet"
+			);
+		}
+		if (
+			typeof newContentResource === "string" &&
+			typeof newContentRecursive === "object"
+		) {
+			// an explicit content of the context, requests mapped to the modules to include
+			rawOptions.newContentResource = newContentResource;
+			rawOptions.newContentCreateContextMap = newContentRecursive;
+			return rawOptions;
+		}
+		if (typeof newContentResource !== "string") {
+			newContentRegExp = newContentRecursive;
+			newContentRecursive = newContentResource;
+			newContentResource = undefined;
+		}
+		if (typeof newContentRecursive !== "boolean") {
+			newContentRegExp = newContentRecursive;
+			newContentRecursive = undefined;
+		}
+		rawOptions.newContentResource = newContentResource;
+		rawOptions.newContentRecursive = newContentRecursive;
+		rawOptions.newContentRegExp = newContentRegExp;
+		return rawOptions;
+	}
+);
@@ -4,6 +4,7 @@ import {
 	ChunkLoading,
 	EntryRuntime,
 	Filename,
+	Layer,
 	LibraryOptions,
 	PublicPath,
 	getRawChunkLoading,
@@ -15,12 +16,14 @@ import { isNil } from "../util";
 export type EntryOptions = {
 	name?: string;
 	runtime?: EntryRuntime;
+	dependOn?: string[];
 	chunkLoading?: ChunkLoading;
 	asyncChunks?: boolean;
 	publicPath?: PublicPath;
 	baseUri?: string;
 	filename?: Filename;
 	library?: LibraryOptions;
+	layer?: Layer;
 };
 export const EntryPlugin = create(
 	BuiltinPluginName.EntryPlugin,
@@ -47,11 +50,13 @@ function getRawEntryOptions(entry: EntryOptions): RawEntryOptions {
 		publicPath: entry.publicPath,
 		baseUri: entry.baseUri,
 		runtime: !isNil(runtime) ? getRawEntryRuntime(runtime) : undefined,
+		dependOn: entry.dependOn,
 		chunkLoading: !isNil(chunkLoading)
 			? getRawChunkLoading(chunkLoading)
 			: undefined,
 		asyncChunks: entry.asyncChunks,
 		filename: entry.filename,
-		library: entry.library && getRawLibrary(entry.library)
+		library: entry.library && getRawLibrary(entry.library),
+		layer: entry.layer ?? undefined
 	};
 }
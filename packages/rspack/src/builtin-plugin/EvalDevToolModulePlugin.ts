@@ -0,0 +1,12 @@
+import { RawEvalDevToolModulePluginOptions } from "@rspack/binding";
+import { BuiltinPluginName, create } from "./base";
+
+export type EvalDevToolModulePluginOptions = RawEvalDevToolModulePluginOptions;
+
+export const EvalDevToolModulePlugin = create(
+	BuiltinPluginName.EvalDevToolModulePlugin,
+	(
+		options: EvalDevToolModulePluginOptions
+	): RawEvalDevToolModulePluginOptions => options,
+	"compilation"
+);
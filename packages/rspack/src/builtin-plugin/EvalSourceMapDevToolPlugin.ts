@@ -1,4 +1,5 @@
 import { RawSourceMapDevToolPluginOptions } from "@rspack/binding";
+import { matchPart } from "../ModuleFilenameHelpers";
 import { BuiltinPluginName, create } from "./base";
 import { SourceMapDevToolPluginOptions } from "./SourceMapDevToolPlugin";
 
@@ -13,7 +14,10 @@ export const EvalSourceMapDevToolPlugin = create(
 			namespace: options.namespace ?? "",
 			columns: options.columns ?? true,
 			noSources: options.noSources ?? false,
-			publicPath: options.publicPath
+			publicPath: options.publicPath,
+			ignoreList: options.ignoreList
+				? text => matchPart(text, options.ignoreList!)
+				: undefined
 		};
 	},
 	"compilation"
@@ -0,0 +1,81 @@
+import http from "http";
+import https from "https";
+import {
+	RawHttpClientResponse,
+	RawHttpUriPluginOptions
+} from "@rspack/binding";
+import { BuiltinPluginName, create } from "./base";
+
+export type HttpClientResponse = RawHttpClientResponse;
+
+/**
+ * Fetches `url` with a GET request, redirects should be followed.
+ */
+export type HttpClient = (url: string) => Promise<HttpClientResponse>;
+
+export type HttpUriPluginOptions = {
+	/** Only urls matching one of these are fetched */
+	allowedUris: (string | RegExp)[];
+	lockfileLocation: string;
+	/** Directory storing fetched content, `false` to fetch in every build */
+	cacheLocation: string | false;
+	/** Build from the lockfile and the cache only */
+	frozen: boolean;
+	/** Refetch urls already in the lockfile and update their entries */
+	upgrade: boolean;
+	httpClient?: HttpClient;
+};
+
+const MAX_REDIRECTS = 5;
+
+const fetchWithNode = (
+	url: string,
+	redirects: number
+): Promise<HttpClientResponse> =>
+	new Promise((resolve, reject) => {
+		const send = url.startsWith("https:") ? https.get : http.get;
+		const request = send(url, res => {
+			const status = res.statusCode ?? 0;
+			const location = res.headers.location;
+			if (status >= 300 && status < 400 && location) {
+				res.resume();
+				if (redirects <= 0) {
+					reject(new Error(`Too many redirects when fetching ${url}`));
+					return;
+				}
+				resolve(fetchWithNode(new URL(location, url).href, redirects - 1));
+				return;
+			}
+			const chunks: Buffer[] = [];
+			res.on("data", chunk => chunks.push(chunk));
+			res.on("error", reject);
+			res.on("end", () => {
+				const headers: Record<string, string> = {};
+				for (const [name, value] of Object.entries(res.headers)) {
+					if (value !== undefined) {
+						headers[name] = Array.isArray(value) ? value.join(", ") : value;
+					}
+				}
+				resolve({ status, headers, body: Buffer.concat(chunks) });
+			});
+		});
+		request.on("error", reject);
+	});
+
+export const defaultHttpClient: HttpClient = url =>
+	fetchWithNode(url, MAX_REDIRECTS);
+
+export const HttpUriPlugin = create(
+	BuiltinPluginName.HttpUriPlugin,
+	(options: HttpUriPluginOptions): RawHttpUriPluginOptions => {
+		return {
+			allowedUris: options.allowedUris,
+			lockfileLocation: options.lockfileLocation,
+			cacheLocation: options.cacheLocation || undefined,
+			frozen: options.frozen,
+			upgrade: options.upgrade,
+			httpClient: options.httpClient ?? defaultHttpClient
+		};
+	},
+	"compilation"
+);
@@ -0,0 +1,31 @@
+import { RawIgnorePluginOptions } from "@rspack/binding";
+import { BuiltinPluginName, create } from "./base";
+
+export type IgnorePluginOptions =
+	| {
+			/** A RegExp to test the request against. */
+			resourceRegExp: RegExp;
+			/** A RegExp to test the context (directory) against. */
+			contextRegExp?: RegExp;
+	  }
+	| {
+			/** A filter function for resource and context. */
+			checkResource: (resource: string, context: string) => boolean;
+	  };
+
+export const IgnorePlugin = create(
+	BuiltinPluginName.IgnorePlugin,
+	(options: IgnorePluginOptions): RawIgnorePluginOptions => {
+		if ("checkResource" in options) {
+			const { checkResource } = options;
+			return {
+				checkResource: ({ resource, context }) =>
+					checkResource(resource, context)
+			};
+		}
+		return {
+			resourceRegExp: options.resourceRegExp,
+			contextRegExp: options.contextRegExp
+		};
+	}
+);
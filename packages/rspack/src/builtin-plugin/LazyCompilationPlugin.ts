@@ -0,0 +1,26 @@
+import { RawLazyCompilationPluginOptions } from "@rspack/binding";
+import { BuiltinPluginName, create } from "./base";
+
+export type LazyCompilationPluginOptions = {
+	/** Compile entries lazily */
+	entries: boolean;
+	/** Compile dynamic imports lazily */
+	imports: boolean;
+	/** Only compile modules matching this RegExp lazily */
+	test?: RegExp;
+	/** Requested by the client with the encoded resource path to activate a module */
+	clientUrl: string;
+};
+
+export const LazyCompilationPlugin = create(
+	BuiltinPluginName.LazyCompilationPlugin,
+	(options: LazyCompilationPluginOptions): RawLazyCompilationPluginOptions => {
+		return {
+			entries: options.entries,
+			imports: options.imports,
+			test: options.test,
+			clientUrl: options.clientUrl
+		};
+	},
+	"compilation"
+);
@@ -0,0 +1,28 @@
+import {
+	RawNormalModuleReplacementPluginOptions,
+	RawNormalModuleReplacementResolveData
+} from "@rspack/binding";
+import { BuiltinPluginName, create } from "./base";
+
+export type NormalModuleReplacementResolveData =
+	RawNormalModuleReplacementResolveData;
+
+export const NormalModuleReplacementPlugin = create(
+	BuiltinPluginName.NormalModuleReplacementPlugin,
+	(
+		resourceRegExp: RegExp,
+		newResource: string | ((data: NormalModuleReplacementResolveData) => void)
+	): RawNormalModuleReplacementPluginOptions => {
+		if (typeof newResource === "function") {
+			return {
+				resourceRegExp,
+				// the callback mutates the data like in webpack
+				newResource: data => {
+					newResource(data);
+					return data;
+				}
+			};
+		}
+		return { resourceRegExp, newResource };
+	}
+);
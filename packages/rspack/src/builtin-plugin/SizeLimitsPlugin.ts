@@ -0,0 +1,22 @@
+import { RawSizeLimitsPluginOptions } from "@rspack/binding";
+import { BuiltinPluginName, create } from "./base";
+
+export type SizeLimitsPluginOptions = {
+	hints: "warning" | "error";
+	maxAssetSize: number;
+	maxEntrypointSize: number;
+	assetFilter?: (assetFilename: string) => boolean;
+};
+
+export const SizeLimitsPlugin = create(
+	BuiltinPluginName.SizeLimitsPlugin,
+	(options: SizeLimitsPluginOptions): RawSizeLimitsPluginOptions => {
+		return {
+			hints: options.hints,
+			maxAssetSize: options.maxAssetSize,
+			maxEntrypointSize: options.maxEntrypointSize,
+			assetFilter: options.assetFilter
+		};
+	},
+	"compilation"
+);
@@ -1,5 +1,5 @@
 import { RawSourceMapDevToolPluginOptions } from "@rspack/binding";
-import { matchObject } from "../ModuleFilenameHelpers";
+import { matchObject, matchPart } from "../ModuleFilenameHelpers";
 import { BuiltinPluginName, create } from "./base";
 
 /**
@@ -14,11 +14,15 @@ type Rule = RegExp | string;
 export interface SourceMapDevToolPluginOptions
 	extends Omit<
 		RawSourceMapDevToolPluginOptions,
-		"test" | "include" | "exclude"
+		"test" | "include" | "exclude" | "ignoreList"
 	> {
 	exclude?: Rules;
 	include?: Rules;
 	test?: Rules;
+	/**
+	 * Sources matching are added to the `ignoreList` of the source map.
+	 */
+	ignoreList?: Rules;
 }
 
 export const SourceMapDevToolPlugin = create(
@@ -26,7 +30,7 @@ export const SourceMapDevToolPlugin = create(
 	(
 		options: SourceMapDevToolPluginOptions
 	): RawSourceMapDevToolPluginOptions => {
-		const { test, include, exclude, ...rest } = options;
+		const { test, include, exclude, ignoreList, ...rest } = options;
 
 		const rawOptions: RawSourceMapDevToolPluginOptions = rest;
 
@@ -34,6 +38,10 @@ export const SourceMapDevToolPlugin = create(
 			rawOptions.test = text => matchObject({ test, include, exclude }, text);
 		}
 
+		if (ignoreList) {
+			rawOptions.ignoreList = text => matchPart(text, ignoreList);
+		}
+
 		return rawOptions;
 	},
 	"compilation"
@@ -44,19 +44,27 @@ export enum BuiltinPluginName {
 	WarnCaseSensitiveModulesPlugin = "WarnCaseSensitiveModulesPlugin",
 	DataUriPlugin = "DataUriPlugin",
 	FileUriPlugin = "FileUriPlugin",
+	HttpUriPlugin = "HttpUriPlugin",
 	RuntimePlugin = "RuntimePlugin",
 	JsonModulesPlugin = "JsonModulesPlugin",
 	InferAsyncModulesPlugin = "InferAsyncModulesPlugin",
 	JavascriptModulesPlugin = "JavascriptModulesPlugin",
 	AsyncWebAssemblyModulesPlugin = "AsyncWebAssemblyModulesPlugin",
+	WebAssemblyModulesPlugin = "WebAssemblyModulesPlugin",
 	AssetModulesPlugin = "AssetModulesPlugin",
 	SourceMapDevToolPlugin = "SourceMapDevToolPlugin",
 	EvalSourceMapDevToolPlugin = "EvalSourceMapDevToolPlugin",
+	EvalDevToolModulePlugin = "EvalDevToolModulePlugin",
 	SideEffectsFlagPlugin = "SideEffectsFlagPlugin",
 	FlagDependencyExportsPlugin = "FlagDependencyExportsPlugin",
 	FlagDependencyUsagePlugin = "FlagDependencyUsagePlugin",
 	MangleExportsPlugin = "MangleExportsPlugin",
-	BundlerInfoPlugin = "BundlerInfoPlugin"
+	BundlerInfoPlugin = "BundlerInfoPlugin",
+	IgnorePlugin = "IgnorePlugin",
+	NormalModuleReplacementPlugin = "NormalModuleReplacementPlugin",
+	ContextReplacementPlugin = "ContextReplacementPlugin",
+	SizeLimitsPlugin = "SizeLimitsPlugin",
+	LazyCompilationPlugin = "LazyCompilationPlugin"
 }
 
 type AffectedHooks = keyof Compiler["hooks"];
@@ -32,19 +32,27 @@ export * from "./EnsureChunkConditionsPlugin";
 export * from "./WarnCaseSensitiveModulesPlugin";
 export * from "./DataUriPlugin";
 export * from "./FileUriPlugin";
+export * from "./HttpUriPlugin";
 export * from "./RuntimePlugin";
 export * from "./JsonModulesPlugin";
 export * from "./InferAsyncModulesPlugin";
 export * from "./JavascriptModulesPlugin";
 export * from "./AsyncWebAssemblyModulesPlugin";
+export * from "./WebAssemblyModulesPlugin";
 export * from "./AssetModulesPlugin";
 export * from "./SourceMapDevToolPlugin";
 export * from "./EvalSourceMapDevToolPlugin";
+export * from "./EvalDevToolModulePlugin";
 export * from "./SideEffectsFlagPlugin";
 export * from "./FlagDependencyExportsPlugin";
 export * from "./FlagDependencyUsagePlugin";
 export * from "./MangleExportsPlugin";
 export * from "./BundlerInfoPlugin";
+export * from "./IgnorePlugin";
+export * from "./NormalModuleReplacementPlugin";
+export * from "./ContextReplacementPlugin";
+export * from "./SizeLimitsPlugin";
+export * from "./LazyCompilationPlugin";
 
 export * from "./HtmlRspackPlugin";
 export * from "./CopyRspackPlugin";
@@ -54,6 +62,7 @@ export * from "./SwcCssMinimizerPlugin";
 ///// DEPRECATED /////
 import { RawBuiltins, RawCssModulesConfig } from "@rspack/binding";
 import { Compiler, RspackOptionsNormalized } from "..";
+import { getTargetsBrowsers } from "../config/target";
 
 type BuiltinsCssConfig = {
 	modules?: Partial<RawCssModulesConfig>;
@@ -70,6 +79,16 @@ function resolveTreeShaking(
 		: "false";
 }
 
+function resolveCssTargets(
+	target: RspackOptionsNormalized["target"],
+	context: string
+): string[] {
+	if (!target) {
+		return [];
+	}
+	return getTargetsBrowsers(Array.isArray(target) ? target : [target], context);
+}
+
 export interface Builtins {
 	css?: BuiltinsCssConfig;
 	treeShaking?: boolean | "module";
@@ -95,7 +114,9 @@ export function deprecated_resolveBuiltins(
 							: "[path][name][ext]__[local]",
 						exportsOnly: false,
 						...builtins.css?.modules
-					}
+					},
+					// css is lowered and prefixed for the browsers of the `browserslist` targets
+					targets: resolveCssTargets(options.target, contextPath)
 			  }
 			: undefined,
 		treeShaking: resolveTreeShaking(builtins.treeShaking, production)
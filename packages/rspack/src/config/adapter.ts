@@ -112,6 +112,8 @@ export const getRawOptions = (
 		profile: options.profile!,
 		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
 		bail: options.bail!,
+		recordsInputPath: options.recordsInputPath || undefined,
+		recordsOutputPath: options.recordsOutputPath || undefined,
 		// TODO: remove this
 		builtins: options.builtins as any
 	};
@@ -380,6 +382,9 @@ const getRawModuleRule = (
 		include: rule.include ? getRawRuleSetCondition(rule.include) : undefined,
 		exclude: rule.exclude ? getRawRuleSetCondition(rule.exclude) : undefined,
 		issuer: rule.issuer ? getRawRuleSetCondition(rule.issuer) : undefined,
+		issuerLayer: rule.issuerLayer
+			? getRawRuleSetCondition(rule.issuerLayer)
+			: undefined,
 		dependency: rule.dependency
 			? getRawRuleSetCondition(rule.dependency)
 			: undefined,
@@ -401,6 +406,7 @@ const getRawModuleRule = (
 		scheme: rule.scheme ? getRawRuleSetCondition(rule.scheme) : undefined,
 		mimetype: rule.mimetype ? getRawRuleSetCondition(rule.mimetype) : undefined,
 		sideEffects: rule.sideEffects,
+		layer: rule.layer,
 		use:
 			typeof rule.use === "function"
 				? { type: "function", funcUse }
@@ -571,7 +577,13 @@ function getRawJavascriptParserOptions(parser: JavascriptParserOptions) {
 				? "false"
 				: parser.url === "relative"
 				? parser.url
-				: "true"
+				: "true",
+		worker:
+			parser.worker === false
+				? []
+				: Array.isArray(parser.worker)
+				? parser.worker
+				: ["..."]
 	};
 }
 
@@ -728,14 +740,18 @@ function getRawSnapshotOptions(
 function getRawExperiments(
 	experiments: ExperimentsNormalized
 ): RawOptions["experiments"] {
-	const { newSplitChunks, topLevelAwait, rspackFuture } = experiments;
+	const { newSplitChunks, topLevelAwait, layers, rspackFuture } = experiments;
 	assert(
-		!isNil(newSplitChunks) && !isNil(topLevelAwait) && !isNil(rspackFuture)
+		!isNil(newSplitChunks) &&
+			!isNil(topLevelAwait) &&
+			!isNil(layers) &&
+			!isNil(rspackFuture)
 	);
 
 	return {
 		newSplitChunks,
 		topLevelAwait,
+		layers,
 		rspackFuture: getRawRspackFutureOptions(rspackFuture)
 	};
 }
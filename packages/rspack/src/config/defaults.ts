@@ -29,6 +29,7 @@ import type {
 	ModuleOptions,
 	Node,
 	Optimization,
+	Performance,
 	ResolveOptions,
 	RuleSetRules,
 	SnapshotOptions
@@ -77,18 +78,32 @@ export const applyRspackOptionsDefaults = (
 	D(options, "watch", false);
 	D(options, "profile", false);
 	D(options, "bail", false);
+	// relative records paths are relative to the context
+	if (options.recordsInputPath) {
+		options.recordsInputPath = path.resolve(
+			options.context!,
+			options.recordsInputPath
+		);
+	}
+	if (options.recordsOutputPath) {
+		options.recordsOutputPath = path.resolve(
+			options.context!,
+			options.recordsOutputPath
+		);
+	}
 
 	const futureDefaults = options.experiments.futureDefaults ?? false;
 	F(options, "cache", () => development);
 
 	applyExperimentsDefaults(options.experiments, {
-		cache: options.cache!
+		cache: options.cache!,
+		production
 	});
 
 	applySnapshotDefaults(options.snapshot, { production });
 
 	applyModuleDefaults(options.module, {
-		// syncWebAssembly: options.experiments.syncWebAssembly,
+		syncWebAssembly: options.experiments.syncWebAssembly!,
 		asyncWebAssembly: options.experiments.asyncWebAssembly!,
 		css: options.experiments.css!
 	});
@@ -121,6 +136,8 @@ export const applyRspackOptionsDefaults = (
 
 	applyNodeDefaults(options.node, { targetProperties });
 
+	applyPerformanceDefaults(options.performance);
+
 	applyOptimizationDefaults(options.optimization, { production, development });
 
 	options.resolve = cleverMerge(
@@ -158,13 +175,20 @@ const applyInfrastructureLoggingDefaults = (
 
 const applyExperimentsDefaults = (
 	experiments: ExperimentsNormalized,
-	{ cache }: { cache: boolean }
+	{ cache, production }: { cache: boolean; production: boolean }
 ) => {
 	D(experiments, "lazyCompilation", false);
 	D(experiments, "asyncWebAssembly", false);
+	D(experiments, "syncWebAssembly", false);
 	D(experiments, "newSplitChunks", true);
 	D(experiments, "css", true); // we not align with webpack about the default value for better DX
 	D(experiments, "topLevelAwait", true);
+	D(experiments, "layers", false);
+
+	if (typeof experiments.buildHttp === "object") {
+		D(experiments.buildHttp, "frozen", production);
+		D(experiments.buildHttp, "upgrade", false);
+	}
 
 	D(experiments, "rspackFuture", {});
 	if (typeof experiments.rspackFuture === "object") {
@@ -202,14 +226,17 @@ const applyJavascriptParserOptionsDefaults = (
 	parserOptions: JavascriptParserOptions
 ) => {
 	D(parserOptions, "dynamicImportMode", "lazy");
+	D(parserOptions, "worker", ["..."]);
 };
 
 const applyModuleDefaults = (
 	module: ModuleOptions,
 	{
+		syncWebAssembly,
 		asyncWebAssembly,
 		css
 	}: {
+		syncWebAssembly: boolean;
 		asyncWebAssembly: boolean;
 		css: boolean;
 	}
@@ -307,6 +334,28 @@ const applyModuleDefaults = (
 				mimetype: "application/wasm",
 				...wasm
 			});
+		} else if (syncWebAssembly) {
+			const wasm = {
+				type: "webassembly/sync",
+				rules: [
+					{
+						descriptionData: {
+							type: "module"
+						},
+						resolve: {
+							fullySpecified: true
+						}
+					}
+				]
+			};
+			rules.push({
+				test: /\.wasm$/i,
+				...wasm
+			});
+			rules.push({
+				mimetype: "application/wasm",
+				...wasm
+			});
 		}
 
 		if (css) {
@@ -705,6 +754,15 @@ const applyNodeDefaults = (
 	});
 };
 
+const applyPerformanceDefaults = (performance: Performance) => {
+	if (performance === false) return;
+	F(performance, "assetFilter", () => (file: string) => !/\.map$/.test(file));
+	// unlike webpack, hints are opt-in
+	D(performance, "hints", false);
+	D(performance, "maxAssetSize", 250000);
+	D(performance, "maxEntrypointSize", 250000);
+};
+
 const applyOptimizationDefaults = (
 	optimization: Optimization,
 	{ production, development }: { production: boolean; development: boolean }
@@ -746,11 +804,11 @@ const applyOptimizationDefaults = (
 		// );
 		D(splitChunks, "hidePathInfo", production);
 		D(splitChunks, "chunks", "async");
-		// D(splitChunks, "usedExports", optimization.usedExports === true);
+		D(splitChunks, "usedExports", optimization.usedExports === true);
 		D(splitChunks, "minChunks", 1);
 		F(splitChunks, "minSize", () => (production ? 20000 : 10000));
-		// F(splitChunks, "minRemainingSize", () => (development ? 0 : undefined));
-		// F(splitChunks, "enforceSizeThreshold", () => (production ? 50000 : 30000));
+		F(splitChunks, "minRemainingSize", () => (development ? 0 : undefined));
+		F(splitChunks, "enforceSizeThreshold", () => (production ? 50000 : 30000));
 		F(splitChunks, "maxAsyncRequests", () => (production ? 30 : Infinity));
 		F(splitChunks, "maxInitialRequests", () => (production ? 30 : Infinity));
 		D(splitChunks, "automaticNameDelimiter", "-");
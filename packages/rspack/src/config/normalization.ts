@@ -14,6 +14,7 @@ import type {
 	Context,
 	Dependencies,
 	Node,
+	Performance,
 	DevTool,
 	EntryStatic,
 	Externals,
@@ -37,11 +38,13 @@ import type {
 	DevServer,
 	Profile,
 	Bail,
+	RecordsPath,
 	Builtins,
 	EntryRuntime,
 	ChunkLoading,
 	PublicPath,
 	EntryFilename,
+	Layer,
 	Path,
 	Clean,
 	Filename,
@@ -74,6 +77,8 @@ import type {
 	ParserOptionsByModuleType,
 	GeneratorOptionsByModuleType,
 	RspackFutureOptions,
+	LazyCompilationOptions,
+	HttpUriOptions,
 	HotUpdateGlobal,
 	ScriptType,
 	DevtoolNamespace,
@@ -235,6 +240,13 @@ export const getNormalizedRspackOptions = (
 					...node
 				}
 		),
+		performance: nestedConfig(
+			config.performance,
+			performance =>
+				performance && {
+					...performance
+				}
+		),
 		snapshot: nestedConfig(config.snapshot, snapshot => ({
 			resolve: optionalNestedConfig(snapshot.resolve, resolve => ({
 				timestamp: resolve.timestamp,
@@ -284,13 +296,28 @@ export const getNormalizedRspackOptions = (
 		}),
 		plugins: nestedArray(config.plugins, p => [...p]),
 		experiments: nestedConfig(config.experiments, experiments => ({
-			...experiments
+			...experiments,
+			lazyCompilation: optionalNestedConfig(
+				experiments.lazyCompilation,
+				options => (options === true ? {} : options)
+			),
+			buildHttp: optionalNestedConfig(experiments.buildHttp, options =>
+				Array.isArray(options) ? { allowedUris: options } : options
+			)
 		})),
 		watch: config.watch,
 		watchOptions: cloneObject(config.watchOptions),
 		devServer: config.devServer,
 		profile: config.profile,
 		bail: config.bail,
+		recordsInputPath:
+			config.recordsInputPath !== undefined
+				? config.recordsInputPath
+				: config.recordsPath,
+		recordsOutputPath:
+			config.recordsOutputPath !== undefined
+				? config.recordsOutputPath
+				: config.recordsPath,
 		builtins: nestedConfig(config.builtins, builtins => ({
 			...builtins
 		}))
@@ -327,12 +354,16 @@ const getNormalizedEntryStatic = (entry: EntryStatic) => {
 			result[key] = {
 				import: Array.isArray(value.import) ? value.import : [value.import],
 				runtime: value.runtime,
+				dependOn:
+					value.dependOn &&
+					(Array.isArray(value.dependOn) ? value.dependOn : [value.dependOn]),
 				publicPath: value.publicPath,
 				baseUri: value.baseUri,
 				chunkLoading: value.chunkLoading,
 				asyncChunks: value.asyncChunks,
 				filename: value.filename,
-				library: value.library
+				library: value.library,
+				layer: value.layer
 			};
 		}
 	}
@@ -413,12 +444,14 @@ export interface EntryStaticNormalized {
 export interface EntryDescriptionNormalized {
 	import?: string[];
 	runtime?: EntryRuntime;
+	dependOn?: string[];
 	chunkLoading?: ChunkLoading;
 	asyncChunks?: boolean;
 	publicPath?: PublicPath;
 	baseUri?: string;
 	filename?: EntryFilename;
 	library?: LibraryOptions;
+	layer?: Layer;
 }
 
 export interface OutputNormalized {
@@ -473,11 +506,14 @@ export interface ModuleOptionsNormalized {
 }
 
 export interface ExperimentsNormalized {
-	lazyCompilation?: boolean;
+	lazyCompilation?: false | LazyCompilationOptions;
+	buildHttp?: HttpUriOptions;
 	asyncWebAssembly?: boolean;
+	syncWebAssembly?: boolean;
 	outputModule?: boolean;
 	newSplitChunks?: boolean;
 	topLevelAwait?: boolean;
+	layers?: boolean;
 	css?: boolean;
 	futureDefaults?: boolean;
 	rspackFuture?: RspackFutureOptions;
@@ -511,6 +547,7 @@ export interface RspackOptionsNormalized {
 	infrastructureLogging: InfrastructureLogging;
 	devtool?: DevTool;
 	node: Node;
+	performance: Performance;
 	snapshot: SnapshotOptions;
 	cache?: CacheOptions;
 	stats: StatsValue;
@@ -523,5 +560,7 @@ export interface RspackOptionsNormalized {
 	ignoreWarnings?: IgnoreWarningsNormalized;
 	profile?: Profile;
 	bail?: Bail;
+	recordsInputPath?: RecordsPath;
+	recordsOutputPath?: RecordsPath;
 	builtins: Builtins;
 }
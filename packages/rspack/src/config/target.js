@@ -355,6 +355,29 @@ const getTargetsProperties = (targets, context) => {
 	);
 };
 
+/**
+ * @param {string[]} targets the targets
+ * @param {string} context the context directory
+ * @returns {string[]} browsers selected by the browserslist targets
+ */
+const getTargetsBrowsers = (targets, context) => {
+	const browsers = new Set();
+	for (const target of targets) {
+		const match = /^browserslist(?::(.+))?$/.exec(target);
+		if (!match) continue;
+		const selected = getBrowserslistTargetHandler().load(
+			// @ts-expect-error
+			match[1] ? match[1].trim() : null,
+			context
+		);
+		for (const browser of selected || []) {
+			browsers.add(browser);
+		}
+	}
+	return Array.from(browsers);
+};
+
 exports.getDefaultTarget = getDefaultTarget;
+exports.getTargetsBrowsers = getTargetsBrowsers;
 exports.getTargetsProperties = getTargetsProperties;
 exports.getTargetProperties = getTargetProperties;
@@ -1,4 +1,4 @@
-import { RawFuncUseCtx } from "@rspack/binding";
+import { RawFuncUseCtx, RawHttpClientResponse } from "@rspack/binding";
 import { z } from "zod";
 import { Compilation, Compiler } from "..";
 import type * as oldBuiltins from "../builtin-plugin";
@@ -158,16 +158,24 @@ export type EntryRuntime = z.infer<typeof entryRuntime>;
 const entryItem = z.string().or(z.array(z.string()));
 export type EntryItem = z.infer<typeof entryItem>;
 
+const entryDependOn = z.string().or(z.array(z.string()));
+export type EntryDependOn = z.infer<typeof entryDependOn>;
+
+const layer = z.string().or(z.null());
+export type Layer = z.infer<typeof layer>;
+
 const entryDescription = z.strictObject({
 	import: entryItem,
 	runtime: entryRuntime.optional(),
+	dependOn: entryDependOn.optional(),
 	publicPath: publicPath.optional(),
 	baseUri: baseUri.optional(),
 	chunkLoading: chunkLoading.optional(),
 	asyncChunks: asyncChunks.optional(),
 	wasmLoading: wasmLoading.optional(),
 	filename: entryFilename.optional(),
-	library: libraryOptions.optional()
+	library: libraryOptions.optional(),
+	layer: layer.optional()
 });
 export type EntryDescription = z.infer<typeof entryDescription>;
 
@@ -270,13 +278,19 @@ const trustedTypes = z.strictObject({
 });
 export type TrustedTypes = z.infer<typeof trustedTypes>;
 
-const hashDigest = z.string();
+const hashDigest = z.enum(["hex", "base64", "base64url", "base58"]);
 export type HashDigest = z.infer<typeof hashDigest>;
 
 const hashDigestLength = z.number();
 export type HashDigestLength = z.infer<typeof hashDigestLength>;
 
-const hashFunction = z.enum(["md4", "xxhash64"]);
+const hashFunction = z.enum([
+	"md4",
+	"xxhash64",
+	"xxhash128",
+	"sha256",
+	"sha512"
+]);
 export type HashFunction = z.infer<typeof hashFunction>;
 
 const hashSalt = z.string();
@@ -479,6 +493,7 @@ const baseRuleSetRule = z.strictObject({
 	exclude: ruleSetCondition.optional(),
 	include: ruleSetCondition.optional(),
 	issuer: ruleSetCondition.optional(),
+	issuerLayer: ruleSetCondition.optional(),
 	dependency: ruleSetCondition.optional(),
 	resource: ruleSetCondition.optional(),
 	resourceFragment: ruleSetCondition.optional(),
@@ -494,6 +509,7 @@ const baseRuleSetRule = z.strictObject({
 	generator: z.record(z.any()).optional(),
 	resolve: resolveOptions.optional(),
 	sideEffects: z.boolean().optional(),
+	layer: z.string().optional(),
 	enforce: z.literal("pre").or(z.literal("post")).optional()
 });
 
@@ -530,12 +546,14 @@ const dynamicImportMode = z.enum(["eager", "lazy"]);
 const dynamicImportPreload = z.union([z.boolean(), z.number()]);
 const dynamicImportPrefetch = z.union([z.boolean(), z.number()]);
 const javascriptParserUrl = z.union([z.literal("relative"), z.boolean()]);
+const javascriptParserWorker = z.union([z.array(z.string()), z.boolean()]);
 
 const javascriptParserOptions = z.strictObject({
 	dynamicImportMode: dynamicImportMode.optional(),
 	dynamicImportPreload: dynamicImportPreload.optional(),
 	dynamicImportPrefetch: dynamicImportPrefetch.optional(),
-	url: javascriptParserUrl.optional()
+	url: javascriptParserUrl.optional(),
+	worker: javascriptParserWorker.optional()
 });
 export type JavascriptParserOptions = z.infer<typeof javascriptParserOptions>;
 
@@ -817,6 +835,7 @@ const devTool = z
 	.literal(false)
 	.or(
 		z.enum([
+			"eval",
 			"cheap-source-map",
 			"cheap-module-source-map",
 			"source-map",
@@ -863,6 +882,27 @@ const node = z.literal(false).or(nodeOptions);
 export type Node = z.infer<typeof node>;
 //#endregion
 
+//#region Performance
+const performance = z
+	.literal(false)
+	.or(
+		z.strictObject({
+			assetFilter: z
+				.function()
+				.args(z.string())
+				.returns(z.boolean())
+				.optional(),
+			hints: z
+				.literal(false)
+				.or(z.enum(["warning", "error"]))
+				.optional(),
+			maxAssetSize: z.number().optional(),
+			maxEntrypointSize: z.number().optional()
+		})
+	);
+export type Performance = z.infer<typeof performance>;
+//#endregion
+
 //#region Snapshot
 const snapshotOptions = z.strictObject({
 	module: z
@@ -916,6 +956,12 @@ const statsOptions = z.strictObject({
 	modulesSpace: z.number().optional(),
 	nestedModules: z.boolean().optional(),
 	source: z.boolean().optional(),
+	providedExports: z.boolean().optional(),
+	usedExports: z.boolean().optional(),
+	optimizationBailout: z.boolean().optional(),
+	depth: z.boolean().optional(),
+	cacheStatus: z.boolean().optional(),
+	chunkOrigins: z.boolean().optional(),
 	logging: z
 		.enum(["none", "error", "warn", "info", "log", "verbose"])
 		.or(z.boolean())
@@ -988,10 +1034,14 @@ const sharedOptimizationSplitChunksCacheGroup = {
 	minChunks: z.number().optional(),
 	name: optimizationSplitChunksName.optional(),
 	minSize: optimizationSplitChunksSizes.optional(),
+	minSizeReduction: optimizationSplitChunksSizes.optional(),
+	minRemainingSize: optimizationSplitChunksSizes.optional(),
+	enforceSizeThreshold: optimizationSplitChunksSizes.optional(),
 	maxSize: optimizationSplitChunksSizes.optional(),
 	maxAsyncSize: optimizationSplitChunksSizes.optional(),
 	maxInitialSize: optimizationSplitChunksSizes.optional(),
-	automaticNameDelimiter: z.string().optional()
+	automaticNameDelimiter: z.string().optional(),
+	usedExports: z.boolean().optional()
 };
 const optimizationSplitChunksCacheGroup = z.strictObject({
 	test: z
@@ -1008,6 +1058,7 @@ const optimizationSplitChunksCacheGroup = z.strictObject({
 	filename: z.string().optional(),
 	reuseExistingChunk: z.boolean().optional(),
 	type: z.string().or(z.instanceof(RegExp)).optional(),
+	layer: z.string().or(z.instanceof(RegExp)).optional(),
 	idHint: z.string().optional(),
 	...sharedOptimizationSplitChunksCacheGroup
 });
@@ -1076,11 +1127,38 @@ const rspackFutureOptions = z.strictObject({
 });
 export type RspackFutureOptions = z.infer<typeof rspackFutureOptions>;
 
+const lazyCompilationOptions = z.strictObject({
+	entries: z.boolean().optional(),
+	imports: z.boolean().optional(),
+	test: z.instanceof(RegExp).optional()
+});
+export type LazyCompilationOptions = z.infer<typeof lazyCompilationOptions>;
+
+const httpUriAllowedUris = z.array(z.string().or(z.instanceof(RegExp)));
+export type HttpUriAllowedUris = z.infer<typeof httpUriAllowedUris>;
+
+const httpUriOptions = z.strictObject({
+	allowedUris: httpUriAllowedUris,
+	lockfileLocation: z.string().optional(),
+	cacheLocation: z.string().or(z.literal(false)).optional(),
+	frozen: z.boolean().optional(),
+	upgrade: z.boolean().optional(),
+	httpClient: z
+		.function()
+		.args(z.string())
+		.returns(z.promise(z.custom<RawHttpClientResponse>()))
+		.optional()
+});
+export type HttpUriOptions = z.infer<typeof httpUriOptions>;
+
 const experiments = z.strictObject({
-	lazyCompilation: z.boolean().optional(),
+	lazyCompilation: z.boolean().or(lazyCompilationOptions).optional(),
+	buildHttp: httpUriAllowedUris.or(httpUriOptions).optional(),
 	asyncWebAssembly: z.boolean().optional(),
+	syncWebAssembly: z.boolean().optional(),
 	outputModule: z.boolean().optional(),
 	topLevelAwait: z.boolean().optional(),
+	layers: z.boolean().optional(),
 	newSplitChunks: z
 		.boolean()
 		.optional()
@@ -1154,6 +1232,11 @@ const bail = z.boolean();
 export type Bail = z.infer<typeof bail>;
 //#endregion
 
+//#region Records
+const recordsPath = z.literal(false).or(z.string());
+export type RecordsPath = z.infer<typeof recordsPath>;
+//#endregion
+
 //#region Builtins (deprecated)
 const builtins = z.custom<oldBuiltins.Builtins>();
 export type Builtins = z.infer<typeof builtins>;
@@ -1175,6 +1258,7 @@ export const rspackOptions = z.strictObject({
 	context: context.optional(),
 	devtool: devTool.optional(),
 	node: node.optional(),
+	performance: performance.optional(),
 	ignoreWarnings: ignoreWarnings.optional(),
 	watchOptions: watchOptions.optional(),
 	watch: watch.optional(),
@@ -1188,7 +1272,10 @@ export const rspackOptions = z.strictObject({
 	builtins: builtins.optional(),
 	module: moduleOptions.optional(),
 	profile: profile.optional(),
-	bail: bail.optional()
+	bail: bail.optional(),
+	recordsPath: recordsPath.optional(),
+	recordsInputPath: recordsPath.optional(),
+	recordsOutputPath: recordsPath.optional()
 });
 export type RspackOptions = z.infer<typeof rspackOptions>;
 export type Configuration = RspackOptions;
@@ -34,6 +34,12 @@ export type { MultiStats } from "./MultiStats";
 
 export type { ChunkGroup } from "./ChunkGroup";
 
+export type {
+	ModuleGraph,
+	ModuleGraphConnection,
+	ExportInfo
+} from "./ModuleGraph";
+
 export type { NormalModuleFactory } from "./NormalModuleFactory";
 
 export { NormalModule } from "./NormalModule";
@@ -94,6 +100,13 @@ export { ExternalsPlugin } from "./builtin-plugin";
 
 export { HotModuleReplacementPlugin } from "./builtin-plugin";
 
+export { IgnorePlugin } from "./builtin-plugin";
+export type { IgnorePluginOptions } from "./builtin-plugin";
+
+export { NormalModuleReplacementPlugin } from "./builtin-plugin";
+
+export { ContextReplacementPlugin } from "./builtin-plugin";
+
 export { LoaderOptionsPlugin } from "./lib/LoaderOptionsPlugin";
 
 export { LoaderTargetPlugin } from "./lib/LoaderTargetPlugin";
@@ -195,3 +208,5 @@ export type { CopyRspackPluginOptions } from "./builtin-plugin";
 
 export { SourceMapDevToolPlugin } from "./builtin-plugin";
 export type { SourceMapDevToolPluginOptions } from "./builtin-plugin";
+
+export { EvalSourceMapDevToolPlugin } from "./builtin-plugin";
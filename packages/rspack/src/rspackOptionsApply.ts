@@ -14,6 +14,7 @@ import {
 	RspackPluginFunction
 } from ".";
 import fs from "graceful-fs";
+import path from "path";
 
 import { DefaultStatsFactoryPlugin } from "./stats/DefaultStatsFactoryPlugin";
 import { DefaultStatsPrinterPlugin } from "./stats/DefaultStatsPrinterPlugin";
@@ -46,20 +47,25 @@ import {
 	WarnCaseSensitiveModulesPlugin,
 	DataUriPlugin,
 	FileUriPlugin,
+	HttpUriPlugin,
 	JavascriptModulesPlugin,
 	JsonModulesPlugin,
 	AsyncWebAssemblyModulesPlugin,
+	WebAssemblyModulesPlugin,
 	RuntimePlugin,
 	InferAsyncModulesPlugin,
 	WorkerPlugin,
 	EvalSourceMapDevToolPlugin,
+	EvalDevToolModulePlugin,
 	SourceMapDevToolPlugin,
 	AssetModulesPlugin,
 	MangleExportsPlugin,
 	FlagDependencyExportsPlugin,
 	FlagDependencyUsagePlugin,
 	SideEffectsFlagPlugin,
-	BundlerInfoPlugin
+	BundlerInfoPlugin,
+	SizeLimitsPlugin,
+	LazyCompilationPlugin
 } from "./builtin-plugin";
 import { deprecatedWarn, termlink } from "./util";
 
@@ -222,6 +228,11 @@ export class RspackOptionsApply {
 					noSources: noSources,
 					namespace: options.output.devtoolNamespace
 				}).apply(compiler);
+			} else if (options.devtool.includes("eval")) {
+				new EvalDevToolModulePlugin({
+					moduleFilenameTemplate: options.output.devtoolModuleFilenameTemplate,
+					namespace: options.output.devtoolNamespace
+				}).apply(compiler);
 			}
 		}
 
@@ -231,6 +242,9 @@ export class RspackOptionsApply {
 		if (options.experiments.asyncWebAssembly) {
 			new AsyncWebAssemblyModulesPlugin().apply(compiler);
 		}
+		if (options.experiments.syncWebAssembly) {
+			new WebAssemblyModulesPlugin().apply(compiler);
+		}
 
 		if (options.experiments.rspackFuture!.disableApplyEntryLazily) {
 			applyEntryOptions(compiler, options);
@@ -253,6 +267,23 @@ export class RspackOptionsApply {
 
 		new DataUriPlugin().apply(compiler);
 		new FileUriPlugin().apply(compiler);
+		if (options.experiments.buildHttp) {
+			const buildHttp = options.experiments.buildHttp;
+			const lockfileLocation =
+				buildHttp.lockfileLocation ??
+				path.resolve(options.context!, "rspack.lock");
+			new HttpUriPlugin({
+				allowedUris: buildHttp.allowedUris,
+				lockfileLocation,
+				cacheLocation:
+					buildHttp.cacheLocation === false
+						? false
+						: buildHttp.cacheLocation ?? `${lockfileLocation}.data`,
+				frozen: buildHttp.frozen!,
+				upgrade: buildHttp.upgrade!,
+				httpClient: buildHttp.httpClient
+			}).apply(compiler);
+		}
 
 		new EnsureChunkConditionsPlugin().apply(compiler);
 		if (options.optimization.mergeDuplicateChunks) {
@@ -344,6 +375,26 @@ export class RspackOptionsApply {
 			options.output.workerPublicPath!
 		).apply(compiler);
 
+		if (options.performance && options.performance.hints) {
+			new SizeLimitsPlugin({
+				hints: options.performance.hints,
+				maxAssetSize: options.performance.maxAssetSize!,
+				maxEntrypointSize: options.performance.maxEntrypointSize!,
+				assetFilter: options.performance.assetFilter
+			}).apply(compiler);
+		}
+
+		if (options.experiments.lazyCompilation) {
+			const lazyOptions = options.experiments.lazyCompilation;
+			new LazyCompilationPlugin({
+				entries: lazyOptions.entries ?? true,
+				imports: lazyOptions.imports ?? true,
+				test: lazyOptions.test,
+				// served by the lazy compilation middleware of @rspack/dev-server
+				clientUrl: "/lazy-compilation-web/"
+			}).apply(compiler);
+		}
+
 		new DefaultStatsFactoryPlugin().apply(compiler);
 		new DefaultStatsPrinterPlugin().apply(compiler);
 
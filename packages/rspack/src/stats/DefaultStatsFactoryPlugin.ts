@@ -748,6 +748,7 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			const { type } = context;
 			object.type = module.type;
 			object.moduleType = module.moduleType;
+			if (module.layer) object.layer = module.layer;
 			object.size = module.size;
 			Object.assign(object, factory.create(`${type}$visible`, module, context));
 		}
@@ -789,6 +790,23 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 		},
 		source: (object, module) => {
 			object.source = module.source;
+		},
+		providedExports: (object, module) => {
+			object.providedExports = module.providedExports ?? null;
+		},
+		usedExports: (object, module) => {
+			object.usedExports = module.usedExports ?? null;
+		},
+		optimizationBailout: (object, module) => {
+			object.optimizationBailout = module.optimizationBailout;
+		},
+		depth: (object, module) => {
+			object.depth = module.depth ?? null;
+		},
+		cacheStatus: (object, module) => {
+			object.cacheable = module.cacheable;
+			object.built = module.built;
+			object.cached = module.cached;
 		}
 	},
 	profile: {
@@ -850,6 +868,26 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 				chunk.modules,
 				context
 			);
+		},
+		chunkOrigins: (object, chunk, context, options, factory) => {
+			const { type } = context;
+			object.origins = factory.create(
+				`${type}.origins`,
+				chunk.origins,
+				context
+			);
+		}
+	},
+	chunkOrigin: {
+		_: (object, origin) => {
+			object.module = origin.module;
+			object.moduleIdentifier = origin.moduleIdentifier;
+			object.moduleName = origin.moduleName;
+			object.loc = origin.loc;
+			object.request = origin.request;
+		},
+		ids: (object, origin) => {
+			object.moduleId = origin.moduleId;
 		}
 	}
 };
@@ -101,6 +101,8 @@ export type KnownStatsLoggingEntry = {
 	time?: number | undefined;
 };
 
+export type StatsChunkOrigin = KnownStatsChunkOrigin & Record<string, any>;
+
 export type KnownStatsChunkOrigin = {
 	module?: string | undefined;
 	moduleIdentifier?: string | undefined;
@@ -148,7 +150,7 @@ export type SimpleExtractors = {
 		StatsModuleReason
 	>;
 	chunk: ExtractorsByOption<StatsChunk, KnownStatsChunk>;
-	// chunkOrigin: ExtractorsByOption<OriginRecord, StatsChunkOrigin>;
+	chunkOrigin: ExtractorsByOption<binding.JsOriginRecord, StatsChunkOrigin>;
 	// error: ExtractorsByOption<binding.JsStatsError, StatsError>;
 	// warning: ExtractorsByOption<binding.JsStatsWarning, StatsError>;
 };
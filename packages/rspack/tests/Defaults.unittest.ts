@@ -132,15 +132,19 @@ describe("snapshots", () => {
 		-     "sideEffects": "flag",
 		+     "sideEffects": true,
 		@@ ... @@
+		-       "enforceSizeThreshold": 30000,
 		-       "hidePathInfo": false,
 		-       "maxAsyncRequests": Infinity,
 		-       "maxInitialRequests": Infinity,
+		+       "enforceSizeThreshold": 50000,
 		+       "hidePathInfo": true,
 		+       "maxAsyncRequests": 30,
 		+       "maxInitialRequests": 30,
 		@@ ... @@
 		-       "minSize": 10000,
+		-       "usedExports": false,
 		+       "minSize": 20000,
+		+       "usedExports": true,
 		@@ ... @@
 		-     "usedExports": false,
 		+     "usedExports": true,
@@ -183,15 +187,19 @@ describe("snapshots", () => {
 		-     "sideEffects": "flag",
 		+     "sideEffects": true,
 		@@ ... @@
+		-       "enforceSizeThreshold": 30000,
 		-       "hidePathInfo": false,
 		-       "maxAsyncRequests": Infinity,
 		-       "maxInitialRequests": Infinity,
+		+       "enforceSizeThreshold": 50000,
 		+       "hidePathInfo": true,
 		+       "maxAsyncRequests": 30,
 		+       "maxInitialRequests": 30,
 		@@ ... @@
 		-       "minSize": 10000,
+		-       "usedExports": false,
 		+       "minSize": 20000,
+		+       "usedExports": true,
 		@@ ... @@
 		-     "usedExports": false,
 		+     "usedExports": true,
@@ -218,6 +226,9 @@ describe("snapshots", () => {
 		-     "nodeEnv": false,
 		+     "nodeEnv": "development",
 		@@ ... @@
+		-       "minRemainingSize": undefined,
+		+       "minRemainingSize": 0,
+		@@ ... @@
 		-       "production",
 		+       "development",
 	`)
@@ -320,6 +331,7 @@ describe("snapshots", () => {
 			-     "asyncWebAssembly": false,
 			+     "asyncWebAssembly": true,
 			@@ ... @@
+			-     "syncWebAssembly": false,
 			+     "syncWebAssembly": true,
 			@@ ... @@
 			+       },
@@ -1011,6 +1023,9 @@ describe("snapshots", () => {
 			-     "nodeEnv": false,
 			+     "nodeEnv": "development",
 			@@ ... @@
+			-       "minRemainingSize": undefined,
+			+       "minRemainingSize": 0,
+			@@ ... @@
 			-       "production",
 			+       "development",
 		`)
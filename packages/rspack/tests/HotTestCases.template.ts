@@ -1,6 +1,7 @@
 import path from "path";
 import fs from "graceful-fs";
 import vm from "vm";
+import { pathToFileURL } from "url";
 import rimraf from "rimraf";
 import checkArrayExpectation from "./checkArrayExpectation";
 import createLazyTestEnv from "./helpers/createLazyTestEnv";
@@ -12,6 +13,8 @@ import {
 } from "@rspack/core";
 import { isValidTestCaseDir } from "./utils";
 
+const asModule = require("./helpers/asModule");
+
 export function describeCases(config: {
 	name: string;
 	target: string;
@@ -129,6 +132,11 @@ export function describeCases(config: {
 									};
 									const window = {
 										fetch: async (url: string) => {
+											// activation requests of lazy compilation, the module is
+											// activated by the rebuild of the next update
+											if (url.startsWith("/lazy-compilation-web/")) {
+												return { status: 200, ok: true };
+											}
 											try {
 												const buffer: any = await new Promise(
 													(resolve, reject) => {
@@ -288,11 +296,99 @@ export function describeCases(config: {
 											callback(null, jsonStats);
 										});
 									}
+									const esmModules = new Map<string, any>();
+									const esmEvaluations = new Map<string, Promise<any>>();
+									const isModuleOutput = !!(options as any).experiments
+										?.outputModule;
+									const esmContext = vm.createContext(
+										{
+											console,
+											setTimeout,
+											clearTimeout,
+											it: _it,
+											beforeEach: _beforeEach,
+											afterEach: _afterEach,
+											expect,
+											jest,
+											self: window,
+											window,
+											fetch: window.fetch,
+											document: window.document,
+											location: window.location,
+											NEXT: _next,
+											STATS: jsonStats
+										},
+										{ name: "context for esm" }
+									);
+									const resolveImport = (
+										parent: string,
+										specifier: string
+									) => {
+										if (specifier.startsWith("https://test.cases/path/")) {
+											return urlToPath(specifier);
+										}
+										if (/^\.\.?\//.test(specifier)) {
+											return path.resolve(path.dirname(parent), specifier);
+										}
+									};
+									// bundles of `output.module` are evaluated as ES modules, their chunks and hot
+									// update chunks are loaded by `import()`
+									function _loadModule(p: string) {
+										let esm = esmModules.get(p);
+										if (!esm) {
+											const content = fs.readFileSync(p, "utf-8");
+											esm = new vm.SourceTextModule(content, {
+												identifier: p,
+												context: esmContext,
+												initializeImportMeta: (meta: any) => {
+													meta.url = pathToFileURL(p).href;
+												},
+												// @ts-expect-error
+												importModuleDynamically: (specifier: string) => {
+													const resolved = resolveImport(p, specifier);
+													return resolved
+														? _import(resolved)
+														: asModule(require(specifier), esmContext);
+												}
+											});
+											esmModules.set(p, esm);
+										}
+										return esm;
+									}
+									function _import(p: string) {
+										let evaluation = esmEvaluations.get(p);
+										if (!evaluation) {
+											const esm = _loadModule(p);
+											evaluation = (async () => {
+												if (esm.status === "unlinked") {
+													await esm.link(
+														(specifier: string, referencing: any) => {
+															const resolved = resolveImport(
+																referencing.identifier,
+																specifier
+															);
+															return resolved
+																? _loadModule(resolved)
+																: asModule(require(specifier), esmContext, true);
+														}
+													);
+												}
+												if (esm.status === "linked") {
+													await esm.evaluate();
+												}
+												return esm;
+											})();
+											esmEvaluations.set(p, evaluation);
+										}
+										return evaluation;
+									}
 									function _require(module: string) {
 										if (module.startsWith("./")) {
 											const p = path.join(outputDirectory, module);
 											if (module.endsWith(".json")) {
 												return JSON.parse(fs.readFileSync(p, "utf8"));
+											} else if (isModuleOutput) {
+												return _import(p);
 											} else {
 												const code =
 													"(function(require, module, exports, __dirname, __filename, it, beforeEach, afterEach, expect, jest, self, window, fetch, document, importScripts, Worker, EventSource, NEXT, STATS) {" +
@@ -340,8 +436,10 @@ export function describeCases(config: {
 										config.target === "webworker"
 									) {
 										for (const file of info.entrypoints!.main.assets) {
-											if (file.name.endsWith(".js")) {
-												_require(`./${file.name}`);
+											if (/\.m?js$/.test(file.name)) {
+												const result = _require(`./${file.name}`);
+												if (typeof result === "object" && "then" in result)
+													promise = promise.then(() => result);
 											} else {
 												const cssElement =
 													window.document.createElement("link");
@@ -354,7 +452,7 @@ export function describeCases(config: {
 										}
 									} else {
 										const assets = info.entrypoints!.main.assets.filter(s =>
-											s.name.endsWith(".js")
+											/\.m?js$/.test(s.name)
 										);
 										const result = _require(
 											`./${assets[assets.length - 1].name}`
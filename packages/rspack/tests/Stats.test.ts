@@ -125,6 +125,78 @@ describe("Stats", () => {
 		).toBe(2);
 	});
 
+	it("should output module details and chunk origins", async () => {
+		const stats = await compile({
+			context: __dirname,
+			entry: "./fixtures/stats-details/index",
+			experiments: {
+				rspackFuture: {
+					newTreeshaking: true
+				}
+			},
+			optimization: {
+				providedExports: true,
+				usedExports: true
+			}
+		});
+
+		const statsJson = stats?.toJson({
+			all: false,
+			modules: true,
+			chunks: true,
+			ids: true,
+			providedExports: true,
+			usedExports: true,
+			optimizationBailout: true,
+			depth: true,
+			cacheStatus: true,
+			chunkOrigins: true
+		});
+		const findModule = (name: string) =>
+			statsJson?.modules?.find(m => m.name === name);
+
+		const entry = findModule("./fixtures/stats-details/index.js");
+		expect(entry).toMatchObject({
+			depth: 0,
+			cacheable: true,
+			built: true,
+			cached: false,
+			optimizationBailout: []
+		});
+		expect([...(entry?.providedExports ?? [])].sort()).toEqual([
+			"default",
+			"load"
+		]);
+
+		const lib = findModule("./fixtures/stats-details/lib.js");
+		expect(lib?.depth).toBe(1);
+		expect([...(lib?.providedExports ?? [])].sort()).toEqual([
+			"unused",
+			"used"
+		]);
+		expect(lib?.usedExports).toEqual(["used"]);
+
+		const lazy = findModule("./fixtures/stats-details/lazy.js");
+		expect(lazy?.depth).toBe(1);
+		expect(lazy?.providedExports).toEqual(["default"]);
+
+		const origins = statsJson?.chunks?.flatMap(c => c.origins);
+		expect(origins).toContainEqual(
+			expect.objectContaining({
+				moduleName: "",
+				loc: "",
+				request: "./fixtures/stats-details/index"
+			})
+		);
+		expect(origins).toContainEqual(
+			expect.objectContaining({
+				moduleName: "./fixtures/stats-details/index.js",
+				loc: "3:26-3:73",
+				request: "./lazy"
+			})
+		);
+	});
+
 	it("should have time log when logging verbose", async () => {
 		const stats = await compile({
 			context: __dirname,
@@ -11,7 +11,12 @@ import FakeDocument from "./helpers/FakeDocument";
 import { rspack, RspackOptions } from "../src";
 import { isValidTestCaseDir } from "./utils";
 
-function copyDiff(src: string, dest: string, initial: boolean) {
+function copyDiff(
+	src: string,
+	dest: string,
+	initial: boolean,
+	writeByRename = false
+) {
 	if (!fs.existsSync(dest)) fs.mkdirSync(dest);
 	const files = fs.readdirSync(src);
 	files.filter(isValidTestCaseDir).forEach(filename => {
@@ -19,13 +24,18 @@ function copyDiff(src: string, dest: string, initial: boolean) {
 		const destFile = path.join(dest, filename);
 		const directory = fs.statSync(srcFile).isDirectory();
 		if (directory) {
-			copyDiff(srcFile, destFile, initial);
+			copyDiff(srcFile, destFile, initial, writeByRename);
 		} else {
 			var content = fs.readFileSync(srcFile);
 			if (/^DELETE\s*$/.test(content.toString("utf-8"))) {
 				fs.unlinkSync(destFile);
 			} else if (/^DELETE_DIRECTORY\s*$/.test(content.toString("utf-8"))) {
 				rimraf.sync(destFile);
+			} else if (!initial && writeByRename) {
+				// replace the file like most editors do when saving, which changes its inode
+				const tempFile = `${destFile}.tmp`;
+				fs.writeFileSync(tempFile, content);
+				fs.renameSync(tempFile, destFile);
 			} else {
 				fs.writeFileSync(destFile, content);
 				if (initial) {
@@ -385,7 +395,8 @@ export const describeCases = (config: any) => {
 															copyDiff(
 																path.join(testDirectory, run.name),
 																tempDirectory,
-																false
+																false,
+																testConfig.writeByRename
 															);
 														}, 1500);
 													} else {
@@ -0,0 +1,19 @@
+import answer from "https://example.com/answer.js";
+
+const fs = require("fs");
+const path = require("path");
+
+it("should build modules fetched by the http client", () => {
+	expect(answer).toBe(42);
+});
+
+it("should record fetched urls in the lockfile", () => {
+	const lockfile = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "rspack.lock"), "utf-8")
+	);
+	expect(lockfile.version).toBe(1);
+	expect(lockfile["https://example.com/answer.js"]).toEqual({
+		integrity: expect.stringMatching(/^sha512-/),
+		contentType: "application/javascript"
+	});
+});
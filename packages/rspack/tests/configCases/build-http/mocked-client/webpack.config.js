@@ -0,0 +1,27 @@
+const path = require("path");
+
+const served = {
+	"https://example.com/answer.js": "export default 42;\n"
+};
+
+/** @type {(env: unknown, options: { testPath: string }) => import("@rspack/core").Configuration} */
+module.exports = (env, { testPath }) => ({
+	experiments: {
+		buildHttp: {
+			allowedUris: ["https://example.com/"],
+			lockfileLocation: path.join(testPath, "rspack.lock"),
+			cacheLocation: path.join(testPath, "rspack.lock.data"),
+			httpClient: async url => {
+				const body = served[url];
+				if (body === undefined) {
+					return { status: 404, headers: {}, body: Buffer.from("") };
+				}
+				return {
+					status: 200,
+					headers: { "content-type": "application/javascript" },
+					body: Buffer.from(body)
+				};
+			}
+		}
+	}
+});
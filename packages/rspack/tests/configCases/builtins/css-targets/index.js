@@ -0,0 +1,12 @@
+const fs = require("fs");
+const path = require("path");
+
+require("./index.css");
+
+it("should lower and prefix css for the targets", () => {
+	const css = fs.readFileSync(path.resolve(__dirname, "main.css"), "utf-8");
+	expect(css).toContain("-webkit-user-select");
+	expect(css).toMatch(/\.parent \.child\s*\{/);
+	expect(css).not.toContain("#ff000080");
+	expect(css).not.toContain("/ 50%");
+});
@@ -0,0 +1,16 @@
+const path = require("path");
+const rspack = require("@rspack/core");
+
+module.exports = {
+	// css is lowered and prefixed for the browsers of the browserslist target
+	target: "browserslist:chrome 60",
+	externals: {
+		path: "require('path')",
+		fs: "require('fs')"
+	},
+	plugins: [
+		new rspack.DefinePlugin({
+			__dirname: JSON.stringify(path.join(__dirname, "./dist"))
+		})
+	]
+};
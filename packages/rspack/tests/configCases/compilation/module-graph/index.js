@@ -0,0 +1,6 @@
+import { a } from "./a";
+
+it("should have the module graph", () => {
+	expect(a).toBe(1);
+	return import("./async");
+});
@@ -0,0 +1,60 @@
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.compilation.tap(pluginName, compilation => {
+			compilation.hooks.processAssets.tap(pluginName, () => {
+				const { moduleGraph, chunkGraph } = compilation;
+				const find = name =>
+					compilation.modules.find(m => m.identifier().endsWith(name));
+				const index = find("index.js");
+				const a = find("a.js");
+				const asyncModule = find("async.js");
+
+				const outgoing = moduleGraph.getOutgoingConnections(index);
+				expect(
+					outgoing.map(c => [c.userRequest, c.dependencyType]).sort()
+				).toEqual(
+					expect.arrayContaining([
+						["./a", "esm import"],
+						["./async", "dynamic import"]
+					])
+				);
+
+				const incoming = moduleGraph.getIncomingConnections(a);
+				expect(incoming.length).toBeGreaterThan(0);
+				expect(
+					incoming.every(
+						c => c.originModule.identifier() === index.identifier()
+					)
+				).toBe(true);
+
+				expect(moduleGraph.getProvidedExports(a).sort()).toEqual([
+					"a",
+					"unused"
+				]);
+				expect(moduleGraph.getUsedExports(a)).toEqual(["a"]);
+
+				const [mainChunk] = chunkGraph.getModuleChunks(index);
+				expect(mainChunk.name).toBe("main");
+				const asyncChunks = chunkGraph.getModuleChunks(asyncModule);
+				expect(asyncChunks.length).toBe(1);
+				expect(asyncChunks[0]).not.toEqual(mainChunk);
+			});
+		});
+	}
+}
+
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	mode: "production",
+	optimization: {
+		usedExports: true
+	},
+	experiments: {
+		rspackFuture: {
+			newTreeshaking: true
+		}
+	},
+	plugins: [new Plugin()]
+};
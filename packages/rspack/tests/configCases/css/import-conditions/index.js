@@ -0,0 +1,20 @@
+const fs = require("fs");
+const path = require("path");
+
+import("./index.css");
+
+it("should wrap imported css in the conditions of @import", async () => {
+	const css = await fs.promises.readFile(
+		path.resolve(__dirname, "index_css.css"),
+		"utf-8"
+	);
+	expect(css).toMatch(/@media print \{\s*\.print \{/);
+	expect(css).toMatch(
+		/@layer base \{\s*@supports \(display: grid\) \{\s*\.grid \{/
+	);
+	expect(css).toMatch(
+		/@layer base \{\s*@supports \(display: grid\) \{\s*@media screen and \(min-width: 100px\) \{\s*\.nested \{/
+	);
+	expect(css).toMatch(/@layer \{\s*\.reset \{/);
+	expect(css).not.toMatch(/@import/);
+});
@@ -0,0 +1 @@
+export const counter = { name: "shared-counter" };
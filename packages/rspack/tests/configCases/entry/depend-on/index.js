@@ -0,0 +1,11 @@
+import { counter } from "./counter";
+
+it("should share modules with the entry it depends on", () => {
+	expect(counter.shared).toBe(true);
+});
+
+it("should not include modules of the entry it depends on", () => {
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).not.toContain("shared-" + "counter");
+});
@@ -0,0 +1,3 @@
+import { counter } from "./counter";
+
+counter.shared = true;
@@ -0,0 +1,9 @@
+module.exports = {
+	entry: {
+		shared: "./shared.js",
+		main: {
+			import: "./index.js",
+			dependOn: "shared"
+		}
+	}
+};
@@ -0,0 +1,13 @@
+const { IgnorePlugin } = require("@rspack/core");
+
+/** @type {import("../../../../src/index").RspackOptions} */
+module.exports = {
+	entry: "./index.js",
+	plugins: [
+		new IgnorePlugin({
+			checkResource(resource, context) {
+				return resource === "./ignored-module" && context === __dirname;
+			}
+		})
+	]
+};
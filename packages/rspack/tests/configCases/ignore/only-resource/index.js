@@ -0,0 +1,9 @@
+it("should ignore ignored resources", function () {
+	expect(function () {
+		require("./ignored-module");
+	}).toThrowError();
+});
+
+it("should not ignore resources that do not match", function () {
+	expect(require("./normal-module")).toBe("normal");
+});
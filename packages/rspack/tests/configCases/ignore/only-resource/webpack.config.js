@@ -0,0 +1,11 @@
+const { IgnorePlugin } = require("@rspack/core");
+
+/** @type {import("../../../../src/index").RspackOptions} */
+module.exports = {
+	entry: "./index.js",
+	plugins: [
+		new IgnorePlugin({
+			resourceRegExp: /ignored-module/
+		})
+	]
+};
@@ -0,0 +1,7 @@
+import clientValue from "./shared";
+import serverValue from "./server";
+
+it("should build the same resource in each layer with its own loaders", () => {
+	expect(clientValue).toBe("client");
+	expect(serverValue).toBe("server");
+});
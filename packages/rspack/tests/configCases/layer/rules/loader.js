@@ -0,0 +1,5 @@
+/** @type {import("@rspack/core").LoaderDefinition} */
+module.exports = function (source) {
+	const { layer } = this.getOptions();
+	return source.replace("__LAYER__", JSON.stringify(layer));
+};
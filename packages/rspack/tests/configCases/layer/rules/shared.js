@@ -0,0 +1 @@
+export default __LAYER__;
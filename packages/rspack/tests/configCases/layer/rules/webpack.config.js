@@ -0,0 +1,36 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: {
+			import: "./index.js",
+			layer: "client"
+		}
+	},
+	experiments: {
+		layers: true
+	},
+	module: {
+		rules: [
+			{
+				test: /server\.js$/,
+				layer: "server"
+			},
+			{
+				test: /shared\.js$/,
+				issuerLayer: "client",
+				use: {
+					loader: "./loader.js",
+					options: { layer: "client" }
+				}
+			},
+			{
+				test: /shared\.js$/,
+				issuerLayer: "server",
+				use: {
+					loader: "./loader.js",
+					options: { layer: "server" }
+				}
+			}
+		]
+	}
+};
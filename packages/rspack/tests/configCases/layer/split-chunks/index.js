@@ -0,0 +1,15 @@
+import fs from "fs";
+import path from "path";
+import clientValue from "./shared";
+
+it("should split modules of the selected layer only", async () => {
+	const { default: serverValue } = await import("./server");
+	expect(clientValue).toBe("shared");
+	expect(serverValue).toBe("shared");
+	const content = fs.readFileSync(
+		path.resolve(__dirname, "server-shared.js"),
+		"utf-8"
+	);
+	expect(content).toContain("(server)/./shared.js");
+	expect(content).not.toContain("(client)/./shared.js");
+});
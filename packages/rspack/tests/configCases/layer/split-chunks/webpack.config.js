@@ -0,0 +1,36 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: {
+			import: "./index.js",
+			layer: "client"
+		}
+	},
+	output: {
+		filename: "[name].js"
+	},
+	experiments: {
+		layers: true
+	},
+	module: {
+		rules: [
+			{
+				test: /server\.js$/,
+				layer: "server"
+			}
+		]
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				server: {
+					test: /shared\.js$/,
+					layer: "server",
+					name: "server-shared",
+					chunks: "all",
+					enforce: true
+				}
+			}
+		}
+	}
+};
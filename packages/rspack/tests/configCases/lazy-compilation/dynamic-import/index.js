@@ -0,0 +1,32 @@
+import fs from "fs";
+import path from "path";
+
+it("should not build the import() target before it is activated", () => {
+	import("./lazy");
+
+	const modules = __STATS__.modules.map(m => m.identifier);
+	expect(modules.some(id => id.startsWith("lazy-compilation-proxy|"))).toBe(
+		true
+	);
+	expect(
+		modules.some(
+			id => id.endsWith("lazy.js") && !id.startsWith("lazy-compilation-proxy|")
+		)
+	).toBe(false);
+
+	for (const file of fs.readdirSync(__dirname)) {
+		if (!file.endsWith(".js")) continue;
+		expect(fs.readFileSync(path.join(__dirname, file), "utf-8")).not.toContain(
+			"LAZY_MODULE_" + "CONTENT"
+		);
+	}
+});
+
+it("should request the activation of the import() target", () => {
+	expect(__FETCHED__.length).toBe(1);
+	const [url] = __FETCHED__;
+	expect(url.startsWith("/lazy-compilation-web/")).toBe(true);
+	expect(decodeURIComponent(url.slice("/lazy-compilation-web/".length))).toBe(
+		path.resolve(__dirname, "../lazy.js")
+	);
+});
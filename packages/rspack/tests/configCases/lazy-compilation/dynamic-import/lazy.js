@@ -0,0 +1 @@
+export default "LAZY_MODULE_CONTENT";
@@ -0,0 +1,9 @@
+module.exports = {
+	moduleScope(scope) {
+		scope.__FETCHED__ = [];
+		scope.fetch = url => {
+			scope.__FETCHED__.push(url);
+			return Promise.resolve({ ok: true, status: 200 });
+		};
+	}
+};
@@ -0,0 +1,9 @@
+import fs from "fs";
+it("should name chunks with base64url sha256 content hashes", async function () {
+	await import(/* webpackChunkName: "two" */ "./two");
+	const files = fs
+		.readdirSync(__dirname)
+		.filter(file => file.startsWith("two."));
+	expect(files).toHaveLength(1);
+	expect(files[0]).toMatch(/^two\.[A-Za-z0-9_-]{20}\.js$/);
+});
@@ -0,0 +1,8 @@
+/** @type {import("../../../../src/index").RspackOptions} */
+module.exports = {
+	output: {
+		chunkFilename: "[name].[contenthash].js",
+		hashFunction: "sha256",
+		hashDigest: "base64url"
+	}
+};
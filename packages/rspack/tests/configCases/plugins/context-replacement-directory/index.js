@@ -0,0 +1,11 @@
+function load(name) {
+	return require("./pages/" + name);
+}
+
+it("should resolve the new directory against the matched directory", function () {
+	expect(load("en")).toBe("en");
+	expect(load("fr")).toBe("fr");
+	expect(function () {
+		load("default");
+	}).toThrowError();
+});
@@ -0,0 +1,7 @@
+const { ContextReplacementPlugin } = require("@rspack/core");
+
+/** @type {import("../../../../src/index").RspackOptions} */
+module.exports = {
+	entry: "./index.js",
+	plugins: [new ContextReplacementPlugin(/pages[\\/]?$/, "./locales")]
+};
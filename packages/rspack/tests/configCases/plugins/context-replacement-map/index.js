@@ -0,0 +1,10 @@
+function load(name) {
+	return require("./locales/" + name);
+}
+
+it("should use the given content of the context", function () {
+	expect(load("english")).toBe("en");
+	expect(function () {
+		load("fr");
+	}).toThrowError();
+});
@@ -0,0 +1,15 @@
+const { ContextReplacementPlugin } = require("@rspack/core");
+
+/** @type {import("../../../../src/index").RspackOptions} */
+module.exports = {
+	entry: "./index.js",
+	plugins: [
+		new ContextReplacementPlugin(
+			/context-replacement-map[\\/]locales[\\/]?$/,
+			".",
+			{
+				"./english": "./en.js"
+			}
+		)
+	]
+};
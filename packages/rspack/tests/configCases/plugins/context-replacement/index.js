@@ -0,0 +1,11 @@
+function load(name) {
+	return require("./locales/" + name);
+}
+
+it("should narrow the context with a new regexp", function () {
+	expect(load("en")).toBe("en");
+	expect(load("fr")).toBe("fr");
+	expect(function () {
+		load("de");
+	}).toThrowError();
+});
@@ -0,0 +1,12 @@
+const { ContextReplacementPlugin } = require("@rspack/core");
+
+/** @type {import("../../../../src/index").RspackOptions} */
+module.exports = {
+	entry: "./index.js",
+	plugins: [
+		new ContextReplacementPlugin(
+			/context-replacement[\\/]locales[\\/]?$/,
+			/^\.\/(en|fr)(\.js)?$/
+		)
+	]
+};
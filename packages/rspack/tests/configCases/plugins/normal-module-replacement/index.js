@@ -0,0 +1,15 @@
+it("should replace requests with a new request", function () {
+	expect(require("./a")).toBe("b");
+});
+
+it("should replace requests with a callback", function () {
+	expect(require("./replace-by-callback")).toBe("c");
+});
+
+it("should replace resolved resources with a new resource", function () {
+	expect(require("./sub/original")).toBe("replaced");
+});
+
+it("should replace resolved resources with a callback", function () {
+	expect(require("./resolved-by-callback")).toBe("c");
+});
@@ -0,0 +1,19 @@
+const path = require("path");
+const { NormalModuleReplacementPlugin } = require("@rspack/core");
+
+/** @type {import("../../../../src/index").RspackOptions} */
+module.exports = {
+	entry: "./index.js",
+	plugins: [
+		new NormalModuleReplacementPlugin(/^\.\/a$/, "./b"),
+		new NormalModuleReplacementPlugin(/replace-by-callback/, data => {
+			data.request = "./c";
+		}),
+		new NormalModuleReplacementPlugin(/original\.js$/, "./replaced.js"),
+		new NormalModuleReplacementPlugin(/resolved-by-callback\.js$/, data => {
+			if (data.createData) {
+				data.createData.resource = path.resolve(__dirname, "c.js");
+			}
+		})
+	]
+};
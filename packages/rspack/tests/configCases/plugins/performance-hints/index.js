@@ -0,0 +1,5 @@
+import content from "./large";
+
+it("should emit performance hints for oversized assets", () => {
+	expect(content.length).toBe(4096);
+});
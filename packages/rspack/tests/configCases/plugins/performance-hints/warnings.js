@@ -0,0 +1,5 @@
+module.exports = [
+	[/asset size limit: The following asset\(s\) exceed the recommended size limit/],
+	[/entrypoint size limit: The following entrypoint\(s\) combined asset size exceeds/],
+	[/You can limit the size of your bundles by using import\(\)/]
+];
@@ -0,0 +1,8 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	performance: {
+		hints: "warning",
+		maxAssetSize: 1000,
+		maxEntrypointSize: 1000
+	}
+};
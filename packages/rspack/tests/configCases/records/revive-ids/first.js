@@ -0,0 +1,10 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should record the ids of the first build", () => {
+	expect(require("./shared")).toBe("./shared.js");
+	const records = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "records.json"), "utf-8")
+	);
+	expect(records.modules.byIdentifier["./shared.js"]).toBe("./shared.js");
+});
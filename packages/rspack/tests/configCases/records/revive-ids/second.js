@@ -0,0 +1,4 @@
+it("should revive the ids recorded by the first build", () => {
+	// deterministic ids would be numeric without the records
+	expect(require("./shared")).toBe("./shared.js");
+});
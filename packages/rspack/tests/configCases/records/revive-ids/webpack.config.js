@@ -0,0 +1,27 @@
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		name: "first",
+		entry: "./first.js",
+		output: {
+			filename: "bundle0.js"
+		},
+		// relative to the context
+		recordsPath: "dist/records.json",
+		optimization: {
+			moduleIds: "named"
+		}
+	},
+	{
+		name: "second",
+		dependencies: ["first"],
+		entry: "./second.js",
+		output: {
+			filename: "bundle1.js"
+		},
+		recordsPath: "dist/records.json",
+		optimization: {
+			moduleIds: "deterministic"
+		}
+	}
+];
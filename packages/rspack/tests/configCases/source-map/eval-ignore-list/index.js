@@ -0,0 +1,21 @@
+import value from "./vendor/ignored";
+
+it("should list ignored sources in ignoreList of eval source maps", () => {
+	expect(value).toBe("ignored");
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	const maps = Array.from(
+		source.matchAll(
+			/sourceMappingURL\s*=\s*data:application\/json;charset=utf-8;base64,([A-Za-z0-9+/=]*)/g
+		),
+		match => JSON.parse(Buffer.from(match[1], "base64").toString("utf-8"))
+	);
+	const ignoredMap = maps.find(map =>
+		map.sources.some(source => source.includes("ignored.js"))
+	);
+	expect(ignoredMap.ignoreList).toEqual([0]);
+	const indexMap = maps.find(map =>
+		map.sources.some(source => source.includes("index.js"))
+	);
+	expect(indexMap.ignoreList).toBeUndefined();
+});
@@ -0,0 +1,11 @@
+const { EvalSourceMapDevToolPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	devtool: false,
+	plugins: [
+		new EvalSourceMapDevToolPlugin({
+			ignoreList: /vendor/
+		})
+	]
+};
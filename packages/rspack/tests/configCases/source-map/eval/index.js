@@ -0,0 +1,7 @@
+it("should wrap modules in eval with sourceURL comments", () => {
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toMatch(/eval\(/);
+	expect(source).toMatch(/\/\/# sourceURL=webpack:\/\/[^/]*\/\.\/index\.js\?/);
+	expect(/sourceMappingURL\s*=\s*data:/.test(source)).toBe(false);
+});
@@ -0,0 +1,5 @@
+module.exports = {
+	devtool: "eval",
+	externals: ["source-map"],
+	externalsType: "commonjs"
+};
@@ -0,0 +1,11 @@
+import value from "./vendor/ignored";
+
+it("should list ignored sources in ignoreList", () => {
+	expect(value).toBe("ignored");
+	const fs = require("fs");
+	const map = JSON.parse(fs.readFileSync(__filename + ".map", "utf-8"));
+	const index = map.sources.findIndex(source => source.includes("ignored.js"));
+	expect(index).not.toBe(-1);
+	expect(map.ignoreList).toEqual([index]);
+	expect(map.x_google_ignoreList).toEqual([index]);
+});
@@ -0,0 +1,12 @@
+const { SourceMapDevToolPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	devtool: false,
+	plugins: [
+		new SourceMapDevToolPlugin({
+			filename: "[file].map",
+			ignoreList: /vendor/
+		})
+	]
+};
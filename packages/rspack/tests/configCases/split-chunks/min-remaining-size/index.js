@@ -0,0 +1,10 @@
+import shared from "./shared";
+import enforced from "./enforced";
+
+it("should keep modules in the chunk when the remaining chunk would be too small", () => {
+	expect(shared).toBe("shared");
+	expect(enforced).toBe("enforced");
+	const files = require("fs").readdirSync(__dirname);
+	expect(files).not.toContain("shared.js");
+	expect(files).toContain("enforced.js");
+});
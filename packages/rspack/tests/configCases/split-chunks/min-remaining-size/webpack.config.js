@@ -0,0 +1,31 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index"
+	},
+	output: {
+		filename: "[name].js",
+		libraryTarget: "commonjs2"
+	},
+	optimization: {
+		splitChunks: {
+			minSize: 0,
+			minRemainingSize: 5000,
+			cacheGroups: {
+				default: false,
+				defaultVendors: false,
+				shared: {
+					chunks: "all",
+					test: /shared/,
+					name: "shared"
+				},
+				enforced: {
+					chunks: "all",
+					test: /enforced/,
+					name: "enforced",
+					enforce: true
+				}
+			}
+		}
+	}
+};
@@ -0,0 +1,5 @@
+module.exports = [
+	[
+		/WebAssembly modules of type "webassembly\/sync" can't be loaded with wasmLoading "async-node-module"/
+	]
+];
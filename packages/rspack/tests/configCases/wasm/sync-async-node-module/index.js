@@ -0,0 +1 @@
+import("./wasm.wasm");
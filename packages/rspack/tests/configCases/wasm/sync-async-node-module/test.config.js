@@ -0,0 +1 @@
+exports.noTests = true;
@@ -0,0 +1,5 @@
+module.exports = [
+	[
+		/Export "add" with i64 as parameter can only be used for direct wasm to wasm dependencies/
+	]
+];
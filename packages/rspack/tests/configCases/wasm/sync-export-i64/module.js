@@ -0,0 +1,5 @@
+import { add } from "./wasm.wasm";
+
+export function run() {
+	return add(1n, 2n);
+}
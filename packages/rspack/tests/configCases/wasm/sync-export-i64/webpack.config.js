@@ -0,0 +1,5 @@
+module.exports = {
+	experiments: {
+		syncWebAssembly: true
+	}
+};
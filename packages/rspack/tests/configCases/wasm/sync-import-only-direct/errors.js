@@ -0,0 +1,5 @@
+module.exports = [
+	[
+		/Import "memory" from "\.\/env\.js" with Memory can only be used for direct wasm to wasm dependencies/
+	]
+];
@@ -0,0 +1 @@
+module.exports = [[/WebAssembly module is included in initial chunk/]];
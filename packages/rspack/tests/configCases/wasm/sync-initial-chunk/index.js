@@ -0,0 +1,3 @@
+import { sum } from "./wasm.wasm";
+
+sum(1, 2);
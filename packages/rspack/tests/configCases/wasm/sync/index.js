@@ -0,0 +1,11 @@
+it("should call a JavaScript function imported by a sync WebAssembly module", function () {
+	return import("./module").then(function (module) {
+		expect(module.run()).toBe(42);
+	});
+});
+
+it("should use the exports of a sync WebAssembly module directly", function () {
+	return import("./wasm.wasm").then(function (wasm) {
+		expect(wasm.sum(1, 2)).toBe(4);
+	});
+});
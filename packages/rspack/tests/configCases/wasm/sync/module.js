@@ -0,0 +1,5 @@
+import { sum } from "./wasm.wasm";
+
+export function run() {
+	return sum(20, 21);
+}
@@ -0,0 +1 @@
+registerProcessor("noise", class {});
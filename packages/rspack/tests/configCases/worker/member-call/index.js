@@ -0,0 +1,67 @@
+const fs = require("fs");
+const path = require("path");
+
+const registered = [];
+const navigator = {
+	serviceWorker: {
+		register(url, options) {
+			registered.push({ url, options });
+		}
+	}
+};
+const CSS = {
+	paintWorklet: {
+		addModule(url) {
+			registered.push({ url });
+		}
+	}
+};
+const context = {
+	audioWorklet: {
+		addModule(url) {
+			registered.push({ url });
+		}
+	}
+};
+
+function expectEmitted(url) {
+	expect(url).toBeInstanceOf(URL);
+	expect(
+		fs.existsSync(path.resolve(__dirname, path.basename(url.pathname)))
+	).toBe(true);
+}
+
+function readEmitted(url) {
+	return fs.readFileSync(
+		path.resolve(__dirname, path.basename(url.pathname)),
+		"utf-8"
+	);
+}
+
+it("should bundle a service worker registered by navigator.serviceWorker.register", () => {
+	navigator.serviceWorker.register(new URL("./sw.js", import.meta.url), {
+		scope: "/"
+	});
+	const { url, options } = registered.pop();
+	expectEmitted(url);
+	expect(options.scope).toBe("/");
+	// service workers keep the worker chunk loading, the helper is in its own chunk
+	expect(readEmitted(url)).not.toContain("sw helper");
+});
+
+it("should bundle worklets added by addModule", () => {
+	CSS.paintWorklet.addModule(new URL("./paint.js", import.meta.url));
+	const { url } = registered.pop();
+	expectEmitted(url);
+	// worklets can't load chunks, the helper is bundled into the worklet
+	expect(readEmitted(url)).toContain("paint helper");
+	context.audioWorklet.addModule(new URL("./audio.js", import.meta.url));
+	expectEmitted(registered.pop().url);
+});
+
+it("should walk the arguments of a worker call without a worker entry", () => {
+	navigator.serviceWorker.register(
+		String(new URL("./file.txt", import.meta.url))
+	);
+	expectEmitted(new URL(registered.pop().url));
+});
@@ -0,0 +1,2 @@
+import("./paint-helper");
+registerPaint("checkerboard", class {});
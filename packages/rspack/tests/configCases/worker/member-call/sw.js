@@ -0,0 +1,2 @@
+import("./sw-helper");
+self.addEventListener("fetch", () => {});
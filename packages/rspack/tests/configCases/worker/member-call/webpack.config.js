@@ -0,0 +1,19 @@
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.txt$/,
+				type: "asset/resource"
+			}
+		],
+		parser: {
+			javascript: {
+				worker: [
+					"...",
+					"CSS.paintWorklet.addModule()",
+					"*audioWorklet.addModule()"
+				]
+			}
+		}
+	}
+};
@@ -0,0 +1,3 @@
+it("should able to run entry a", () => {
+  expect(true).toBeTruthy();
+});
@@ -0,0 +1,3 @@
+it("should able to run entry b", () => {
+  expect(true).toBeTruthy();
+});
@@ -0,0 +1,4 @@
+import { used } from "./lib";
+
+export const load = () => import(/* webpackChunkName: "lazy" */ "./lazy");
+export default used;
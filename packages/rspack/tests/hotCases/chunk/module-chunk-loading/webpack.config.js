@@ -0,0 +1,12 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	output: {
+		module: true,
+		chunkFormat: "module",
+		chunkLoading: "import"
+	},
+	experiments: {
+		outputModule: true
+	}
+};
@@ -0,0 +1,24 @@
+it("should build the import() target once it is activated", done => {
+	expect(
+		STATS.modules.some(
+			m =>
+				m.identifier.endsWith("lazy.js") &&
+				!m.identifier.startsWith("lazy-compilation-proxy|")
+		)
+	).toBe(false);
+	import("./lazy").then(lazy => {
+		expect(lazy.default).toBe("lazy");
+		done();
+	}, done);
+	NEXT(
+		require("../../update")(done, true, stats => {
+			expect(
+				stats.modules.some(
+					m =>
+						m.identifier.endsWith("lazy.js") &&
+						!m.identifier.startsWith("lazy-compilation-proxy|")
+				)
+			).toBe(true);
+		})
+	);
+});
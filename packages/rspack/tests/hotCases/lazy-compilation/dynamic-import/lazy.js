@@ -0,0 +1,3 @@
+export default "lazy";
+---
+export default "lazy";
@@ -0,0 +1,3 @@
+it("should watch files replaced by rename", function () {
+	expect(require("./changing-file")).toBe(WATCH_STEP);
+});